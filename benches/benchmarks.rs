@@ -8,7 +8,7 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use fixed::types::I16F16;
 use fixed_analytics::{
     acos, acosh, acoth, asin, asinh, atan, atan2, atanh, cos, cosh, coth, exp, ln, log2, log10,
-    sin, sin_cos, sinh, sinh_cosh, sqrt, tan, tanh,
+    sin, sin_cos, sinh, sinh_cosh, sqrt, sqrt_cordic, tan, tanh, trig_all,
 };
 
 fn bench_circular(c: &mut Criterion) {
@@ -19,6 +19,16 @@ fn bench_circular(c: &mut Criterion) {
     c.bench_function("cos", |b| b.iter(|| cos(black_box(angle))));
     c.bench_function("tan", |b| b.iter(|| tan(black_box(angle))));
     c.bench_function("sin_cos", |b| b.iter(|| sin_cos(black_box(angle))));
+    // sin and cos evaluate one polynomial; compare with projecting sin_cos.
+    c.bench_function("sin_cos().0", |b| b.iter(|| sin_cos(black_box(angle)).0));
+    c.bench_function("sin_cos().1", |b| b.iter(|| sin_cos(black_box(angle)).1));
+    c.bench_function("trig_all", |b| b.iter(|| trig_all(black_box(angle))));
+    c.bench_function("sin+cos+tan", |b| {
+        b.iter(|| {
+            let angle = black_box(angle);
+            (sin(angle), cos(angle), tan(angle))
+        });
+    });
     c.bench_function("asin", |b| b.iter(|| asin(black_box(x))));
     c.bench_function("acos", |b| b.iter(|| acos(black_box(x))));
     c.bench_function("atan", |b| b.iter(|| atan(black_box(x))));
@@ -54,8 +64,13 @@ fn bench_exponential(c: &mut Criterion) {
 
 fn bench_algebraic(c: &mut Criterion) {
     let x = I16F16::from_num(2.0);
+    let small = I16F16::from_num(0.0003);
+    let large = I16F16::from_num(30_000);
 
     c.bench_function("sqrt", |b| b.iter(|| sqrt(black_box(x))));
+    c.bench_function("sqrt small", |b| b.iter(|| sqrt(black_box(small))));
+    c.bench_function("sqrt large", |b| b.iter(|| sqrt(black_box(large))));
+    c.bench_function("sqrt_cordic", |b| b.iter(|| sqrt_cordic(black_box(x))));
 }
 
 criterion_group!(
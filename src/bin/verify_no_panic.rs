@@ -8,18 +8,28 @@
 compile_error!("this binary should only be built with --features verify-no-panic");
 
 use fixed::types::I16F16;
-use fixed_analytics::bounded::{NonNegative, OpenUnitInterval};
+use fixed_analytics::bounded::{NonNegative, OpenUnitInterval, UnitInterval};
 use fixed_analytics::ops::algebraic::sqrt_nonneg;
+use fixed_analytics::ops::circular::{acos_unit, asin_unit};
 use fixed_analytics::ops::hyperbolic::atanh_open;
 use fixed_analytics::{
-    acos, acosh, acoth, asin, asinh, atan, atan2, atanh, cos, cosh, coth, exp, ln, log2, log10,
-    pow2, sin, sin_cos, sinh, sinh_cosh, sqrt, tan, tanh,
+    CordicConfig, PowBase, acos, acos_clamped, acos_with, acosh, acoth, angle_diff, asin,
+    asin_clamped, asin_with, asinh, atan, atan_with, atan2, atan2_deg, atan2_quadrant, atan2_turns,
+    atanh, atanh_with, ccos, cexp, cexp_scaled, complex_mul, cos, cos_array, cosh, cosh_max_arg,
+    coth, csin, ema_step, erf, erfc, exp, exp_array, exp_max_arg, exp_monotone_slice, exp_with,
+    gamma, isqrt, lerp, lgamma, ln, ln_with, log2, log10, logaddexp, map_array, normalize_angle,
+    normalize2, normalize3, pow, pow2, pow2_clamped, powi, range_bearing, reduce_periodic, sin,
+    sin_array, sin_cos, sin_cos_checked, sin_cos_turns, sin_cos_with, sinc, sinc_pi, sinh,
+    sinh_cosh, sinh_cosh_tanh, sinh_max_arg, sinh_with, smoothstep, sqrt, sqrt_cordic, sqrt_with,
+    tan, tan_half, tanh, trig_all, try_atan2, try_cosh, try_exp, try_pow2, try_sinh, try_sinh_cosh,
+    twiddle,
 };
 
 fn main() {
     // Use black_box to prevent the optimizer from eliminating calls entirely.
     let x = std::hint::black_box(I16F16::from_num(0.5));
     let y = std::hint::black_box(I16F16::from_num(0.25));
+    let config = std::hint::black_box(CordicConfig::new());
 
     // Total functions (return T)
     let _ = std::hint::black_box(sin(x));
@@ -28,6 +38,7 @@ fn main() {
     let _ = std::hint::black_box(sin_cos(x));
     let _ = std::hint::black_box(atan(x));
     let _ = std::hint::black_box(atan2(y, x));
+    let _ = std::hint::black_box(normalize_angle(x));
     let _ = std::hint::black_box(exp(x));
     let _ = std::hint::black_box(pow2(x));
     let _ = std::hint::black_box(sinh(x));
@@ -35,6 +46,50 @@ fn main() {
     let _ = std::hint::black_box(tanh(x));
     let _ = std::hint::black_box(sinh_cosh(x));
     let _ = std::hint::black_box(asinh(x));
+    let _ = std::hint::black_box(sinh_cosh_tanh(x));
+    let _ = std::hint::black_box(powi(x, 3));
+    let _ = std::hint::black_box(complex_mul(x, y, y, x));
+    let _ = std::hint::black_box(twiddle::<I16F16>(std::hint::black_box(3), 16));
+    let _ = std::hint::black_box(logaddexp(x, y));
+    let _ = std::hint::black_box(isqrt(x));
+    let _ = std::hint::black_box(angle_diff(x, y));
+    let _ = std::hint::black_box(erf(x));
+    let _ = std::hint::black_box(erfc(x));
+    let _ = std::hint::black_box(asin_clamped(x));
+    let _ = std::hint::black_box(acos_clamped(x));
+    let _ = std::hint::black_box(pow2_clamped(x, y));
+    let _ = std::hint::black_box(sinc(x));
+    let _ = std::hint::black_box(sinc_pi(x));
+    let _ = std::hint::black_box(sqrt_cordic(x));
+    let _ = std::hint::black_box(trig_all(x));
+    let _ = std::hint::black_box(atan_with(x, &config));
+    let _ = std::hint::black_box(sin_cos_with(x, &config));
+    let _ = std::hint::black_box(atan2_quadrant(y, x));
+    let _ = std::hint::black_box(sin_cos_turns(x));
+    let _ = std::hint::black_box(cexp(x));
+    let _ = std::hint::black_box(cexp_scaled(y, x));
+    let _ = std::hint::black_box({
+        let mut out = [x; 2];
+        exp_monotone_slice(&[y, x], &mut out);
+        out
+    });
+    let _ = std::hint::black_box(reduce_periodic(x, y));
+    let _ = std::hint::black_box(map_array([x, y], sin));
+    let _ = std::hint::black_box(sin_array([x, y]));
+    let _ = std::hint::black_box(cos_array([x, y]));
+    let _ = std::hint::black_box(exp_array([x, y]));
+    let _ = std::hint::black_box(range_bearing(x, y));
+    let _ = std::hint::black_box(csin(x, y));
+    let _ = std::hint::black_box(ccos(x, y));
+    let _ = std::hint::black_box(exp_max_arg::<I16F16>());
+    let _ = std::hint::black_box(cosh_max_arg::<I16F16>());
+    let _ = std::hint::black_box(sinh_max_arg::<I16F16>());
+    let _ = std::hint::black_box(lerp(x, y, x));
+    let _ = std::hint::black_box(smoothstep(y, x, x));
+    let _ = std::hint::black_box(tan_half(x));
+    let _ = std::hint::black_box(ema_step(x, y, y));
+    let _ = std::hint::black_box(atan2_deg(y, x));
+    let _ = std::hint::black_box(atan2_turns(y, x));
 
     // Fallible functions (return Result<T>)
     let _ = std::hint::black_box(asin(x));
@@ -47,10 +102,33 @@ fn main() {
     let _ = std::hint::black_box(atanh(x));
     let _ = std::hint::black_box(coth(x));
     let _ = std::hint::black_box(acoth(I16F16::from_num(2)));
+    let _ = std::hint::black_box(try_sinh_cosh(x));
+    let _ = std::hint::black_box(try_atan2(y, x));
+    let _ = std::hint::black_box(lgamma(x));
+    let _ = std::hint::black_box(gamma(x));
+    let _ = std::hint::black_box(normalize2(x, y));
+    let _ = std::hint::black_box(sin_cos_checked(x));
+    let _ = std::hint::black_box(sqrt_with(x, &config));
+    let _ = std::hint::black_box(try_exp(x));
+    let _ = std::hint::black_box(try_pow2(x));
+    let _ = std::hint::black_box(try_sinh(x));
+    let _ = std::hint::black_box(try_cosh(x));
+    let _ = std::hint::black_box(pow(x, y));
+    let _ = std::hint::black_box(asin_with(x, &config));
+    let _ = std::hint::black_box(acos_with(x, &config));
+    let _ = std::hint::black_box(exp_with(x, &config));
+    let _ = std::hint::black_box(ln_with(x, &config));
+    let _ = std::hint::black_box(sinh_with(x, &config));
+    let _ = std::hint::black_box(atanh_with(x, &config));
+    let _ = std::hint::black_box(PowBase::new(x).map(|b| b.pow(y)));
+    let _ = std::hint::black_box(normalize3(x, y, x));
 
     // Type-safe wrapper functions
     let nn = NonNegative::new(x).unwrap();
     let _ = std::hint::black_box(sqrt_nonneg(nn));
     let ou = OpenUnitInterval::new(x).unwrap();
     let _ = std::hint::black_box(atanh_open(ou));
+    let unit = UnitInterval::new(x).unwrap();
+    let _ = std::hint::black_box(asin_unit(unit));
+    let _ = std::hint::black_box(acos_unit(unit));
 }
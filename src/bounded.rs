@@ -45,6 +45,14 @@ impl<T: CordicNumber> NonNegative<T> {
         (value >= T::zero()).then_some(Self(value))
     }
 
+    /// Constructs by clamping negative inputs to zero.
+    #[inline]
+    #[must_use]
+    pub fn clamp(value: T) -> Self {
+        let zero = T::zero();
+        Self(if value < zero { zero } else { value })
+    }
+
     /// Constructs from `1 + x^2`, which is always >= 1.
     ///
     /// This is mathematically infallible: for any real `x`, `1 + x^2 >= 1`.
@@ -103,6 +111,23 @@ impl<T: CordicNumber> UnitInterval<T> {
         (value >= -one && value <= one).then_some(Self(value))
     }
 
+    /// Constructs by clamping the input into [-1, 1].
+    ///
+    /// Useful for values that should be in range but may overshoot slightly,
+    /// such as a dot product of normalized vectors.
+    #[inline]
+    #[must_use]
+    pub fn clamp(value: T) -> Self {
+        let one = T::one();
+        Self(if value > one {
+            one
+        } else if value < -one {
+            -one
+        } else {
+            value
+        })
+    }
+
     /// Unwraps the inner value.
     #[inline]
     #[must_use]
@@ -128,6 +153,21 @@ impl<T: CordicNumber> OpenUnitInterval<T> {
         (value > -one && value < one).then_some(Self(value))
     }
 
+    /// Constructs by clamping the input into `[-1 + ε, 1 - ε]`, one ULP
+    /// inside the open interval.
+    #[inline]
+    #[must_use]
+    pub fn clamp(value: T) -> Self {
+        let bound = T::one() - T::epsilon();
+        Self(if value > bound {
+            bound
+        } else if value < -bound {
+            -bound
+        } else {
+            value
+        })
+    }
+
     /// Constructs from `x / sqrt(1 + x^2)`, which is always in (-1, 1).
     ///
     /// This is mathematically infallible: for any real `x`,
@@ -0,0 +1,142 @@
+//! Tunable iteration counts and out-of-range policy for the `*_with`
+//! function variants.
+
+use crate::traits::CordicNumber;
+
+/// Settings used by the `*_with` variants, such as
+/// [`atan_with`](crate::atan_with), [`sin_cos_with`](crate::sin_cos_with)
+/// and [`sqrt_with`](crate::sqrt_with).
+///
+/// [`CordicConfig::default`] reproduces the plain functions exactly. Each
+/// setting left unset keeps its per-type default:
+///
+/// | Setting | Default | Used by |
+/// |---------|---------|---------|
+/// | CORDIC iterations | `frac_bits`, at most 62 | circular vectoring (atan); when set, circular rotation (`sin_cos`) |
+/// | Newton iterations | `frac_bits / 2`, clamped to `[4, 20]` | sqrt |
+/// | [`Saturation`] | [`Saturation::Saturate`] | every `*_with` that can overflow or reject its input |
+///
+/// Fewer iterations trade accuracy for speed; more than the default cannot
+/// improve on the type's precision. Thresholds that keep an algorithm inside
+/// its convergence region, such as atanh's argument reduction, are not
+/// configurable.
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed_analytics::{CordicConfig, atan_with};
+///
+/// let coarse = CordicConfig::new().with_cordic_iterations(8);
+/// let angle = atan_with(I16F16::from_num(0.5), &coarse);
+/// assert!((angle.to_num::<f32>() - 0.4636).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CordicConfig {
+    cordic_iterations: Option<u32>,
+    newton_iterations: Option<u32>,
+    saturation: Saturation,
+}
+
+impl CordicConfig {
+    /// Configuration with every setting at its default.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cordic_iterations: None,
+            newton_iterations: None,
+            saturation: Saturation::Saturate,
+        }
+    }
+
+    /// Sets the number of CORDIC micro-rotations.
+    ///
+    /// At most 62 run, the length of the angle table, and at most
+    /// `total_bits - 1` of `T`, past which a shift would overflow. With the
+    /// `small-tables` feature the table holds 32 entries and later
+    /// micro-rotations add no angle, so more than 32 gain no accuracy.
+    #[must_use]
+    pub const fn with_cordic_iterations(mut self, iterations: u32) -> Self {
+        self.cordic_iterations = Some(iterations);
+        self
+    }
+
+    /// Sets the maximum number of Newton-Raphson steps. At least one step is
+    /// always taken.
+    #[must_use]
+    pub const fn with_newton_iterations(mut self, iterations: u32) -> Self {
+        self.newton_iterations = Some(iterations);
+        self
+    }
+
+    /// Sets how results outside the range of `T` and inputs outside the
+    /// function's domain are handled.
+    #[must_use]
+    pub const fn with_saturation(mut self, saturation: Saturation) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    /// The configured out-of-range policy.
+    #[must_use]
+    pub const fn saturation(&self) -> Saturation {
+        self.saturation
+    }
+
+    /// CORDIC iterations to run for `T`.
+    pub(crate) fn cordic_iterations<T: CordicNumber>(&self) -> u32 {
+        self.cordic_iterations.unwrap_or_else(T::frac_bits).min(62)
+    }
+
+    /// The CORDIC iteration count, if one was set.
+    pub(crate) const fn explicit_cordic_iterations(&self) -> Option<u32> {
+        self.cordic_iterations
+    }
+
+    /// Newton-Raphson iterations to run for `T`.
+    pub(crate) fn newton_iterations<T: CordicNumber>(&self) -> u32 {
+        // Newton-Raphson for sqrt converges quadratically from a power-of-two
+        // estimate: `sqrt_traced` over the accuracy-bench inputs and full
+        // sweeps of the 8- and 16-bit types never takes more than 6 steps,
+        // and no result changes until the limit drops below 4.
+        self.newton_iterations
+            .unwrap_or_else(|| (T::frac_bits() / 2).clamp(4, 20))
+            .max(1)
+    }
+}
+
+/// How the `*_with` variants handle values they cannot represent.
+///
+/// The plain functions each follow one of these: [`exp`](crate::exp)
+/// saturates, [`try_exp`](crate::try_exp) and [`asin`](crate::asin) return
+/// errors, [`asin_clamped`](crate::asin_clamped) clamps. A [`CordicConfig`]
+/// picks one policy for every `*_with` call it is passed to:
+///
+/// | Policy | Result beyond the range of `T` | Input outside the domain |
+/// |--------|--------------------------------|--------------------------|
+/// | [`Saturate`](Self::Saturate) | `T::MAX` or `T::MIN` | `DomainError` |
+/// | [`Error`](Self::Error) | `Overflow` | `DomainError` |
+/// | [`Clamp`](Self::Clamp) | `T::MAX` or `T::MIN` | clamped to the nearest point of the domain |
+///
+/// Under `Clamp` the `*_with` variants never return an error. Open domains
+/// clamp to one ULP inside their bound, so `ln_with(0)` is `ln(ε)`.
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed_analytics::{CordicConfig, Saturation, asin_with, exp_with};
+///
+/// let strict = CordicConfig::new().with_saturation(Saturation::Error);
+/// assert!(exp_with(I16F16::from_num(20), &strict).is_err());
+///
+/// let clamp = CordicConfig::new().with_saturation(Saturation::Clamp);
+/// let angle = asin_with(I16F16::from_num(1.001), &clamp).unwrap();
+/// assert_eq!(angle, I16F16::FRAC_PI_2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Saturation {
+    /// Saturate results; reject inputs outside the domain.
+    #[default]
+    Saturate,
+    /// Report both overflow and domain violations as errors.
+    Error,
+    /// Saturate results; clamp inputs into the domain.
+    Clamp,
+}
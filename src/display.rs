@@ -0,0 +1,35 @@
+//! Decimal formatting for generic fixed-point values.
+
+use core::fmt;
+
+use crate::traits::CordicNumber;
+
+/// Formats a [`CordicNumber`] as a decimal through [`fmt::Display`] and
+/// [`fmt::Debug`], without floating point.
+///
+/// Generic code bounded only by `CordicNumber` cannot use the `fixed` crate's
+/// own `Display` impls; wrapping a value in `Decimal` makes it loggable from
+/// inside such code, including on `no_std` targets.
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed_analytics::Decimal;
+///
+/// let x = I16F16::from_num(1.5);
+/// assert_eq!(format!("{}", Decimal(x)), "1.5");
+/// assert_eq!(format!("{:.3}", Decimal(x)), "1.500");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Decimal<T>(pub T);
+
+impl<T: CordicNumber> fmt::Display for Decimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_decimal(f)
+    }
+}
+
+impl<T: CordicNumber> fmt::Debug for Decimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_decimal(f)
+    }
+}
@@ -1,19 +1,46 @@
 //! Error types for CORDIC operations.
 
+use crate::traits::CordicNumber;
 use core::fmt;
 
 /// Errors that can occur during CORDIC computations.
+///
+/// New variants may be added in minor releases, so matches need a wildcard
+/// arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// Input value is outside the valid domain for the function.
     ///
     /// For example, `asin(2.0)` would produce this error since
-    /// arcsine is only defined for inputs in [-1, 1].
+    /// arcsine is only defined for inputs in [-1, 1]. Build it with
+    /// [`Error::domain`] or [`Error::domain_at`]; fields may be added in
+    /// minor releases.
+    #[non_exhaustive]
     DomainError {
         /// Name of the function that encountered the error.
         function: &'static str,
         /// Human-readable description of the valid domain.
         expected: &'static str,
+        /// Raw bits of the offending input, for functions of one value.
+        ///
+        /// Interpret them with the caller's fixed-point type, e.g.
+        /// `I16F16::from_bits(bits as i32)`. `None` if the error concerns
+        /// several inputs, or the bits of a 128-bit type do not fit.
+        #[cfg_attr(feature = "serde", serde(default))]
+        value: Option<i64>,
+    },
+    /// Result is too large in magnitude to be represented by the type.
+    ///
+    /// Returned by the `try_*` variants of functions that otherwise saturate,
+    /// for example `try_sinh_cosh(20.0)` on `I16F16`. Build it with
+    /// [`Error::overflow`].
+    #[non_exhaustive]
+    Overflow {
+        /// Name of the function that encountered the error.
+        function: &'static str,
     },
 }
 
@@ -21,18 +48,50 @@ impl Error {
     /// Create a domain error for the given function.
     #[must_use]
     pub const fn domain(function: &'static str, expected: &'static str) -> Self {
-        Self::DomainError { function, expected }
+        Self::DomainError {
+            function,
+            expected,
+            value: None,
+        }
+    }
+
+    /// Create a domain error that records the offending input.
+    #[must_use]
+    pub fn domain_at<T: CordicNumber>(
+        function: &'static str,
+        expected: &'static str,
+        value: T,
+    ) -> Self {
+        Self::DomainError {
+            function,
+            expected,
+            value: value.to_bits_i64(),
+        }
+    }
+
+    /// Create an overflow error for the given function.
+    #[must_use]
+    pub const fn overflow(function: &'static str) -> Self {
+        Self::Overflow { function }
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::DomainError { function, expected } => {
+            Self::DomainError {
+                function,
+                expected,
+                value,
+            } => {
                 write!(
                     f,
                     "{function}: input outside valid domain, expected {expected}"
-                )
+                )?;
+                value.map_or(Ok(()), |bits| write!(f, " (got raw bits {bits})"))
+            }
+            Self::Overflow { function } => {
+                write!(f, "{function}: result exceeds the representable range")
             }
         }
     }
@@ -21,17 +21,68 @@
 //! - d = +1 for circular, -1 for hyperbolic, 0 for linear
 //! - angle[i] = atan(2^-i) for circular, atanh(2^-i) for hyperbolic
 
+use crate::config::CordicConfig;
 use crate::tables::hyperbolic::needs_repeat;
+#[cfg(not(feature = "small-tables"))]
 use crate::tables::{ATAN_TABLE, ATANH_TABLE};
+// The kernel reads the compact copies; the public tables stay I1F63.
+#[cfg(feature = "small-tables")]
+use crate::tables::{
+    circular::ATAN_TABLE_I1F31 as ATAN_TABLE, hyperbolic::ATANH_TABLE_I1F31 as ATANH_TABLE,
+};
 use crate::traits::CordicNumber;
 
+/// Overflow handling for the x/y updates in the CORDIC kernels.
+///
+/// The plain kernels use [`Saturating`]. [`Wrapping`] gives modular
+/// arithmetic, as preferred by some fixed-point DSP pipelines.
+pub trait OverflowPolicy {
+    /// Adds `a + b` under this policy.
+    fn add<T: CordicNumber>(a: T, b: T) -> T;
+    /// Subtracts `a - b` under this policy.
+    fn sub<T: CordicNumber>(a: T, b: T) -> T;
+}
+
+/// Clamp to the representable range on overflow.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Saturating;
+
+impl OverflowPolicy for Saturating {
+    #[inline]
+    fn add<T: CordicNumber>(a: T, b: T) -> T {
+        a.saturating_add(b)
+    }
+
+    #[inline]
+    fn sub<T: CordicNumber>(a: T, b: T) -> T {
+        a.saturating_sub(b)
+    }
+}
+
+/// Wrap around (two's complement) on overflow.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Wrapping;
+
+impl OverflowPolicy for Wrapping {
+    #[inline]
+    fn add<T: CordicNumber>(a: T, b: T) -> T {
+        a.wrapping_add(b)
+    }
+
+    #[inline]
+    fn sub<T: CordicNumber>(a: T, b: T) -> T {
+        a.wrapping_sub(b)
+    }
+}
+
 /// Table lookup for CORDIC iteration.
 ///
 /// Index is bounded by CORDIC iteration limits:
-/// - Circular mode: `min(frac_bits, 62)` → max index 61
+/// - Circular mode: `min(iterations, 62, total_bits - 1)` → max index 61
 /// - Hyperbolic mode: `min(frac_bits, 54)` with `i.saturating_sub(1)` → max index 53
 ///
 /// Since the tables have 64 elements and max index is 61, bounds are always satisfied.
+#[cfg(not(feature = "small-tables"))]
 #[inline]
 const fn table_lookup(table: &[i64; 64], index: u32) -> i64 {
     #[allow(
@@ -41,6 +92,25 @@ const fn table_lookup(table: &[i64; 64], index: u32) -> i64 {
     table[index as usize]
 }
 
+/// Table lookup for CORDIC iteration, widening the I1F31 `small-tables`
+/// entries to I1F63.
+///
+/// The default iteration counts for types with at most 32 fractional bits
+/// never index past the 32 entries. Wider types, and explicit counts from
+/// [`CordicConfig`], do and get 0 there: their angles stop resolving below
+/// 2⁻³¹, which caps their accuracy at roughly that absolute error.
+#[cfg(feature = "small-tables")]
+#[inline]
+const fn table_lookup(table: &[i32; 32], index: u32) -> i64 {
+    if index < 32 {
+        #[allow(clippy::indexing_slicing, reason = "index checked above")]
+        let entry = table[index as usize];
+        (entry as i64) << 32
+    } else {
+        0
+    }
+}
+
 /// Performs circular CORDIC in vectoring mode.
 ///
 /// Given an initial vector (x, y), rotates it until y ≈ 0.
@@ -63,28 +133,134 @@ const fn table_lookup(table: &[i64; 64], index: u32) -> i64 {
 ///
 /// For computing atan(y/x), pass (1, y/x, 0) or (x, y, 0).
 #[must_use]
-pub fn circular_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T, T, T) {
+pub fn circular_vectoring<T: CordicNumber>(x: T, y: T, z: T) -> (T, T, T) {
+    circular_vectoring_with::<Saturating, T>(x, y, z)
+}
+
+/// [`circular_vectoring`] with the x/y overflow behavior chosen by `P`.
+#[must_use]
+pub fn circular_vectoring_with<P: OverflowPolicy, T: CordicNumber>(x: T, y: T, z: T) -> (T, T, T) {
+    let iterations = CordicConfig::new().cordic_iterations::<T>();
+    circular_vectoring_n::<P, T>(x, y, z, iterations)
+}
+
+/// [`circular_vectoring_with`] running `iterations` micro-rotations, at most
+/// 62 and at most `total_bits - 1`.
+pub fn circular_vectoring_n<P: OverflowPolicy, T: CordicNumber>(
+    mut x: T,
+    mut y: T,
+    mut z: T,
+    iterations: u32,
+) -> (T, T, T) {
     let zero = T::zero();
-    let iterations = T::frac_bits().min(62);
 
-    for i in 0..iterations {
+    for i in 0..circular_limit::<T>(iterations) {
         let angle = T::from_i1f63(table_lookup(&ATAN_TABLE, i));
 
         if y < zero {
             // y is negative, rotate counter-clockwise to bring y toward zero
-            let x_new = x.saturating_sub(y >> i);
-            y = y.saturating_add(x >> i);
+            let x_new = P::sub(x, y >> i);
+            y = P::add(y, x >> i);
             x = x_new;
             z -= angle;
         } else {
             // y is positive or zero, rotate clockwise
+            let x_new = P::add(x, y >> i);
+            y = P::sub(y, x >> i);
+            x = x_new;
+            z += angle;
+        }
+    }
+
+    (x, y, z)
+}
+
+/// Caps a circular iteration count at the angle table's length and below the
+/// width of `T`. Shifting by the full width overflows, and past it the step
+/// is zero anyway.
+fn circular_limit<T: CordicNumber>(iterations: u32) -> u32 {
+    iterations.min(62).min(T::total_bits() - 1)
+}
+
+/// Circular CORDIC in rotation mode for `iterations` micro-rotations (at
+/// most 62 and at most `total_bits - 1`), starting from the vector (x, 0).
+///
+/// Returns the rotated (x, y) and the angle actually turned, as the exact sum
+/// of the signed table entries in units of 2⁻⁶³. The z accumulator only
+/// steers the rotation directions; its rounded table values never reach the
+/// result, so callers can measure the leftover angle `z₀ - turned` to well
+/// below one ULP of `T`.
+pub fn circular_rotation_n<T: CordicNumber>(mut x: T, mut z: T, iterations: u32) -> (T, T, i128) {
+    let zero = T::zero();
+    let mut y = zero;
+    let mut turned: i128 = 0;
+
+    for i in 0..circular_limit::<T>(iterations) {
+        let bits = table_lookup(&ATAN_TABLE, i);
+        let angle = T::from_i1f63(bits);
+
+        if z < zero {
             let x_new = x.saturating_add(y >> i);
             y = y.saturating_sub(x >> i);
             x = x_new;
             z += angle;
+            turned -= i128::from(bits);
+        } else {
+            let x_new = x.saturating_sub(y >> i);
+            y = y.saturating_add(x >> i);
+            x = x_new;
+            z -= angle;
+            turned += i128::from(bits);
         }
     }
 
+    (x, y, turned)
+}
+
+/// Performs circular CORDIC in rotation mode, reporting each iteration.
+///
+/// Rotates (x, y) by z, driving z toward zero. After iteration:
+/// - x ≈ K * (x₀·cos(z₀) - y₀·sin(z₀))
+/// - y ≈ K * (y₀·cos(z₀) + x₀·sin(z₀))
+/// - z ≈ 0
+///
+/// where K ≈ 1.6468 is the circular gain. Converges for |z₀| ≤ 1.7433.
+///
+/// `trace` is called after every micro-rotation with the iteration index and
+/// the residual z. The residual is not monotone (a step may overshoot), but
+/// after iteration `i` it is bounded by `2^-i` plus rounding.
+///
+/// Only available with the `trace` feature.
+#[cfg(feature = "trace")]
+pub fn circular_rotation_traced<T: CordicNumber>(
+    mut x: T,
+    mut y: T,
+    mut z: T,
+    mut trace: impl FnMut(u32, T),
+) -> (T, T, T) {
+    let zero = T::zero();
+    let iterations = CordicConfig::new().cordic_iterations::<T>();
+
+    for i in 0..iterations {
+        let angle = T::from_i1f63(table_lookup(&ATAN_TABLE, i));
+
+        if z < zero {
+            // Rotate clockwise to bring z up toward zero
+            let x_new = x.saturating_add(y >> i);
+            y = y.saturating_sub(x >> i);
+            x = x_new;
+            z += angle;
+        } else {
+            // Rotate counter-clockwise to bring z down toward zero
+            let x_new = x.saturating_sub(y >> i);
+            y = y.saturating_add(x >> i);
+            x = x_new;
+            z -= angle;
+        }
+
+        trace(i, z);
+    }
+
     (x, y, z)
 }
 
@@ -112,7 +288,17 @@ pub fn circular_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T,
 /// For computing atanh(v), pass (1, v, 0) where |v| < 1.
 /// For computing ln(x), use the identity: ln(x) = 2 * atanh((x-1)/(x+1))
 #[must_use]
-pub fn hyperbolic_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T, T, T) {
+pub fn hyperbolic_vectoring<T: CordicNumber>(x: T, y: T, z: T) -> (T, T, T) {
+    hyperbolic_vectoring_with::<Saturating, T>(x, y, z)
+}
+
+/// [`hyperbolic_vectoring`] with the x/y overflow behavior chosen by `P`.
+#[must_use]
+pub fn hyperbolic_vectoring_with<P: OverflowPolicy, T: CordicNumber>(
+    mut x: T,
+    mut y: T,
+    mut z: T,
+) -> (T, T, T) {
     let zero = T::zero();
     // Use at least 24 iterations for better accuracy, even for lower precision types.
     let max_iterations = T::frac_bits().clamp(24, 54);
@@ -121,7 +307,10 @@ pub fn hyperbolic_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T
     let mut iteration_count: u32 = 0;
     let mut repeated = false;
 
-    while iteration_count < max_iterations && i < 64 {
+    // Shifting by the full width overflows, and past it the step is zero anyway.
+    let max_shift = T::total_bits().min(64);
+
+    while iteration_count < max_iterations && i < max_shift {
         let table_index = i.saturating_sub(1);
         let angle = T::from_i1f63(table_lookup(&ATANH_TABLE, table_index));
 
@@ -136,8 +325,8 @@ pub fn hyperbolic_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T
             // x' = x + y*2^(-i) [y is negative, so this subtracts magnitude]
             // y' = y + x*2^(-i) [adds positive to make less negative]
             // z' = z - angle    [accumulate negative contribution]
-            let x_new = x.saturating_add(y >> i);
-            y = y.saturating_add(x >> i);
+            let x_new = P::add(x, y >> i);
+            y = P::add(y, x >> i);
             x = x_new;
             z -= angle;
         } else {
@@ -145,8 +334,8 @@ pub fn hyperbolic_vectoring<T: CordicNumber>(mut x: T, mut y: T, mut z: T) -> (T
             // x' = x - y*2^(-i) [subtracts positive]
             // y' = y - x*2^(-i) [subtracts to decrease toward zero]
             // z' = z + angle    [accumulate positive contribution]
-            let x_new = x.saturating_sub(y >> i);
-            y = y.saturating_sub(x >> i);
+            let x_new = P::sub(x, y >> i);
+            y = P::sub(y, x >> i);
             x = x_new;
             z += angle;
         }
@@ -0,0 +1,35 @@
+//! CORDIC gain constants converted to the caller's numeric type.
+//!
+//! The kernels leave their outputs scaled by the gain; the raw I1F63 values
+//! live in [`crate::tables`]. These helpers are for code that drives
+//! [`circular_vectoring`](super::circular_vectoring) or
+//! [`hyperbolic_vectoring`](super::hyperbolic_vectoring) directly and needs
+//! to undo that scaling.
+
+use crate::tables::circular::CIRCULAR_GAIN_INV;
+use crate::tables::hyperbolic::{HYPERBOLIC_GAIN, HYPERBOLIC_GAIN_INV_MINUS_ONE};
+use crate::traits::CordicNumber;
+
+/// `1/K` ≈ 0.6073, the reciprocal of the circular CORDIC gain.
+///
+/// Multiplying the magnitude left by circular vectoring by this value
+/// recovers `√(x² + y²)`. Truncated to `T`'s precision.
+#[must_use]
+pub fn circular_gain_inv_value<T: CordicNumber>() -> T {
+    T::from_i1f63(CIRCULAR_GAIN_INV)
+}
+
+/// `K_h` ≈ 0.8282, the hyperbolic CORDIC gain including repeated iterations.
+///
+/// Hyperbolic vectoring leaves `K_h·√(x² - y²)` in `x`. Truncated to `T`'s
+/// precision.
+#[must_use]
+pub fn hyperbolic_gain_value<T: CordicNumber>() -> T {
+    T::from_i1f63(HYPERBOLIC_GAIN)
+}
+
+/// `1/K_h` ≈ 1.2075, the reciprocal of the hyperbolic CORDIC gain.
+#[must_use]
+pub fn hyperbolic_gain_inv_value<T: CordicNumber>() -> T {
+    T::one().saturating_add(T::from_i1f63(HYPERBOLIC_GAIN_INV_MINUS_ONE))
+}
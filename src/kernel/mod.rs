@@ -20,8 +20,30 @@
 //! | Circular | atan |
 //! | Hyperbolic | atanh, ln |
 //!
+//! With the `trace` feature, `circular_rotation_traced` runs rotation mode
+//! (z → 0) and reports the residual angle after every iteration, for
+//! inspecting convergence at a given input.
+//!
+//! The x/y updates saturate by default; the `*_with` variants take an
+//! [`OverflowPolicy`] such as [`Wrapping`] for modular arithmetic.
+//!
+//! [`circular_gain_inv_value`], [`hyperbolic_gain_value`] and
+//! [`hyperbolic_gain_inv_value`] give the kernel gains as `T` for undoing the
+//! scaling that vectoring leaves in `x`.
+//!
 //! Users should call functions in [`crate::ops`] rather than kernels directly.
 
 mod cordic;
+mod gain;
+
+pub(crate) use crate::kernel::cordic::{circular_rotation_n, circular_vectoring_n};
 
-pub use crate::kernel::cordic::{circular_vectoring, hyperbolic_vectoring};
+#[cfg(feature = "trace")]
+pub use crate::kernel::cordic::circular_rotation_traced;
+pub use crate::kernel::cordic::{
+    OverflowPolicy, Saturating, Wrapping, circular_vectoring, circular_vectoring_with,
+    hyperbolic_vectoring, hyperbolic_vectoring_with,
+};
+pub use crate::kernel::gain::{
+    circular_gain_inv_value, hyperbolic_gain_inv_value, hyperbolic_gain_value,
+};
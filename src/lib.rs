@@ -21,14 +21,19 @@
 //! # Available Functions
 //!
 //! **Total functions** return `T` directly, saturating on overflow.
-//! **Fallible functions** return [`Result<T, Error>`] on domain violations.
+//! **Fallible functions** return [`Result<T, Error>`] on domain violations, or on
+//! overflow for the `try_*` variants of saturating functions.
 //!
 //! | Category | Total | Fallible |
 //! |--------------|-------|----------|
-//! | Trigonometric | [`sin`], [`cos`], [`tan`], [`sin_cos`], [`atan`], [`atan2`] | [`asin`], [`acos`] |
-//! | Hyperbolic | [`sinh`], [`cosh`], [`tanh`], [`sinh_cosh`], [`asinh`] | [`acosh`], [`atanh`], [`acoth`], [`coth`] |
-//! | Exponential | [`exp`], [`pow2`] | [`ln`], [`log2`], [`log10`] |
-//! | Algebraic | — | [`sqrt`] |
+//! | Trigonometric | [`sin`], [`cos`], [`tan`], [`sin_cos`], [`atan`], [`atan2`], [`normalize_angle`], [`angle_diff`], [`asin_clamped`], [`acos_clamped`], [`sinc`], [`sinc_pi`], [`trig_all`], [`atan_with`], [`sin_cos_with`], [`atan2_quadrant`], [`sin_cos_turns`], [`reduce_periodic`], [`range_bearing`], [`tan_half`], [`atan2_deg`], [`atan2_turns`] | [`asin`], [`acos`], [`try_atan2`], [`normalize2`], [`sin_cos_checked`], [`asin_with`], [`acos_with`] |
+//! | Hyperbolic | [`sinh`], [`cosh`], [`tanh`], [`sinh_cosh`], [`asinh`], [`sinh_cosh_tanh`], [`cosh_max_arg`], [`sinh_max_arg`] | [`acosh`], [`atanh`], [`acoth`], [`coth`], [`try_sinh_cosh`], [`try_sinh`], [`try_cosh`], [`sinh_with`], [`atanh_with`] |
+//! | Exponential | [`exp`], [`pow2`], [`powi`], [`logaddexp`], [`pow2_clamped`], [`exp_monotone_slice`], [`exp_max_arg`] | [`ln`], [`log2`], [`log10`], [`try_exp`], [`try_pow2`], [`pow`], [`exp_with`], [`ln_with`], [`PowBase`] |
+//! | Complex | [`complex_mul`], [`twiddle`], [`cexp`], [`cexp_scaled`], [`csin`], [`ccos`] | — |
+//! | Special | [`erf`], [`erfc`] | [`gamma`], [`lgamma`] |
+//! | Algebraic | [`sqrt_i16f16`], [`isqrt`], [`sqrt_cordic`] | [`sqrt`], [`sqrt_with`], [`normalize3`] |
+//! | Array | [`map_array`], [`sin_array`], [`cos_array`], [`exp_array`] | — |
+//! | Interpolation | [`lerp`], [`smoothstep`], [`ema_step`] | — |
 //!
 //! Functions use polynomial evaluation, CORDIC, and Newton-Raphson techniques.
 //! Complete absence of panic is verified at the linker level via the
@@ -44,9 +49,34 @@
 //! All functions are benchmarked against MPFR reference implementations.
 //! Accuracy regressions are not permitted across releases.
 //!
+//! # Reproducibility
+//!
+//! Results use integer arithmetic only, so for a given crate version and
+//! feature set they are bit-identical on every target and optimization
+//! level. The exact bits of [`sin`], [`exp`], [`sqrt`] and [`ln`] are pinned
+//! by tests for `I16F16` and `I32F32`; a release that changes them says so.
+//! `fast-math` and `small-tables` select different algorithms and give
+//! different, equally reproducible, bits.
+//!
 //! # Features
 //!
 //! - **`std`** (default): Enables `std::error::Error` impl on [`Error`]
+//! - **`defmt`**: Implements `defmt::Format` for [`Error`] for logging on embedded targets
+//! - **`serde`**: Implements `Serialize` and `Deserialize` for [`Error`]
+//! - **`fast-math`**: Uses shorter polynomials in [`sin_cos`] and [`sinh_cosh`]
+//!   for all types, trading accuracy (~4 digits for sin/cos) for speed
+//! - **`tanh-table`**: Interpolates [`tanh`] from a 2 KiB table for |x| < 4,
+//!   trading accuracy (~2.5e-5 absolute) for speed
+//! - **`small-tables`**: Runs the CORDIC kernels from angle tables of 32
+//!   `i32` entries (I1F31) instead of 64 `i64`, saving 768 bytes of flash.
+//!   Meant for types of up to 32 bits: the angles resolve only to 2⁻³¹, so
+//!   wider types lose accuracy, and `I32F32` may gain a few ULPs of error in
+//!   the CORDIC-based functions. The public [`tables`] are unchanged
+//! - **`trace`**: Adds `kernel::circular_rotation_traced`, which reports the
+//!   CORDIC residual after each iteration, and `ops::algebraic::sqrt_traced`,
+//!   which reports each Newton-Raphson step
+//! - **`num-traits`**: Provides `Transcendental`, float-style methods for generic
+//!   code written against `num_traits`
 //!
 //! See the [`kernel`] module for algorithm details.
 
@@ -54,21 +84,46 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod bounded;
+pub mod config;
+pub mod display;
 pub mod error;
 pub mod kernel;
 pub mod ops;
+pub mod series;
 pub mod tables;
 pub mod traits;
+#[cfg(feature = "num-traits")]
+pub mod transcendental;
 
 // Re-export the fixed crate for convenience
 pub use fixed;
 
 // Re-export main types
+pub use config::{CordicConfig, Saturation};
+pub use display::Decimal;
 pub use error::{Error, Result};
-pub use traits::CordicNumber;
+pub use kernel::{circular_gain_inv_value, hyperbolic_gain_inv_value, hyperbolic_gain_value};
+pub use traits::{CordicNumber, try_from_f64};
+#[cfg(feature = "num-traits")]
+pub use transcendental::Transcendental;
 
 // Re-export all mathematical functions at crate root for convenience
-pub use ops::algebraic::sqrt;
-pub use ops::circular::{acos, asin, atan, atan2, cos, sin, sin_cos, tan};
-pub use ops::exponential::{exp, ln, log2, log10, pow2};
-pub use ops::hyperbolic::{acosh, acoth, asinh, atanh, cosh, coth, sinh, sinh_cosh, tanh};
+pub use ops::algebraic::{isqrt, normalize3, sqrt, sqrt_cordic, sqrt_i16f16, sqrt_with};
+pub use ops::array::{cos_array, exp_array, map_array, sin_array};
+pub use ops::circular::{
+    acos, acos_clamped, acos_with, angle_diff, asin, asin_clamped, asin_with, atan, atan_with,
+    atan2, atan2_deg, atan2_quadrant, atan2_turns, cos, normalize_angle, normalize2, range_bearing,
+    reduce_periodic, sin, sin_cos, sin_cos_checked, sin_cos_turns, sin_cos_with, sinc, sinc_pi,
+    tan, tan_half, trig_all, try_atan2,
+};
+pub use ops::complex::{ccos, cexp, cexp_scaled, complex_mul, csin, twiddle};
+pub use ops::exponential::{
+    PowBase, exp, exp_max_arg, exp_monotone_slice, exp_with, ln, ln_with, log2, log10, logaddexp,
+    pow, pow2, pow2_clamped, powi, try_exp, try_pow2,
+};
+pub use ops::hyperbolic::{
+    acosh, acoth, asinh, atanh, atanh_with, cosh, cosh_max_arg, coth, sinh, sinh_cosh,
+    sinh_cosh_tanh, sinh_max_arg, sinh_with, tanh, try_cosh, try_sinh, try_sinh_cosh,
+};
+pub use ops::interp::{ema_step, lerp, smoothstep};
+pub use ops::special::{erf, erfc, gamma, lgamma};
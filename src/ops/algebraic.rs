@@ -1,7 +1,10 @@
-//! Algebraic functions (sqrt).
+//! Algebraic functions (sqrt, 3D normalization).
 
 use crate::bounded::NonNegative;
+use crate::config::{CordicConfig, Saturation};
 use crate::error::{Error, Result};
+use crate::kernel::hyperbolic_vectoring;
+use crate::tables::hyperbolic::HYPERBOLIC_GAIN_INV_MINUS_ONE;
 use crate::traits::CordicNumber;
 
 /// Square root. Domain: `x ≥ 0`. Uses Newton-Raphson iteration.
@@ -13,7 +16,7 @@ use crate::traits::CordicNumber;
 pub fn sqrt<T: CordicNumber>(x: T) -> Result<T> {
     NonNegative::new(x)
         .map(sqrt_nonneg)
-        .ok_or_else(|| Error::domain("sqrt", "non-negative value"))
+        .ok_or_else(|| Error::domain_at("sqrt", "non-negative value", x))
 }
 
 /// Infallible square root for non-negative values.
@@ -26,7 +29,54 @@ pub fn sqrt<T: CordicNumber>(x: T) -> Result<T> {
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn sqrt_nonneg<T: CordicNumber>(x: NonNegative<T>) -> T {
-    let x = x.get();
+    sqrt_nonneg_n(x.get(), CordicConfig::new().newton_iterations::<T>())
+}
+
+/// [`sqrt`] with the Newton-Raphson iteration limit and out-of-range
+/// policy taken from `config`. Under [`Saturation::Clamp`] negative inputs
+/// give 0.
+///
+/// # Errors
+/// Returns `DomainError` if `x < 0`, unless `config` clamps.
+#[must_use = "returns the square root result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sqrt_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    let iterations = config.newton_iterations::<T>();
+    if config.saturation() == Saturation::Clamp {
+        return Ok(sqrt_nonneg_n(NonNegative::clamp(x).get(), iterations));
+    }
+    NonNegative::new(x)
+        .map(|x| sqrt_nonneg_n(x.get(), iterations))
+        .ok_or_else(|| Error::domain_at("sqrt", "non-negative value", x))
+}
+
+/// [`sqrt`], reporting each Newton-Raphson step.
+///
+/// `trace` is called after every step with the step index and the new
+/// estimate, before rounding to nearest. The number of calls is the number
+/// of steps taken: fewer than the configured limit when the estimate
+/// converges early, none for 0 and 1.
+///
+/// Only available with the `trace` feature.
+///
+/// # Errors
+/// Returns `DomainError` if `x < 0`.
+#[cfg(feature = "trace")]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sqrt_traced<T: CordicNumber>(x: T, mut trace: impl FnMut(u32, T)) -> Result<T> {
+    let iterations = CordicConfig::new().newton_iterations::<T>();
+    NonNegative::new(x)
+        .map(|x| sqrt_newton(x.get(), iterations, &mut trace))
+        .ok_or_else(|| Error::domain_at("sqrt", "non-negative value", x))
+}
+
+/// Newton-Raphson square root of `x ≥ 0` with at most `iterations` steps.
+#[inline]
+fn sqrt_nonneg_n<T: CordicNumber>(x: T, iterations: u32) -> T {
+    sqrt_newton(x, iterations, &mut |_, _| {})
+}
+
+fn sqrt_newton<T: CordicNumber>(x: T, iterations: u32, trace: &mut impl FnMut(u32, T)) -> T {
     let zero = T::zero();
     let one = T::one();
     let half = T::half();
@@ -41,50 +91,50 @@ pub fn sqrt_nonneg<T: CordicNumber>(x: NonNegative<T>) -> T {
 
     // Initial guess: use bit-level estimation for faster convergence
     // For sqrt(x), a good initial estimate is 2^(floor(log2(x))/2)
-    let mut guess = if x > one {
-        // For large numbers, use bit estimation: sqrt(x) ≈ 2^(log2(x)/2)
-        // We find the approximate position by successive squaring comparison
-        let mut g = one;
+    // We find the approximate position by successive squaring comparison,
+    // scaling by 4 toward [1/4, 4) while g moves by 2 the other way.
+    let mut g = one;
+    let mut test = x;
+    // Guard: max 64 iterations sufficient for any representable value
+    let mut iter_guard = 0u32;
+    let mut halvings = 0u32;
+    if x > one {
         let four = T::two().saturating_mul(T::two());
-        let mut test = x;
-        // Guard: max 64 iterations sufficient for any representable value
-        let mut iter_guard = 0u32;
         while test >= four && iter_guard < 64 {
             test = test >> 2;
             g = g << 1;
             iter_guard += 1;
         }
-        // g is now approximately sqrt(x) rounded down to a power of 2
-        // Refine: average with x/g for a better starting point
-        let quotient = x.div(g);
-        g.saturating_add(quotient) >> 1
     } else {
-        // For numbers < 1, x is a reasonable starting point
-        // since sqrt(x) > x when 0 < x < 1
-        x
-    };
+        // Starting from x itself would only halve the error per step until
+        // the guess nears √x, exhausting the iterations for tiny inputs.
+        let quarter = one >> 2;
+        while test < quarter && iter_guard < 64 {
+            test = test << 2;
+            g = g >> 1;
+            iter_guard += 1;
+            halvings += 1;
+        }
+    }
+    // g is now approximately sqrt(x) rounded to a power of 2
+    // Refine: average with x/g for a better starting point
+    let mut guess = g.saturating_add(x.div(g)) >> 1;
 
     // Newton-Raphson iteration: x_new = (x_old + n/x_old) / 2
-    // Number of iterations depends on precision needed.
-    // Newton-Raphson for sqrt converges quadratically, so 8-20 iterations
-    // is sufficient for any fixed-point precision up to 128 bits.
-    let iterations = (T::frac_bits() / 2).clamp(8, 20);
-
-    // Pre-compute epsilon: approximately 2^(-frac_bits/2) for convergence check.
-    // frac_bits ≤ 128 for all supported types, so shift is in range [0, 63].
-    #[allow(
-        clippy::cast_possible_wrap,
-        clippy::cast_sign_loss,
-        reason = "frac_bits bounded by type size"
-    )]
-    let epsilon_shift = (63i32 - (T::frac_bits() / 2) as i32).max(0) as u32;
-    let epsilon = T::from_i1f63(1i64 << epsilon_shift);
+
+    // Convergence threshold: approximately 2^(-frac_bits/2). A step of d
+    // leaves an error of about d²/2√x, so for √x ≈ 2^-h the threshold
+    // shrinks by 2^(h/2) to keep that error within a couple of ULPs.
+    let frac_bits = T::frac_bits();
+    let epsilon = T::epsilon() << (frac_bits - frac_bits / 2).saturating_sub(halvings.div_ceil(2));
 
     // Run iterations - 1 times with early exit on convergence
-    for _ in 0..iterations.saturating_sub(1) {
+    let last = iterations.saturating_sub(1);
+    for i in 0..last {
         let quotient = x.div(guess);
         let sum = guess.saturating_add(quotient);
         let new_guess = sum.saturating_mul(half);
+        trace(i, new_guess);
 
         let diff = if new_guess > guess {
             new_guess.saturating_sub(guess)
@@ -93,14 +143,216 @@ pub fn sqrt_nonneg<T: CordicNumber>(x: NonNegative<T>) -> T {
         };
 
         if diff <= epsilon {
-            return new_guess;
+            return round_root(x, new_guess);
         }
 
         guess = new_guess;
     }
 
-    // Final iteration - always performed, result always returned
+    // Final iteration - always performed
     let quotient = x.div(guess);
     let sum = guess.saturating_add(quotient);
-    sum.saturating_mul(half)
+    let new_guess = sum.saturating_mul(half);
+    trace(last, new_guess);
+    round_root(x, new_guess)
+}
+
+/// Moves a converged root estimate to the representable value nearest √x.
+///
+/// (r ± δ/2)² = r·(r ± δ) + δ²/4, so the sign of the residual x - r·(r ± δ)
+/// decides whether a neighbor is closer. [`CordicNumber::mul_add`] keeps the
+/// product at full width and rounds toward -∞, which preserves that sign; a
+/// separate multiply would round it away. Newton leaves the estimate within
+/// a few δ, so a handful of steps suffices.
+#[inline]
+fn round_root<T: CordicNumber>(x: T, mut root: T) -> T {
+    let zero = T::zero();
+    let delta = T::epsilon();
+    for _ in 0..4 {
+        let up = root.saturating_add(delta);
+        let down = root.saturating_sub(delta);
+        if (-root).mul_add(up, x) >= zero {
+            root = up;
+        } else if (-root).mul_add(down, x) < zero {
+            root = down;
+        } else {
+            break;
+        }
+    }
+    root
+}
+
+/// Unit vector in the direction of `(x, y, z)`.
+///
+/// The components are first divided by the largest magnitude among them, so
+/// the sum of squares lies in `[1, 3]` and cannot overflow however large the
+/// inputs are. The scaled vector is then multiplied by the reciprocal of its
+/// length. Each output component is within a few ULPs of the exact value.
+///
+/// # Errors
+/// Returns `DomainError` for the zero vector, which has no direction.
+#[must_use = "returns the normalized vector which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn normalize3<T: CordicNumber>(x: T, y: T, z: T) -> Result<(T, T, T)> {
+    let zero = T::zero();
+    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+    let largest = if ax > ay { ax } else { ay };
+    let largest = if largest > az { largest } else { az };
+    if largest == zero {
+        return Err(Error::domain("normalize3", "non-zero (x, y, z)"));
+    }
+
+    let scaled = [x.div(largest), y.div(largest), z.div(largest)];
+    let sum = scaled
+        .iter()
+        .fold(zero, |acc, &c| acc.saturating_add(c.saturating_mul(c)));
+    let inv_length = T::one().div(sqrt_nonneg(NonNegative::clamp(sum)));
+    Ok(scaled.map(|c| c.saturating_mul(inv_length)).into())
+}
+
+/// Integer square root, `floor(√x)`, for integer types such as `I32F0`.
+///
+/// When `T` has no fractional bits this uses the exact bit-by-bit restoring
+/// method, which is faster than Newton-Raphson. Otherwise it falls back to
+/// [`sqrt`]. Negative inputs return 0.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn isqrt<T: CordicNumber>(x: T) -> T {
+    let zero = T::zero();
+    let Some(x) = NonNegative::new(x) else {
+        return zero;
+    };
+    if T::frac_bits() != 0 {
+        return sqrt_nonneg(x);
+    }
+
+    let mut rem = x.get();
+    let mut root = zero;
+    // Highest power of four representable in T.
+    let mut bit = T::one() << (T::total_bits() - 2);
+    while bit > rem {
+        bit = bit >> 2;
+    }
+
+    while bit != zero {
+        let trial = root + bit;
+        if rem >= trial {
+            rem -= trial;
+            root = (root >> 1) + bit;
+        } else {
+            root = root >> 1;
+        }
+        bit = bit >> 2;
+    }
+    root
+}
+
+/// Square root by hyperbolic CORDIC, using only shifts, additions and
+/// multiplication by a constant. Negative inputs return 0.
+///
+/// Since `(w + b)² - (w - b)² = 4wb`, vectoring from `((w + b)/2, (w - b)/2)`
+/// leaves `K_h·√(wb)` in x. Choosing `b` as the power of four with
+/// `b ≤ w < 4b` keeps `|y/x| < 0.6`, inside the kernel's convergence range,
+/// and makes `√b` a shift. The input is first scaled up by powers of four,
+/// which is exact, so the kernel works on as many significant bits as the
+/// type has. Accurate to about 1 ULP. Types with fewer than two fractional
+/// bits use [`isqrt`].
+///
+/// Intended for targets without a hardware divider; where division is cheap,
+/// [`sqrt`] is faster and rounds to nearest.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sqrt_cordic<T: CordicNumber>(x: T) -> T {
+    let zero = T::zero();
+    if x <= zero {
+        return zero;
+    }
+    // Powers of four below one need at least two fractional bits.
+    if T::frac_bits() < 2 {
+        return isqrt(x);
+    }
+
+    // Scale up by powers of four: √x = √w / 2^j with w = x·4^j ∈ (MAX/8, MAX/2]
+    let limit = T::max_value() >> 3;
+    let mut w = x;
+    let mut up_shifts: i32 = 0;
+    while w <= limit {
+        w = w << 2;
+        up_shifts += 1;
+    }
+
+    // b = 4^m, the largest power of four not above w (m ≥ -1 since w ≥ ½)
+    let mut pow4 = T::one() >> 2;
+    let mut pow4_exp: i32 = -1;
+    while pow4 <= w >> 2 {
+        pow4 = pow4 << 2;
+        pow4_exp += 1;
+    }
+
+    let half_w = w >> 1;
+    let half_b = pow4 >> 1;
+    let (scaled_root, _, _) = hyperbolic_vectoring(half_w + half_b, half_w - half_b, zero);
+
+    // scaled_root = K_h·2^m·√w. scaled_root is large after the scaling above,
+    // so 1/K_h must be applied with more precision than T holds: split the
+    // constant into the part T represents and the bits below it.
+    let frac_bits = T::frac_bits();
+    let gain_hi = T::from_i1f63(HYPERBOLIC_GAIN_INV_MINUS_ONE);
+    let gain_lo = if frac_bits < 63 {
+        let below = HYPERBOLIC_GAIN_INV_MINUS_ONE & ((1 << (63 - frac_bits)) - 1);
+        T::from_i1f63(below << frac_bits)
+    } else {
+        zero
+    };
+    let root = scaled_root
+        .saturating_add(scaled_root.saturating_mul(gain_hi))
+        .saturating_add(scaled_root.saturating_mul(gain_lo) >> frac_bits);
+    match (pow4_exp + up_shifts).cast_unsigned() {
+        u32::MAX => root << 1,
+        shift => root >> shift,
+    }
+}
+
+/// Square root of an `I16F16` given and returned as raw bits, usable in `const`
+/// context.
+///
+/// A const-only companion to the generic [`sqrt`], for lookup tables and
+/// calibration constants computed at compile time. Uses integer Newton-Raphson
+/// iteration, so the result is truncated rather than rounded. Negative inputs
+/// return 0.
+///
+/// ```
+/// use fixed::types::I16F16;
+/// use fixed_analytics::sqrt_i16f16;
+///
+/// const SQRT_2: I16F16 = I16F16::from_bits(sqrt_i16f16(I16F16::from_bits(2 << 16).to_bits()));
+/// assert!((SQRT_2.to_num::<f32>() - core::f32::consts::SQRT_2).abs() < 1e-4);
+/// ```
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    reason = "root of a 47-bit value fits in i32"
+)]
+pub const fn sqrt_i16f16(x: i32) -> i32 {
+    if x <= 0 {
+        return 0;
+    }
+
+    // sqrt(x / 2^16) * 2^16 = sqrt(x * 2^16), computed on the widened bits.
+    let n = (x.unsigned_abs() as u64) << 16;
+
+    // Start from a power of two at or above the root; Newton then descends
+    // monotonically to floor(sqrt(n)).
+    let mut guess = 1u64 << (u64::BITS - n.leading_zeros()).div_ceil(2);
+    loop {
+        let next = u64::midpoint(guess, n / guess);
+        if next >= guess {
+            break;
+        }
+        guess = next;
+    }
+
+    // n < 2^47, so the root is below 2^24 and fits in i32.
+    guess as i32
 }
@@ -0,0 +1,37 @@
+//! Element-wise evaluation over fixed-size arrays.
+//!
+//! For `no_std` code that keeps samples in `[T; N]` rather than heap slices.
+//! The concrete `*_array` functions give the compiler a known length and a
+//! known kernel, so small `N` can be unrolled.
+
+use crate::ops::circular::{cos, sin};
+use crate::ops::exponential::exp;
+use crate::traits::CordicNumber;
+
+/// Applies `f` to every element of `input`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn map_array<T: CordicNumber, const N: usize>(input: [T; N], f: impl Fn(T) -> T) -> [T; N] {
+    input.map(f)
+}
+
+/// [`sin`] of every element.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sin_array<T: CordicNumber, const N: usize>(input: [T; N]) -> [T; N] {
+    map_array(input, sin)
+}
+
+/// [`cos`] of every element.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn cos_array<T: CordicNumber, const N: usize>(input: [T; N]) -> [T; N] {
+    map_array(input, cos)
+}
+
+/// [`exp`] of every element, saturating as `exp` does.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn exp_array<T: CordicNumber, const N: usize>(input: [T; N]) -> [T; N] {
+    map_array(input, exp)
+}
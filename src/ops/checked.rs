@@ -0,0 +1,29 @@
+//! Overflow-reporting variants of the saturating functions.
+//!
+//! The functions in [`crate::ops`] that can exceed the range of `T` saturate
+//! silently. The ones in the table below have a `try_*` counterpart that
+//! returns [`Error::Overflow`](crate::Error::Overflow) instead, collected here
+//! so they can be imported together:
+//!
+//! ```
+//! use fixed::types::I16F16;
+//! use fixed_analytics::ops::checked::*;
+//!
+//! assert!(try_exp(I16F16::from_num(5)).is_ok());
+//! assert!(try_exp(I16F16::from_num(20)).is_err());
+//! ```
+//!
+//! | Saturating | Checked |
+//! |------------|---------|
+//! | [`exp`](crate::exp) | [`try_exp`] |
+//! | [`pow2`](crate::pow2) | [`try_pow2`] |
+//! | [`sinh`](crate::sinh) | [`try_sinh`] |
+//! | [`cosh`](crate::cosh) | [`try_cosh`] |
+//! | [`sinh_cosh`](crate::sinh_cosh) | [`try_sinh_cosh`] |
+//!
+//! Underflow towards zero is never reported. Other saturating functions, such
+//! as [`pow`](crate::pow), [`PowBase::pow`](crate::PowBase::pow) and
+//! [`powi`](crate::powi), have no checked form.
+
+pub use crate::ops::exponential::{try_exp, try_pow2};
+pub use crate::ops::hyperbolic::{try_cosh, try_sinh, try_sinh_cosh};
@@ -1,40 +1,191 @@
 //! Trigonometric functions via circular CORDIC.
 
 use crate::bounded::{NonNegative, UnitInterval};
+use crate::config::{CordicConfig, Saturation};
 use crate::error::{Error, Result};
-use crate::kernel::circular_vectoring;
+use crate::kernel::{
+    Saturating, circular_gain_inv_value, circular_rotation_n, circular_vectoring,
+    circular_vectoring_n,
+};
 use crate::ops::algebraic::sqrt_nonneg;
-use crate::tables::chebyshev::{COS_Q_HI, COS_Q_LO, SIN_P_HI, SIN_P_LO, horner};
+use crate::series::sinc_series;
+use crate::tables::chebyshev::{
+    COS_Q_FAST, COS_Q_HI, COS_Q_LO, SIN_P_FAST, SIN_P_HI, SIN_P_LO, horner,
+};
+use crate::tables::circular::{CIRCULAR_GAIN_INV, DEGREES_PER_RADIAN_DIV_64, FRAC_1_2PI};
 use crate::traits::CordicNumber;
 
+/// Reduces an angle to `[-π, π]`. Accepts any angle.
+///
+/// Angles outside the range are reduced with one exact Euclidean remainder
+/// by 2π, so the cost is constant regardless of the input magnitude.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn normalize_angle<T: CordicNumber>(angle: T) -> T {
+    let pi = T::pi();
+    reduce_periodic(angle, pi + pi).0
+}
+
+/// Reduces `x` to `[-period/2, period/2]`, returning the reduced value and
+/// the number of whole periods `k` removed, so that `x = k·period + reduced`.
+///
+/// This is the primitive behind [`normalize_angle`], for building other
+/// periodic functions. The reduction is one exact Euclidean remainder, so
+/// the reduced value carries no rounding error for any input magnitude.
+///
+/// The sign of `period` is ignored. A zero period returns `(x, 0)`. The
+/// count saturates if it does not fit in `T` or `i32`, which can only
+/// happen for periods below one.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn reduce_periodic<T: CordicNumber>(x: T, period: T) -> (T, i32) {
+    let period = period.abs();
+    let half = period >> 1;
+    if period == T::zero() || (x <= half && x >= -half) {
+        return (x, 0);
+    }
+
+    // x = k·period + r with r ∈ [0, period). Form the exact multiple k·period
+    // from the side that cannot overflow: x - r for x ≥ 0, or
+    // x + (period - r) = (k + 1)·period for x < 0.
+    let r = x.rem_euclid(period);
+    let k = if x.is_negative() {
+        let next = x.saturating_add(period.saturating_sub(r));
+        saturating_to_i32(next.div(period)).saturating_sub(1)
+    } else {
+        saturating_to_i32(x.saturating_sub(r).div(period))
+    };
+
+    // Fold the upper half of [0, period) down to (-period/2, 0).
+    if r > half {
+        (r.saturating_sub(period), k.saturating_add(1))
+    } else {
+        (r, k)
+    }
+}
+
+/// Converts an integer-valued `T` to `i32`, saturating where `T` is wider.
+fn saturating_to_i32<T: CordicNumber>(n: T) -> i32 {
+    if T::total_bits() - T::frac_bits() > 32 {
+        let limit = T::one() << 31;
+        if n >= limit {
+            return i32::MAX;
+        }
+        if n < -limit {
+            return i32::MIN;
+        }
+    }
+    n.to_i32()
+}
+
+/// [`normalize_angle`] with a Cody–Waite correction for the rounding of π.
+///
+/// Removing `k` turns of the type's 2π leaves an error of `k·(2π - 2·PI)`,
+/// which grows to many ULPs for large angles. [`CordicNumber::pi_hi_lo`]
+/// supplies the sub-ULP residual needed to cancel it. Where `T` has more
+/// integer than fractional bits the correction can exceed π, so the
+/// corrected angle is reduced once more.
+fn reduce_angle<T: CordicNumber>(angle: T) -> T {
+    let reduced = normalize_angle(angle);
+    if reduced == angle {
+        return reduced;
+    }
+
+    let (pi_hi, pi_lo) = T::pi_hi_lo();
+    // angle - reduced is an exact multiple of 2·pi_hi.
+    let turns = angle.saturating_sub(reduced).div(pi_hi + pi_hi).round();
+    let ulps = turns.saturating_mul(pi_lo + pi_lo).round();
+    normalize_angle(reduced.saturating_sub(ulps.saturating_mul(T::epsilon())))
+}
+
+/// Shortest signed difference `a - b` between two angles, wrapped to `[-π, π]`.
+///
+/// Both angles are reduced first, so the subtraction cannot overflow even for
+/// large inputs.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn angle_diff<T: CordicNumber>(a: T, b: T) -> T {
+    normalize_angle(normalize_angle(a).saturating_sub(normalize_angle(b)))
+}
+
 /// Sine and cosine. More efficient than separate calls. Accepts any angle.
+///
+/// With the `fast-math` feature, every type uses a degree 5 sin / degree 4
+/// cos polynomial, accurate to roughly 4 decimal digits (absolute error
+/// ≤ 5e-5), in exchange for fewer multiplications.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn sin_cos<T: CordicNumber>(angle: T) -> (T, T) {
-    let pi = T::pi();
-    let frac_pi_2 = T::frac_pi_2();
-    let two_pi = pi + pi;
-
-    // Reduce angle to [-π, π] using direct quotient computation.
-    // This handles arbitrarily large angles without iteration limits.
-    let reduced = if angle > pi || angle < -pi {
-        // Compute n = round(angle / 2π), then reduced = angle - n * 2π
-        let quotient = angle.div(two_pi);
-        let n = quotient.round();
-        angle.saturating_sub(n.saturating_mul(two_pi))
+    let r = reduce_octant(angle);
+    let sp_val = sin_poly(r.poly_arg, r.u);
+    let cp_val = cos_poly(r.u);
+
+    // Map back: if we swapped, sin(x) = cos_poly, cos(x) = sin_poly
+    let (sin_val, cos_val) = if r.swapped {
+        (cp_val, sp_val)
     } else {
-        angle
+        (sp_val, cp_val)
     };
+    (r.apply_sin_sign(sin_val), r.apply_cos_sign(cos_val))
+}
 
-    // Clamp to [-π, π] to handle any residual from saturation.
-    // This is a safety net; mathematically unnecessary for valid inputs.
-    let reduced = if reduced > pi {
-        reduced.saturating_sub(two_pi)
-    } else if reduced < -pi {
-        reduced.saturating_add(two_pi)
+/// [`sin_cos`] with the evaluation method taken from `config`.
+///
+/// [`sin_cos`] evaluates minimax polynomials, which the default
+/// configuration reproduces exactly. A configuration that sets the CORDIC
+/// iteration count instead rotates the angle, reduced to `[0, π/4]`, by that
+/// many micro-rotations; the error then falls roughly as `2^-iterations`
+/// until it reaches the precision of `T`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sin_cos_with<T: CordicNumber>(angle: T, config: &CordicConfig) -> (T, T) {
+    let Some(iterations) = config.explicit_cordic_iterations() else {
+        return sin_cos(angle);
+    };
+    let r = reduce_octant(angle);
+    let (cos_val, sin_val, _) =
+        circular_rotation_n(circular_gain_inv_value::<T>(), r.poly_arg, iterations);
+
+    let (sin_val, cos_val) = if r.swapped {
+        (cos_val, sin_val)
     } else {
-        reduced
+        (sin_val, cos_val)
     };
+    (r.apply_sin_sign(sin_val), r.apply_cos_sign(cos_val))
+}
+
+/// An angle reduced to `[0, π/4]` for the polynomials, with what is needed
+/// to map the results back.
+struct Octant<T> {
+    poly_arg: T,
+    u: T,
+    /// The polynomial argument is `π/2 - |x|`, so sin and cos trade places.
+    swapped: bool,
+    /// The angle was in `[-π/2, π/2]` only after subtracting ±π.
+    negate: bool,
+    /// The angle in `[-π/2, π/2]` was negative.
+    negative: bool,
+}
+
+impl<T: CordicNumber> Octant<T> {
+    fn apply_sin_sign(&self, value: T) -> T {
+        if self.negative == self.negate {
+            value
+        } else {
+            -value
+        }
+    }
+
+    fn apply_cos_sign(&self, value: T) -> T {
+        if self.negate { -value } else { value }
+    }
+}
+
+fn reduce_octant<T: CordicNumber>(angle: T) -> Octant<T> {
+    let pi = T::pi();
+    let frac_pi_2 = T::frac_pi_2();
+
+    let reduced = reduce_angle(angle);
 
     // Further reduce to [-π/2, π/2] and track sign
     let (reduced, negate) = if reduced > frac_pi_2 {
@@ -45,11 +196,9 @@ pub fn sin_cos<T: CordicNumber>(angle: T) -> (T, T) {
         (reduced, false)
     };
 
-    // Polynomial evaluation via factored Horner form.
     // To avoid catastrophic cancellation near π/2, reduce to [0, π/4]:
     //   For |x| ∈ [0, π/4]:      sin(x) = sin_poly(x), cos(x) = cos_poly(x)
     //   For |x| ∈ (π/4, π/2]:    sin(x) = cos_poly(π/2-|x|), cos(x) = sin_poly(π/2-|x|)
-    let one = T::one();
     let frac_pi_4 = T::frac_pi_4();
     let abs_reduced = reduced.abs();
     let (poly_arg, swapped) = if abs_reduced >= frac_pi_4 {
@@ -57,45 +206,132 @@ pub fn sin_cos<T: CordicNumber>(angle: T) -> (T, T) {
     } else {
         (abs_reduced, false)
     };
-    let u = poly_arg.saturating_mul(poly_arg);
-
-    // Evaluate sin and cos polynomials over [0, π/4] using minimax
-    // (Chebyshev) coefficients. Uses multiply-by-constant instead of
-    // division, avoiding cumulative rounding error from per-step divides.
-    //
-    // sin(x) = x + x³·P(x²)   where P = minimax poly of (sin(x)-x)/x³
-    // cos(x) = 1 + x²·Q(x²)   where Q = minimax poly of (cos(x)-1)/x²
-    let (sp_val, cp_val) = if T::frac_bits() >= 24 {
-        // High precision: degree 15 sin, degree 14 cos
-        let sp = horner(&SIN_P_HI, u);
-        let sin_approx = poly_arg.saturating_add(poly_arg.saturating_mul(u).saturating_mul(sp));
-        let cp = horner(&COS_Q_HI, u);
-        (sin_approx, one.saturating_add(u.saturating_mul(cp)))
+    Octant {
+        poly_arg,
+        u: poly_arg.saturating_mul(poly_arg),
+        swapped,
+        negate,
+        negative: reduced < T::zero(),
+    }
+}
+
+// sin and cos polynomials over [0, π/4] use minimax (Chebyshev)
+// coefficients, multiplying by constants instead of dividing, which avoids
+// cumulative rounding error from per-step divides.
+//
+// sin(x) = x + x³·P(x²)   where P = minimax poly of (sin(x)-x)/x³
+// cos(x) = 1 + x²·Q(x²)   where Q = minimax poly of (cos(x)-1)/x²
+
+/// `sin(x)` for `x ∈ [0, π/4]`, given `u = x²`.
+fn sin_poly<T: CordicNumber>(x: T, u: T) -> T {
+    let sp = if cfg!(feature = "fast-math") {
+        // Fast: degree 5
+        horner(&SIN_P_FAST, u)
+    } else if T::frac_bits() >= 24 {
+        // High precision: degree 15
+        horner(&SIN_P_HI, u)
+    } else {
+        // Low precision: degree 9
+        horner(&SIN_P_LO, u)
+    };
+    x.saturating_add(x.saturating_mul(u).saturating_mul(sp))
+}
+
+/// `cos(x)` for `x ∈ [0, π/4]`, given `u = x²`.
+fn cos_poly<T: CordicNumber>(u: T) -> T {
+    let cp = if cfg!(feature = "fast-math") {
+        // Fast: degree 4
+        horner(&COS_Q_FAST, u)
+    } else if T::frac_bits() >= 24 {
+        // High precision: degree 14
+        horner(&COS_Q_HI, u)
     } else {
-        // Low precision: degree 9 sin, degree 8 cos
-        let sp = horner(&SIN_P_LO, u);
-        let sin_approx = poly_arg.saturating_add(poly_arg.saturating_mul(u).saturating_mul(sp));
-        let cp = horner(&COS_Q_LO, u);
-        (sin_approx, one.saturating_add(u.saturating_mul(cp)))
+        // Low precision: degree 8
+        horner(&COS_Q_LO, u)
     };
+    T::one().saturating_add(u.saturating_mul(cp))
+}
 
-    // Map back: if we swapped, sin(x) = cos_poly, cos(x) = sin_poly
-    // Also restore sign of sin for negative angles.
-    let (sin_unsigned, cos_val) = if swapped {
-        (cp_val, sp_val)
+/// Sine alone: evaluates only the polynomial that [`sin_cos`] would return
+/// as the sine, so the result is identical without the cost of the other.
+fn sin_only<T: CordicNumber>(angle: T) -> T {
+    let r = reduce_octant(angle);
+    let value = if r.swapped {
+        cos_poly(r.u)
     } else {
-        (sp_val, cp_val)
+        sin_poly(r.poly_arg, r.u)
     };
-    let sin_val = if reduced < T::zero() {
-        -sin_unsigned
+    r.apply_sin_sign(value)
+}
+
+/// Cosine alone; see [`sin_only`].
+fn cos_only<T: CordicNumber>(angle: T) -> T {
+    let r = reduce_octant(angle);
+    let value = if r.swapped {
+        sin_poly(r.poly_arg, r.u)
     } else {
-        sin_unsigned
+        cos_poly(r.u)
     };
+    r.apply_cos_sign(value)
+}
 
-    if negate {
-        (-sin_val, -cos_val)
+/// [`sin_cos`] that rejects angles too large to reduce meaningfully.
+///
+/// Reducing `angle` subtracts `n` turns with `n ≈ angle / 2π`. The rounding
+/// of `2π` in `T` is cancelled with the residual from
+/// [`CordicNumber::pi_hi_lo`], but that residual is itself held to one ULP
+/// of `T`, so the reduced angle is still off by up to `(2n·ε + 1/2)·ε`
+/// radians, where ε is one ULP. Beyond `|angle| > π·2^(2·frac_bits - 5)`
+/// this can exceed 2⁻⁴, leaving fewer than 4 meaningful fractional bits.
+///
+/// Types with at least as many fractional as integer bits, such as I16F16
+/// and I32F32, never reach the threshold; for I24F8 it is `|angle| > ~6434`
+/// and for I48F16 `|angle| > ~4.2e8`.
+///
+/// # Errors
+/// Returns `Overflow` if `|angle|` exceeds the threshold above.
+#[must_use = "returns the sine and cosine which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sin_cos_checked<T: CordicNumber>(angle: T) -> Result<(T, T)> {
+    let frac_bits = T::frac_bits();
+    let int_bits = T::total_bits() - frac_bits;
+    let magnitude = angle.abs();
+    // |angle| ≤ 2^(int_bits - 1) < π·2^(int_bits - 2), so from that shift
+    // on no angle is too large.
+    let too_large = if frac_bits < 3 {
+        magnitude > T::pi()
     } else {
-        (sin_val, cos_val)
+        let shift = 2 * frac_bits - 5;
+        shift < int_bits.saturating_sub(2) && magnitude >> shift > T::pi()
+    };
+    if too_large {
+        return Err(Error::overflow("sin_cos"));
+    }
+    Ok(sin_cos(angle))
+}
+
+/// Sine and cosine of an angle in turns (1 turn = 2π radians).
+///
+/// The turn count is reduced modulo 1 and split into whole quarter turns
+/// exactly, with no rounded 2π involved, so large inputs lose no accuracy
+/// and multiples of a quarter turn give exact results.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sin_cos_turns<T: CordicNumber>(turns: T) -> (T, T) {
+    let one = T::one();
+    let t = turns.rem_euclid(one);
+
+    // t ∈ [0, 1): quadrant = floor(4t), remainder r ∈ [0, 1) quarter turns.
+    let quarters = t << 2;
+    let quadrant = quarters.to_i32();
+    let r = quarters.saturating_sub(T::from_num(quadrant));
+    let (s, c) = sin_cos(r.saturating_mul(T::frac_pi_2()));
+
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
     }
 }
 
@@ -104,7 +340,7 @@ pub fn sin_cos<T: CordicNumber>(angle: T) -> (T, T) {
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn sin<T: CordicNumber>(angle: T) -> T {
-    sin_cos(angle).0
+    sin_only(angle)
 }
 
 /// Cosine. Accepts any angle (reduced internally).
@@ -112,11 +348,16 @@ pub fn sin<T: CordicNumber>(angle: T) -> T {
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn cos<T: CordicNumber>(angle: T) -> T {
-    sin_cos(angle).1
+    cos_only(angle)
 }
 
 /// Tangent. Returns `sin(angle) / cos(angle)`.
 ///
+/// Within π/8 of ±π/4 the quotient is instead read off a dedicated CORDIC
+/// rotation (one rounding rather than three), which keeps the error near
+/// one ULP where the quotient of rounded sine and cosine reaches two to
+/// three. The `fast-math` feature always takes the quotient.
+///
 /// # Overflow Behavior
 ///
 /// Tangent has poles at ±π/2, ±3π/2, etc. where it approaches ±∞.
@@ -156,8 +397,174 @@ pub fn cos<T: CordicNumber>(angle: T) -> T {
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn tan<T: CordicNumber>(angle: T) -> T {
+    tan_near_diagonal(angle).unwrap_or_else(|| {
+        let (s, c) = sin_cos(angle);
+        s.saturating_div(c)
+    })
+}
+
+/// Tangent from the half-angle tangent: `tan(x) = 2t / (1 - t²)` where
+/// `t = tan(x/2)`.
+///
+/// For callers that already hold `tan(x/2)`, e.g. from a rational
+/// parametrization of the circle, and as an independent cross-check of
+/// [`tan`]. The denominator is formed as `(1 - t)(1 + t)`, which rounds once
+/// instead of cancelling after squaring.
+///
+/// # Saturation Behavior
+///
+/// At `t = ±1` (`x = ±π/2`) the denominator is zero and the result
+/// saturates to `T::MAX` or `T::MIN` by the sign of `t`. Near there it
+/// overflows like [`tan`] does near its poles.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn tan_half<T: CordicNumber>(t: T) -> T {
+    let one = T::one();
+    let denominator = one.saturating_sub(t).saturating_mul(one.saturating_add(t));
+    t.saturating_mul(T::two()).saturating_div(denominator)
+}
+
+/// Sine, cosine, and tangent from a single evaluation.
+///
+/// Returns `(sin, cos, tan)`. Shares one [`sin_cos`] evaluation and derives
+/// tan with one division, rather than reducing the angle three times. Near
+/// ±π/4 tan takes the same CORDIC path as [`tan`], so the two always agree.
+///
+/// Behavior near the poles of tan follows [`tan`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn trig_all<T: CordicNumber>(angle: T) -> (T, T, T) {
     let (s, c) = sin_cos(angle);
-    s.div(c)
+    (
+        s,
+        c,
+        tan_near_diagonal(angle).unwrap_or_else(|| s.saturating_div(c)),
+    )
+}
+
+/// tan of angles within π/8 of ±π/4 (modulo π), or `None` elsewhere.
+///
+/// In that band sin and cos are both large, so each one's rounding reaches
+/// their quotient in full; [`tan_rotation`] avoids the compounding.
+fn tan_near_diagonal<T: CordicNumber>(angle: T) -> Option<T> {
+    // The rotation needs a start vector well above one to bury the shift
+    // rounding, and its 2⁻⁶³ (2⁻³¹ with `small-tables`) table entries summed
+    // over up to 22 iterations must stay below one ULP. Integer types have
+    // no ULP fraction for the rotation to carry.
+    let int_bits = T::total_bits() - T::frac_bits();
+    let table_frac_bits = if cfg!(feature = "small-tables") {
+        31
+    } else {
+        63
+    };
+    if cfg!(feature = "fast-math")
+        || int_bits < 8
+        || T::frac_bits() == 0
+        || T::frac_bits() > table_frac_bits - 7
+    {
+        return None;
+    }
+
+    // tan has period π, so reduce by the type's π directly. That removes an
+    // exact `half_turns` multiples of `pi_hi`; the rotation accounts for the
+    // `half_turns·pi_lo` ULPs still owed to the true π.
+    let (pi_hi, pi_lo) = T::pi_hi_lo();
+    let (reduced, half_turns) = reduce_periodic(angle, pi_hi);
+    if half_turns == i32::MAX || half_turns == i32::MIN {
+        return None;
+    }
+
+    let frac_pi_4 = T::frac_pi_4();
+    let in_band = reduced.abs().saturating_sub(frac_pi_4).abs() <= frac_pi_4 >> 1;
+    in_band.then(|| tan_rotation(reduced, T::from_num(half_turns).saturating_mul(pi_lo)))
+}
+
+/// tan of `reduced - owed·ε` via a scaled CORDIC rotation whose leftover
+/// angle is applied exactly.
+///
+/// Rotating `(s, 0)` by `reduced` gives `y/x = tan(turned)` without any gain
+/// correction, and the large start `s` keeps the shift rounding far below
+/// one ULP of the ratio. The kernel reports the angle turned exactly, so the
+/// residual `r = reduced - owed·ε - turned` is known to a fraction of an ULP
+/// and folded in with `tan(t + r) = (y + r·x) / (x - r·y)`. Enough
+/// iterations run that dropping `r³/3` from `tan(r)` is below `ε/8`.
+///
+/// This leaves a single rounding in the final division, where `s / c` with
+/// a rounded sine and cosine compounds three.
+fn tan_rotation<T: CordicNumber>(reduced: T, owed: T) -> T {
+    let frac_bits = T::frac_bits();
+    // |K·s| < MAX/2 with s = 2^(int_bits - 3).
+    let scale = T::one() << (T::total_bits() - frac_bits - 3);
+    let iterations = (frac_bits / 3 + 2).min(62);
+    let (x, y, turned) = circular_rotation_n(scale, reduced, iterations);
+
+    // Split the turned angle (units of 2⁻⁶³, |turned| < 1.75) into its value
+    // in `T`, rounded down, and the remainder below one ULP.
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "the integer part is within ±2 and the fraction is masked to 63 bits"
+    )]
+    let (int_part, frac_part) = (
+        (turned >> 63) as i32,
+        (turned & i128::from(i64::MAX)) as i64,
+    );
+    let (frac_hi, turned_lo) = split_i1f63::<T>(frac_part);
+    let turned_hi = T::from_num(int_part).saturating_add(frac_hi);
+
+    // r = r_hi - ulps·ε. Whole ULPs go into r_hi exactly; the fraction left
+    // in `ulps` is applied to the scaled vector.
+    let ulps = turned_lo.saturating_add(owed);
+    let whole = T::from_num(ulps.to_i32());
+    let ulps = ulps.saturating_sub(whole);
+    let r_hi = reduced
+        .saturating_sub(turned_hi)
+        .saturating_sub(whole >> frac_bits);
+
+    let num = y
+        .saturating_add(r_hi.saturating_mul(x))
+        .saturating_sub(x.saturating_mul(ulps) >> frac_bits);
+    let den = x
+        .saturating_sub(r_hi.saturating_mul(y))
+        .saturating_add(y.saturating_mul(ulps) >> frac_bits);
+    num.div(den)
+}
+
+/// Unnormalized sinc, `sin(x) / x`, with `sinc(0) = 1`. Accepts any value.
+///
+/// For `|x| < 1/8` the quotient is replaced by its Taylor series
+/// `1 - x²/6·(1 - x²/20·(1 - x²/42))`, which avoids dividing two values
+/// that both round to a few ULPs; the truncation error there is below 2e-13.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sinc<T: CordicNumber>(x: T) -> T {
+    // 1/8 in I1F63
+    if x.abs() < T::from_i1f63(0x1000_0000_0000_0000) {
+        return sinc_series(x, 4);
+    }
+
+    sin(x).div(x)
+}
+
+/// Normalized sinc, `sin(πx) / (πx)`, with `sinc_pi(0) = 1`. Accepts any value.
+///
+/// Zero at every nonzero integer. For `|x| > 1` the argument of `sin` is
+/// first reduced modulo 2, so large inputs neither overflow `πx` nor lose
+/// the phase to rounding.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sinc_pi<T: CordicNumber>(x: T) -> T {
+    let pi = T::pi();
+
+    if x.abs() <= T::one() {
+        return sinc(x.saturating_mul(pi));
+    }
+
+    // sin(πx) = sin(π(x - 2n)) with n = round(x/2). Work on x/2 so that 2n
+    // cannot overflow near T::MAX, and add back the bit the halving drops.
+    let half_x = x >> 1;
+    let dropped = x - (half_x << 1);
+    let reduced = ((half_x - half_x.round()) << 1) + dropped;
+    sin(reduced.saturating_mul(pi)).div(x).div(pi)
 }
 
 /// Arcsine. Domain: `[-1, 1]`. Returns angle in `[-π/2, π/2]`.
@@ -167,36 +574,71 @@ pub fn tan<T: CordicNumber>(angle: T) -> T {
 #[must_use = "returns the arcsine result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn asin<T: CordicNumber>(x: T) -> Result<T> {
-    let Some(unit_x) = UnitInterval::new(x) else {
-        return Err(Error::domain("asin", "value in range [-1, 1]"));
-    };
+    UnitInterval::new(x)
+        .map(asin_unit)
+        .ok_or_else(|| Error::domain_at("asin", "value in range [-1, 1]", x))
+}
+
+/// Arcsine with the input clamped into `[-1, 1]`. Returns angle in `[-π/2, π/2]`.
+///
+/// Inputs that overshoot the domain, such as `1.0001` from a rounded dot
+/// product, give `±π/2` instead of an error.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn asin_clamped<T: CordicNumber>(x: T) -> T {
+    asin_unit(UnitInterval::clamp(x))
+}
+
+/// [`asin`] with the out-of-range policy taken from `config`. Under
+/// [`Saturation::Clamp`] it matches [`asin_clamped`].
+///
+/// # Errors
+/// Returns `DomainError` if `|x| > 1`, unless `config` clamps.
+#[must_use = "returns the arcsine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn asin_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Clamp => Ok(asin_clamped(x)),
+        Saturation::Saturate | Saturation::Error => asin(x),
+    }
+}
+
+/// Infallible arcsine for values in [-1, 1].
+///
+/// This function takes a [`UnitInterval<T>`] wrapper, guaranteeing at the
+/// type level that the input is valid. No domain check is performed at runtime.
+///
+/// Use this when the input is known to be in [-1, 1] through mathematical
+/// invariants (e.g., a component of a unit vector).
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn asin_unit<T: CordicNumber>(unit_x: UnitInterval<T>) -> T {
+    let x = unit_x.get();
 
     // Special cases
     if x == T::one() {
-        return Ok(T::frac_pi_2());
+        return T::frac_pi_2();
     }
     if x == -T::one() {
-        return Ok(-T::frac_pi_2());
+        return -T::frac_pi_2();
     }
     if x == T::zero() {
-        return Ok(T::zero());
-    }
-
-    // Use the identity: asin(x) = atan(x / sqrt(1 - x²))
-    // NonNegative::one_minus_square gives 1 - x², which is ≥ 0 since |x| ≤ 1
-    let sqrt_term = sqrt_nonneg(NonNegative::one_minus_square(unit_x));
-
-    // Handle case where sqrt_term is very small (x close to ±1)
-    if sqrt_term < T::from_i1f63(0x0001_0000_0000_0000) {
-        // Very close to ±1, return ±π/2
-        return if x.is_positive() {
-            Ok(T::frac_pi_2())
-        } else {
-            Ok(-T::frac_pi_2())
-        };
+        return T::zero();
     }
 
-    Ok(atan(x.div(sqrt_term)))
+    // asin(x) is the angle of the unit vector (sqrt(1 - x²), x), so vector
+    // it directly instead of dividing x by the root and taking atan.
+    let abs_x = x.abs();
+    let cos_term = if abs_x > T::one() >> 1 {
+        // 1 - x² is small here and the product (1 - |x|)(1 + |x|) would
+        // truncate away most of its bits; two roots keep them.
+        let one = T::one();
+        sqrt_nonneg(NonNegative::clamp(one.saturating_sub(abs_x)))
+            .saturating_mul(sqrt_nonneg(NonNegative::clamp(one.saturating_add(abs_x))))
+    } else {
+        sqrt_nonneg(NonNegative::one_minus_square(unit_x))
+    };
+    circular_vectoring(cos_term, x, T::zero()).2
 }
 
 /// Arccosine. Domain: `[-1, 1]`. Returns angle in `[0, π]`.
@@ -206,16 +648,58 @@ pub fn asin<T: CordicNumber>(x: T) -> Result<T> {
 #[must_use = "returns the arccosine result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn acos<T: CordicNumber>(x: T) -> Result<T> {
+    UnitInterval::new(x)
+        .map(acos_unit)
+        .ok_or_else(|| Error::domain_at("acos", "value in range [-1, 1]", x))
+}
+
+/// Infallible arccosine for values in [-1, 1].
+///
+/// The [`UnitInterval<T>`] counterpart of [`acos`]; see [`asin_unit`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn acos_unit<T: CordicNumber>(x: UnitInterval<T>) -> T {
     // acos(x) = π/2 - asin(x)
-    asin(x).map(|a| T::frac_pi_2().saturating_sub(a))
+    T::frac_pi_2().saturating_sub(asin_unit(x))
+}
+
+/// Arccosine with the input clamped into `[-1, 1]`. Returns angle in `[0, π]`.
+///
+/// Inputs above `1` give `0` and inputs below `-1` give `π`, instead of an error.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn acos_clamped<T: CordicNumber>(x: T) -> T {
+    acos_unit(UnitInterval::clamp(x))
+}
+
+/// [`acos`] with the out-of-range policy taken from `config`. Under
+/// [`Saturation::Clamp`] it matches [`acos_clamped`].
+///
+/// # Errors
+/// Returns `DomainError` if `|x| > 1`, unless `config` clamps.
+#[must_use = "returns the arccosine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn acos_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Clamp => Ok(acos_clamped(x)),
+        Saturation::Saturate | Saturation::Error => acos(x),
+    }
 }
 
 /// Arctangent. Accepts any value. Returns angle in `(-π/2, π/2)`.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn atan<T: CordicNumber>(x: T) -> T {
+    atan_with(x, &CordicConfig::new())
+}
+
+/// [`atan`] with the CORDIC iteration count taken from `config`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn atan_with<T: CordicNumber>(x: T, config: &CordicConfig) -> T {
     let zero = T::zero();
     let one = T::one();
+    let iterations = config.cordic_iterations::<T>();
 
     // Special cases
     if x == zero {
@@ -227,20 +711,23 @@ pub fn atan<T: CordicNumber>(x: T) -> T {
     let abs_x = x.abs();
     if abs_x > one {
         let recip = one.div(x);
-        let atan_recip = circular_vectoring(one, recip, zero).2;
+        let atan_recip = circular_vectoring_n::<Saturating, T>(one, recip, zero, iterations).2;
 
-        if x.is_positive() {
-            T::frac_pi_2() - atan_recip
-        } else {
-            -T::frac_pi_2() - atan_recip
-        }
+        T::frac_pi_2().copysign(x) - atan_recip
     } else {
         // |x| <= 1, use CORDIC directly
-        circular_vectoring(one, x, zero).2
+        circular_vectoring_n::<Saturating, T>(one, x, zero, iterations).2
     }
 }
 
-/// Four-quadrant arctangent. Returns angle in `[-π, π]`. Returns 0 for (0, 0).
+/// Four-quadrant arctangent. Returns angle in `[-π, π]`. Returns 0 for (0, 0);
+/// use [`try_atan2`] to reject the origin instead.
+///
+/// Vectors in the left half-plane are first turned into the right one by an
+/// exact quarter turn (swapping and negating the coordinates), with the
+/// accumulator starting at `±π/2`. The result then comes from one CORDIC
+/// angle sum, not a `π - atan(|y/x|)` correction. Inputs above a quarter of
+/// the type's range are scaled down so the kernel's gain cannot saturate.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn atan2<T: CordicNumber>(y: T, x: T) -> T {
@@ -263,19 +750,224 @@ pub fn atan2<T: CordicNumber>(y: T, x: T) -> T {
         return if x.is_negative() { pi } else { zero };
     }
 
-    // Compute atan(|y|/|x|) using CORDIC vectoring mode
-    // Using absolute values ensures the base angle is always positive
-    let (_, _, base_angle) = circular_vectoring(x.abs(), y.abs(), zero);
-
-    // Adjust for quadrant based on signs of original x and y
-    match (x.is_negative(), y.is_negative()) {
-        // Q1: x positive, y positive -> angle is base_angle
-        (false, false) => base_angle,
-        // Q4: x positive, y negative -> angle is -base_angle
-        (false, true) => -base_angle,
-        // Q2: x negative, y positive -> angle is π - base_angle
-        (true, false) => pi - base_angle,
-        // Q3: x negative, y negative -> angle is -(π - base_angle) = base_angle - π
-        (true, true) => base_angle - pi,
+    polar_vectoring(y, x).1
+}
+
+/// Vectoring shared by [`atan2`] and [`range_bearing`], for `(x, y)` off the
+/// axes. Returns the kernel's final x, which is `K·r`, or `K·r/4` if the
+/// inputs had to be quartered (the flag), along with the angle.
+fn polar_vectoring<T: CordicNumber>(y: T, x: T) -> (T, T, bool) {
+    let zero = T::zero();
+    let pi = T::pi();
+    let frac_pi_2 = T::frac_pi_2();
+
+    // Rotating by ∓π/2 maps Q2 (x < 0, y > 0) to (y, -x) and Q3 to (-y, x),
+    // both with x > 0, where vectoring converges directly.
+    let (x, y, z) = match (x.is_negative(), y.is_negative()) {
+        (false, _) => (x, y, zero),
+        (true, false) => (y, x.abs(), frac_pi_2),
+        (true, true) => (y.abs(), x, -frac_pi_2),
+    };
+
+    // Vectoring grows the vector by K·√2 < 4 at most; shifting both
+    // coordinates keeps their ratio and only drops bits far below the larger.
+    let limit = T::max_value() >> 2;
+    let quartered = x > limit || y.abs() > limit;
+    let (x, y) = if quartered { (x >> 2, y >> 2) } else { (x, y) };
+
+    let (scaled, _, angle) = circular_vectoring(x, y, z);
+    let angle = if angle > pi {
+        pi
+    } else if angle < -pi {
+        -pi
+    } else {
+        angle
+    };
+    (scaled, angle, quartered)
+}
+
+/// Range and bearing of a sensor return at `(x, y)`: `(magnitude, angle)`.
+///
+/// Converts a radar or lidar return from Cartesian to polar form in a single
+/// CORDIC vectoring pass, instead of separate `sqrt(x² + y²)` and [`atan2`]
+/// calls. The bearing is exactly [`atan2(y, x)`](atan2), in `[-π, π]`. The
+/// range never forms `x² + y²`, so it cannot overflow before the result
+/// does; a range above `T::MAX` saturates. The origin returns `(0, 0)`.
+///
+/// Note the argument order: `x` first, unlike [`atan2`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn range_bearing<T: CordicNumber>(x: T, y: T) -> (T, T) {
+    let zero = T::zero();
+    if x == zero || y == zero {
+        return (x.abs().saturating_add(y.abs()), atan2(y, x));
+    }
+
+    let (scaled, angle, quartered) = polar_vectoring(y, x);
+
+    // scaled = K·r, and can be large: remove the gain at full precision.
+    let range = mul_i1f63(scaled, CIRCULAR_GAIN_INV);
+    let range = if quartered {
+        let doubled = range.saturating_add(range);
+        doubled.saturating_add(doubled)
+    } else {
+        range
+    };
+    (range, angle)
+}
+
+/// `value · constant` for an I1F63 `constant`, keeping the constant's bits
+/// below `T`'s precision.
+///
+/// The constant is split into the part `T` represents and the bits below it,
+/// so the product is within about one ULP even when `value` is large.
+fn mul_i1f63<T: CordicNumber>(value: T, constant: i64) -> T {
+    if T::frac_bits() == 0 {
+        // An integer type holds none of the constant, so `hi` and `lo` would
+        // both be zero: take the integer part of the exact product instead.
+        // Only I128F0 values beyond ±2⁶³ clamp before the multiply.
+        let clamped = if value.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        };
+        let bits = value.to_bits_i64().unwrap_or(clamped);
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "|constant| < 1, so the product is within the i64 `bits`"
+        )]
+        let product = ((i128::from(bits) * i128::from(constant)) >> 63) as i64;
+        return T::from_num(product);
+    }
+    let (hi, lo) = split_i1f63::<T>(constant);
+    value
+        .saturating_mul(hi)
+        .saturating_add(value.saturating_mul(lo) >> T::frac_bits())
+}
+
+/// Splits an I1F63 value into `(hi, lo)`: `hi` is the value in `T`, rounded
+/// down, and `lo` the bits below one ULP of `T`, scaled up by
+/// `2^frac_bits`.
+///
+/// Integer types keep no fraction to scale, so their `lo` is zero.
+fn split_i1f63<T: CordicNumber>(bits: i64) -> (T, T) {
+    let frac_bits = T::frac_bits();
+    let hi = T::from_i1f63(bits);
+    let lo = if (1..63).contains(&frac_bits) {
+        let below = bits & ((1 << (63 - frac_bits)) - 1);
+        T::from_i1f63(below << frac_bits)
+    } else {
+        T::zero()
+    };
+    (hi, lo)
+}
+
+/// Four-quadrant arctangent in degrees, in `[-180, 180]`.
+///
+/// [`atan2`] scaled by `180/π`, with the same special cases: `(0, 0)` gives
+/// 0. Types with fewer than 9 integer bits cannot hold ±180 and saturate for
+/// angles beyond their range.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn atan2_deg<T: CordicNumber>(y: T, x: T) -> T {
+    let radians = atan2(y, x);
+    if T::total_bits() - T::frac_bits() >= 9 {
+        // |radians| · 64 < 256 fits, so only the final product rounds.
+        let degrees = mul_i1f63(radians << 6, DEGREES_PER_RADIAN_DIV_64);
+        let half_turn = T::from_num(180);
+        if degrees > half_turn {
+            half_turn
+        } else if degrees < -half_turn {
+            -half_turn
+        } else {
+            degrees
+        }
+    } else {
+        let scaled = mul_i1f63(radians, DEGREES_PER_RADIAN_DIV_64);
+        if scaled > T::max_value() >> 6 {
+            T::max_value()
+        } else if scaled < T::min_value() >> 6 {
+            T::min_value()
+        } else {
+            scaled << 6
+        }
+    }
+}
+
+/// Four-quadrant arctangent in turns, in `[-1/2, 1/2]`.
+///
+/// [`atan2`] divided by 2π, with the same special cases: `(0, 0)` gives 0.
+/// Pairs with [`sin_cos_turns`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn atan2_turns<T: CordicNumber>(y: T, x: T) -> T {
+    let half = T::one() >> 1;
+    let turns = mul_i1f63(atan2(y, x), FRAC_1_2PI);
+    if turns > half {
+        half
+    } else if turns < -half {
+        -half
+    } else {
+        turns
+    }
+}
+
+/// Four-quadrant arctangent along with the quadrant it resolved to.
+///
+/// Returns `(atan2(y, x), quadrant)`, where the quadrant follows the signs
+/// that [`atan2`] uses to place the angle:
+///
+/// | Quadrant | x | y | Angle |
+/// |----------|---|---|-------|
+/// | 0 | ≥ 0 | ≥ 0 | `[0, π/2]` |
+/// | 1 | < 0 | ≥ 0 | `(π/2, π]` |
+/// | 2 | < 0 | < 0 | `(-π, -π/2)` |
+/// | 3 | ≥ 0 | < 0 | `[-π/2, 0)` |
+///
+/// The origin reports quadrant 0 with angle 0, as in [`atan2`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn atan2_quadrant<T: CordicNumber>(y: T, x: T) -> (T, u8) {
+    let quadrant = match (x.is_negative(), y.is_negative()) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (true, true) => 2,
+        (false, true) => 3,
+    };
+    (atan2(y, x), quadrant)
+}
+
+/// Four-quadrant arctangent that rejects the origin.
+///
+/// Identical to [`atan2`] except that `(0, 0)`, where the angle is undefined,
+/// is reported as an error rather than mapped to 0. A zero vector reaching
+/// `atan2` often indicates a bug upstream.
+///
+/// # Errors
+/// Returns `DomainError` if both `y` and `x` are zero.
+#[must_use = "returns the arctangent result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_atan2<T: CordicNumber>(y: T, x: T) -> Result<T> {
+    if y == T::zero() && x == T::zero() {
+        return Err(Error::domain("atan2", "non-zero (x, y)"));
+    }
+    Ok(atan2(y, x))
+}
+
+/// Unit vector in the direction of `(x, y)`.
+///
+/// Extracts the angle with CORDIC vectoring and returns its `(cos, sin)`, so
+/// the result has unit length without a square root or division, and large
+/// components cannot overflow an intermediate `x² + y²`.
+///
+/// # Errors
+/// Returns `DomainError` for the zero vector, which has no direction.
+#[must_use = "returns the normalized vector which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn normalize2<T: CordicNumber>(x: T, y: T) -> Result<(T, T)> {
+    if x == T::zero() && y == T::zero() {
+        return Err(Error::domain("normalize2", "non-zero (x, y)"));
     }
+    let (s, c) = sin_cos(atan2(y, x));
+    Ok((c, s))
 }
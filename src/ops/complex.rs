@@ -0,0 +1,103 @@
+//! Complex arithmetic helpers for fixed-point FFTs.
+
+use crate::ops::circular::sin_cos;
+use crate::ops::hyperbolic::sinh_cosh;
+use crate::traits::CordicNumber;
+
+/// Complex product `(a_re + i·a_im) · (b_re + i·b_im)`, returned as `(re, im)`.
+///
+/// Saturates component-wise on overflow.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn complex_mul<T: CordicNumber>(a_re: T, a_im: T, b_re: T, b_im: T) -> (T, T) {
+    let re = a_re
+        .saturating_mul(b_re)
+        .saturating_sub(a_im.saturating_mul(b_im));
+    let im = a_re
+        .saturating_mul(b_im)
+        .saturating_add(a_im.saturating_mul(b_re));
+    (re, im)
+}
+
+/// Complex exponential `e^(iθ)`, returned as `(cos(θ), sin(θ))`.
+///
+/// Evaluates a single [`sin_cos`], so both components come from the same
+/// rotation.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn cexp<T: CordicNumber>(theta: T) -> (T, T) {
+    let (sin, cos) = sin_cos(theta);
+    (cos, sin)
+}
+
+/// Phasor `r·e^(iθ)`, returned as `(r·cos(θ), r·sin(θ))`.
+///
+/// Saturates component-wise on overflow.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn cexp_scaled<T: CordicNumber>(r: T, theta: T) -> (T, T) {
+    let (cos, sin) = cexp(theta);
+    (r.saturating_mul(cos), r.saturating_mul(sin))
+}
+
+/// Complex sine `sin(re + i·im) = sin(re)·cosh(im) + i·cos(re)·sinh(im)`,
+/// returned as `(re, im)`.
+///
+/// Evaluates one [`sin_cos`] and one [`sinh_cosh`]. Saturates component-wise
+/// on overflow, which for large `|im|` happens before `sinh_cosh` itself
+/// saturates.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn csin<T: CordicNumber>(re: T, im: T) -> (T, T) {
+    let (sin, cos) = sin_cos(re);
+    let (sinh, cosh) = sinh_cosh(im);
+    (sin.saturating_mul(cosh), cos.saturating_mul(sinh))
+}
+
+/// Complex cosine `cos(re + i·im) = cos(re)·cosh(im) - i·sin(re)·sinh(im)`,
+/// returned as `(re, im)`.
+///
+/// Evaluates one [`sin_cos`] and one [`sinh_cosh`]. Saturates component-wise
+/// on overflow.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn ccos<T: CordicNumber>(re: T, im: T) -> (T, T) {
+    let (sin, cos) = sin_cos(re);
+    let (sinh, cosh) = sinh_cosh(im);
+    (
+        cos.saturating_mul(cosh),
+        T::zero().saturating_sub(sin.saturating_mul(sinh)),
+    )
+}
+
+/// FFT twiddle factor `e^(-2πik/n)`, returned as `(cos(-2πk/n), sin(-2πk/n))`.
+///
+/// `k` is taken modulo `n`. Returns `(1, 0)` for `n = 0`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn twiddle<T: CordicNumber>(k: usize, n: usize) -> (T, T) {
+    if n == 0 {
+        return (T::one(), T::zero());
+    }
+
+    // k/n as I1F63 in [0, 1). Wider types than u128 are not a concern for usize.
+    let ratio_bits = ((k % n) as u128) << 63;
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "quotient is below 2^63 since k % n < n"
+    )]
+    let ratio = (ratio_bits / n as u128) as i64;
+
+    // Shift to [-1/2, 1/2) so the angle stays within [-π, π], which every
+    // CordicNumber can represent even when 2π cannot.
+    let half_turns = if ratio >= 1 << 62 {
+        T::from_i1f63(ratio).saturating_sub(T::one())
+    } else {
+        T::from_i1f63(ratio)
+    };
+    let half_angle = half_turns.saturating_mul(T::pi());
+    let angle = T::zero().saturating_sub(half_angle.saturating_add(half_angle));
+
+    let (sin, cos) = sin_cos(angle);
+    (cos, sin)
+}
@@ -1,9 +1,12 @@
 //! Exponential and logarithmic functions.
 
 use crate::bounded::{NormalizedLnArg, OpenUnitInterval};
+use crate::config::{CordicConfig, Saturation};
 use crate::error::{Error, Result};
+use crate::ops::circular::reduce_periodic;
 use crate::ops::hyperbolic::atanh_open;
 use crate::traits::CordicNumber;
+use fixed::types::I32F32;
 
 /// Exponential function (e^x).
 ///
@@ -21,16 +24,16 @@ use crate::traits::CordicNumber;
 /// - **I32F32:** Saturates to MAX for x > ~21.5, to zero for x < ~-22.2
 ///
 /// Saturation is silent and deterministic. If you need to detect overflow,
-/// check the input range before calling:
+/// check the input against [`exp_max_arg`] before calling:
 ///
 /// ```
 /// use fixed::types::I16F16;
-/// use fixed_analytics::exp;
+/// use fixed_analytics::{exp, exp_max_arg};
 ///
 /// let x = I16F16::from_num(5.0);
-/// let max_safe = I16F16::from_num(10.0);
+/// let max_safe = exp_max_arg::<I16F16>();
 ///
-/// if x < max_safe {
+/// if x <= max_safe {
 ///     let result = exp(x);  // Safe
 /// } else {
 ///     // Handle potential saturation
@@ -39,38 +42,183 @@ use crate::traits::CordicNumber;
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn exp<T: CordicNumber>(x: T) -> T {
+    exp_core(x).0
+}
+
+/// Exponential function, reporting overflow instead of saturating.
+///
+/// Same computation as [`exp`], but returns an error where [`exp`] would
+/// saturate to `T::MAX`. Underflow to zero is not an error.
+///
+/// # Errors
+/// Returns `Overflow` if e^x does not fit in `T`
+/// (x > ~10.4 for I16F16, x > ~21.5 for I32F32).
+#[must_use = "returns the exponential result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_exp<T: CordicNumber>(x: T) -> Result<T> {
+    let (value, overflowed) = exp_core(x);
+    if overflowed {
+        Err(Error::overflow("exp"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// The largest `x` for which [`exp`] does not saturate in `T`.
+///
+/// This is `ln(T::MAX)` as the implementation rounds it: `exp` of the
+/// result is just below `T::MAX`, and `exp` of the next value up saturates.
+/// Found by bisection against [`try_exp`], so it costs about
+/// `T::total_bits()` evaluations; compute it once and keep it.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn exp_max_arg<T: CordicNumber>() -> T {
+    largest_fitting(|x| !exp_core(x).1)
+}
+
+/// Returns the largest non-negative `x` for which `fits(x)` holds, given
+/// that it holds at zero.
+///
+/// Bisects over `[0, T::MAX]` keeping `fits(low)` true and `fits(high)`
+/// false, so the result satisfies `fits(x)` and `!fits(x + ε)` even if
+/// `fits` is not exactly monotone at the edge.
+pub(crate) fn largest_fitting<T: CordicNumber>(fits: impl Fn(T) -> bool) -> T {
+    let epsilon = T::epsilon();
+    let mut low = T::zero();
+    let mut high = T::max_value();
+    if fits(high) {
+        return high;
+    }
+    // Each step halves the gap, so this ends within total_bits iterations.
+    while high.saturating_sub(low) > epsilon {
+        let mid = low.saturating_add(high.saturating_sub(low) >> 1);
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// [`exp`] with the out-of-range policy taken from `config`: saturates like
+/// [`exp`], or reports overflow like [`try_exp`] under [`Saturation::Error`].
+///
+/// # Errors
+/// Returns `Overflow` if e^x does not fit in `T` and `config` asks for errors.
+#[must_use = "returns the exponential result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn exp_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Error => try_exp(x),
+        Saturation::Saturate | Saturation::Clamp => Ok(exp(x)),
+    }
+}
+
+/// Evaluates [`exp`] over a slice, writing `e^inputs[i]` to `out[i]`.
+///
+/// For sorted `inputs` the output is guaranteed non-decreasing: each result
+/// is clamped to be at least the previous one. [`exp`] is already monotone
+/// across its reduction boundaries, so the clamp only guards the contract.
+/// Unsorted inputs are accepted but the clamp then distorts the results.
+///
+/// Only the first `min(inputs.len(), out.len())` elements are written.
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn exp_monotone_slice<T: CordicNumber>(inputs: &[T], out: &mut [T]) {
+    let mut floor = T::zero();
+    for (&x, y) in inputs.iter().zip(out.iter_mut()) {
+        let value = exp(x);
+        floor = if value < floor { floor } else { value };
+        *y = floor;
+    }
+}
+
+/// ln(2) in I1F63, used to form `k * ln(2)` without rounding `ln(2)` to `T` first.
+const LN_2_I1F63: i64 = 0x58B9_0BFB_E8E7_BCD6;
+
+/// The next 63 bits of ln(2): `ln(2) - LN_2_I1F63` scaled by 2^126.
+///
+/// `LN_2_I1F63` alone is 0.1 units of 2^-63 high, which `k` multiplies past
+/// one ULP of the widest types.
+const LN_2_LO_I1F63: i64 = -0x0D87_1319_FF03_4254;
+
+/// Returns `k * ln(2)`, rounded once to `T`.
+///
+/// Multiplying the type's own `ln(2)` by `k` would scale its rounding error
+/// by `k`. Instead the product is formed Cody–Waite style from a two-word
+/// `ln(2)`: `k` times the high word is exact, and `k` times the low word is
+/// rounded into it as a correction, so the sum is within half a unit of
+/// 2^-63 before the single rounding to `T`.
+pub(crate) fn scaled_ln2<T: CordicNumber>(k: i32) -> T {
+    let k = i128::from(k);
+    let correction = (k * i128::from(LN_2_LO_I1F63) + (1 << 62)) >> 63;
+    let product = k * i128::from(LN_2_I1F63) + correction;
+    // exp clamps k to the width of T (at most 128) and then rounds it by one,
+    // so |k| ≤ 129 and |k·ln(2)| < 89.5: the integer part lies in [-90, 89].
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "|k| ≤ 129 puts the integer part in [-90, 89]; the fraction is masked to 63 bits"
+    )]
+    let (int_part, frac_part) = (
+        (product >> 63) as i32,
+        (product & i128::from(i64::MAX)) as i64,
+    );
+    // Types with few integer bits cannot hold k * ln(2) for large |k|.
+    let int_bits = T::total_bits() - T::frac_bits();
+    if int_bits <= 32 {
+        let limit = 1_i64 << (int_bits - 1);
+        if i64::from(int_part) >= limit {
+            return T::max_value();
+        }
+        if i64::from(int_part) < -limit {
+            return T::min_value();
+        }
+    }
+    T::from_num(int_part).saturating_add(T::from_i1f63(frac_part))
+}
+
+/// Shared exp evaluation. The flag is set if the result saturated to `T::MAX`.
+fn exp_core<T: CordicNumber>(x: T) -> (T, bool) {
     let zero = T::zero();
     let one = T::one();
     let ln2 = T::ln_2();
 
     // Handle special case
     if x == zero {
-        return one;
+        return (one, false);
     }
 
-    // Argument reduction: exp(x) = 2^k * exp(r), where r ∈ (-ln2, ln2).
-    // Compute k = trunc(x / ln2) in one step, then r = x - k*ln2 in one
-    // subtraction. Truncation toward zero matches the old iterative
-    // subtraction behavior while avoiding error accumulation.
+    // Argument reduction: exp(x) = 2^k * exp(r), where r ∈ [-ln2/2, ln2/2].
+    // Compute k = trunc(x / ln2) in one step and nudge it to the nearest
+    // integer by inspecting the remainder; `round` itself can overflow near
+    // the type's limits. Halving |r| compared to truncation shortens the
+    // Taylor tail below.
     #[allow(clippy::cast_possible_wrap, reason = "total_bits bounded by type size")]
     let max_shift = (T::total_bits() - 1) as i32;
-    let scale = x.div(ln2).to_i32();
+    let mut scale = x.div(ln2).to_i32().clamp(-max_shift - 1, max_shift + 1);
+    let half_ln2 = ln2 >> 1;
+    let remainder = x.saturating_sub(scaled_ln2(scale));
+    if remainder > half_ln2 {
+        scale += 1;
+    } else if remainder < -half_ln2 {
+        scale -= 1;
+    }
 
     // Early exit for values that will saturate after scaling
     if scale > max_shift {
-        return T::max_value();
+        return (T::max_value(), true);
     }
     if scale < -max_shift {
-        return zero;
+        return (zero, false);
     }
 
-    let r = x.saturating_sub(T::from_num(scale).saturating_mul(ln2));
+    let r = x.saturating_sub(scaled_ln2(scale));
 
     // Factored Taylor: exp(r) = 1 + r*(1 + r/2*(1 + r/3*(1 + ... r/n)))
     let mut p = one;
     if T::frac_bits() >= 24 {
         // High precision: degree 12 Taylor
-        // Truncation error: |r^13/13!| ≤ (ln2)^13/13! ≈ 3.4e-15
+        // Truncation error: |r^13/13!| ≤ (ln2/2)^13/13! ≈ 1.7e-16
         p = one.saturating_add(r.div(T::from_num(12)).saturating_mul(p));
         p = one.saturating_add(r.div(T::from_num(11)).saturating_mul(p));
         p = one.saturating_add(r.div(T::from_num(10)).saturating_mul(p));
@@ -78,7 +226,7 @@ pub fn exp<T: CordicNumber>(x: T) -> T {
         p = one.saturating_add(r.div(T::from_num(8)).saturating_mul(p));
     }
     // Common terms (degree 7 base)
-    // Low-precision truncation error: |r^8/8!| ≤ (ln2)^8/8! ≈ 8.9e-7
+    // Low-precision truncation error: |r^8/8!| ≤ (ln2/2)^8/8! ≈ 5.2e-9
     p = one.saturating_add(r.div(T::from_num(7)).saturating_mul(p));
     p = one.saturating_add(r.div(T::from_num(6)).saturating_mul(p));
     p = one.saturating_add(r.div(T::from_num(5)).saturating_mul(p));
@@ -97,13 +245,13 @@ pub fn exp<T: CordicNumber>(x: T) -> T {
             // the left shift would wrap, so saturate to MAX instead.
             let headroom = T::max_value() >> shift;
             if exp_r > headroom {
-                T::max_value()
+                (T::max_value(), true)
             } else {
-                exp_r << shift
+                (exp_r << shift, false)
             }
         }
-        core::cmp::Ordering::Less => exp_r >> ((-scale) as u32),
-        core::cmp::Ordering::Equal => exp_r,
+        core::cmp::Ordering::Less => (exp_r >> ((-scale) as u32), false),
+        core::cmp::Ordering::Equal => (exp_r, false),
     }
 }
 
@@ -114,43 +262,66 @@ pub fn exp<T: CordicNumber>(x: T) -> T {
 #[must_use = "returns the natural logarithm result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn ln<T: CordicNumber>(x: T) -> Result<T> {
+    if x <= T::zero() {
+        return Err(Error::domain_at("ln", "positive value", x));
+    }
+    Ok(ln_positive(x))
+}
+
+/// [`ln`] with the out-of-range policy taken from `config`. Under
+/// [`Saturation::Clamp`] inputs `x ≤ 0` are raised to `ε`, giving `ln(ε)`.
+///
+/// # Errors
+/// Returns `DomainError` if `x ≤ 0`, unless `config` clamps.
+#[must_use = "returns the natural logarithm result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn ln_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Clamp => {
+            let epsilon = T::epsilon();
+            Ok(ln_positive(if x < epsilon { epsilon } else { x }))
+        }
+        Saturation::Saturate | Saturation::Error => ln(x),
+    }
+}
+
+/// Core ln implementation. Caller must ensure x > 0.
+pub(crate) fn ln_positive<T: CordicNumber>(x: T) -> T {
     let zero = T::zero();
     let one = T::one();
     let two = T::two();
 
-    if x <= zero {
-        return Err(Error::domain("ln", "positive value"));
+    if x == one {
+        return zero;
     }
 
-    if x == one {
-        return Ok(zero);
+    if T::frac_bits() < 16
+        && let Some(bits) = x.to_bits_i64()
+    {
+        return ln_narrow(bits);
     }
 
     // For x far from 1, use argument reduction:
     // ln(x) = ln(x * 2^(-k)) + k * ln(2)
-    // where k is chosen so that x * 2^(-k) is close to 1
-
-    let ln2 = T::ln_2();
+    // where k is chosen so that x * 2^(-k) is close to 1. The shifts only
+    // count k; k * ln(2) is formed once, so inputs near ε no longer collect
+    // one rounding of ln(2) per doubling.
     let mut normalized = x;
-    let mut k_ln2 = zero;
+    let mut k: i32 = 0;
 
     // Reduce to range [0.5, 2] for better convergence.
     let half = T::half();
 
     // For large x, divide by 2 repeatedly
-    let mut i = 0;
-    while normalized > two && i < 128 {
+    while normalized > two && k < 128 {
         normalized = normalized >> 1;
-        k_ln2 = k_ln2.saturating_add(ln2);
-        i += 1;
+        k += 1;
     }
 
     // For small x (< 0.5), multiply by 2 repeatedly
-    i = 0;
-    while normalized < half && i < 128 {
+    while normalized < half && k > -128 {
         normalized = normalized.saturating_add(normalized);
-        k_ln2 = k_ln2.saturating_sub(ln2);
-        i += 1;
+        k -= 1;
     }
 
     // Now compute ln(normalized) where 0.5 <= normalized <= 2
@@ -165,21 +336,101 @@ pub fn ln<T: CordicNumber>(x: T) -> Result<T> {
     let atanh_val = atanh_open(arg);
     let ln_normalized = atanh_val.saturating_add(atanh_val); // 2 * atanh
 
-    Ok(ln_normalized.saturating_add(k_ln2))
+    ln_normalized.saturating_add(scaled_ln2(k))
+}
+
+/// [`ln_positive`] for types with fewer than 16 fractional bits, given the
+/// raw bits of `x > 0`.
+///
+/// With so few bits, reducing `x` in `T` truncates the mantissa and the
+/// atanh iterations round at every step, costing several ULPs. Instead the
+/// exponent is read off the bit length, only the mantissa in `[1, 2)` goes
+/// through CORDIC, in `I32F32`, and the sum is rounded to `T` once.
+fn ln_narrow<T: CordicNumber>(bits: i64) -> T {
+    let frac_bits = T::frac_bits().cast_signed();
+    let top_bit = 63 - bits.leading_zeros().cast_signed();
+    // Keep the leading 33 significant bits as an I32F32 in [1, 2).
+    let mantissa_bits = if top_bit > 32 {
+        bits >> (top_bit - 32)
+    } else {
+        bits << (32 - top_bit)
+    };
+    let mantissa = I32F32::from_bits(mantissa_bits);
+    let wide = ln_positive(mantissa).saturating_add(scaled_ln2(top_bit - frac_bits));
+
+    // Round to nearest at T's precision, then split into an integer part and
+    // a fraction T can take without overflowing.
+    let half_ulp = I32F32::from_bits(1 << (31 - frac_bits));
+    let rounded = wide.saturating_add(half_ulp).to_bits();
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "|ln x| < 64 for 64-bit x, and the fraction is masked to 32 bits"
+    )]
+    let (int_part, fraction) = ((rounded >> 32) as i32, rounded & 0xFFFF_FFFF);
+    if int_part >= T::max_value().to_i32().saturating_add(1) {
+        return T::max_value();
+    }
+    if int_part < T::min_value().to_i32() {
+        return T::min_value();
+    }
+    T::from_num(int_part).saturating_add(T::from_i1f63(fraction << 31))
 }
 
 /// Base-2 logarithm. Domain: `x > 0`.
 ///
+/// Exact powers of two return their integer exponent exactly.
+///
 /// # Errors
 /// Returns `DomainError` if `x ≤ 0`.
 #[must_use = "returns the base-2 logarithm result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn log2<T: CordicNumber>(x: T) -> Result<T> {
+    if let Some(exponent) = exact_log2(x) {
+        return Ok(exponent);
+    }
     let ln_x = ln(x)?;
     let ln_2 = T::ln_2();
     Ok(ln_x.div(ln_2))
 }
 
+/// `Some(k)` if `x` is exactly `2^k` and `k` is representable in `T`.
+fn exact_log2<T: CordicNumber>(x: T) -> Option<T> {
+    let one = T::one();
+    let two = T::two();
+    if x <= T::zero() {
+        return None;
+    }
+
+    // Shift x into [1, 2), counting the exponent. Both loops are bounded
+    // by the bit width.
+    let mut normalized = x;
+    let mut k: i32 = 0;
+    while normalized >= two {
+        normalized = normalized >> 1;
+        k += 1;
+    }
+    while normalized < one {
+        normalized = normalized << 1;
+        k -= 1;
+    }
+    if normalized != one {
+        return None;
+    }
+    // Right shifts may have dropped low bits, so rebuild 2^k and compare.
+    let power = if k >= 0 {
+        one << k.unsigned_abs()
+    } else {
+        one >> k.unsigned_abs()
+    };
+    if power != x {
+        return None;
+    }
+
+    // T holds integers of magnitude below 2^(int_bits - 1).
+    let int_bits = T::total_bits() - T::frac_bits();
+    (int_bits > 32 || k.unsigned_abs() < 1 << (int_bits - 1)).then(|| T::from_num(k))
+}
+
 /// Base-10 logarithm. Domain: `x > 0`.
 ///
 /// # Errors
@@ -212,3 +463,208 @@ pub fn pow2<T: CordicNumber>(x: T) -> T {
     let ln_2 = T::ln_2();
     exp(x.saturating_mul(ln_2))
 }
+
+/// Power of 2, reporting overflow instead of saturating.
+///
+/// Same computation as [`pow2`], but returns an error where [`pow2`] would
+/// saturate to `T::MAX`. Underflow to zero is not an error.
+///
+/// # Errors
+/// Returns `Overflow` if 2^x does not fit in `T`
+/// (x > ~15 for I16F16, x > ~31 for I32F32).
+#[must_use = "returns the power of 2 result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_pow2<T: CordicNumber>(x: T) -> Result<T> {
+    let (value, overflowed) = exp_core(x.saturating_mul(T::ln_2()));
+    if overflowed {
+        Err(Error::overflow("pow2"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Base-2 exponential (2^x) limited to `ceiling`.
+///
+/// Returns `min(2^x, ceiling)`. Unlike [`pow2`], which jumps to `T::MAX`
+/// once the result overflows, the output levels off continuously at
+/// `ceiling`. This is useful for activations that must stay bounded. A
+/// non-positive `ceiling` is returned as is.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn pow2_clamped<T: CordicNumber>(x: T, ceiling: T) -> T {
+    let result = pow2(x);
+    if result > ceiling { ceiling } else { result }
+}
+
+/// Integer power (x^n). Computed by repeated squaring, so the result is exact
+/// whenever every intermediate product is representable.
+///
+/// Saturates to `T::MAX`/`T::MIN` on overflow. For `n < 0` the result is
+/// `1 / x^|n|`, which saturates to `T::MAX` for `x = 0`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn powi<T: CordicNumber>(x: T, n: i32) -> T {
+    let one = T::one();
+    let mut base = x;
+    let mut result = one;
+    let mut remaining = n.unsigned_abs();
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result.saturating_mul(base);
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            base = base.saturating_mul(base);
+        }
+    }
+
+    if n < 0 { one.div(result) } else { result }
+}
+
+/// General power (`base^exponent`).
+///
+/// The exponent is split into an integer `k` and a fraction `f` with
+/// `|f| < 1`, and the result is `powi(base, k) · exp(f · ln(base))`. An
+/// integer exponent is detected exactly, with no tolerance: `f` is then
+/// zero, the second factor is exactly one, and the result is the exact
+/// [`powi`] value (`pow(2, 10)` is 1024, not the 1023.7 of a plain
+/// `exp(10 · ln(2))`). Off the integers only the fractional factor goes
+/// through `exp`/`ln`, and the two factors meet continuously at every
+/// integer, so no snapping window is needed.
+///
+/// `f` takes the sign that makes the fractional factor at least one, so
+/// `powi(base, k)` never exceeds the result and saturates only when the
+/// result does. A negative base is accepted for integer exponents, and
+/// `0^exponent` is zero for any positive exponent.
+///
+/// # Errors
+/// Returns `DomainError` for a negative base with a non-integer exponent,
+/// or a zero base with a negative non-integer exponent.
+#[must_use = "returns the power result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn pow<T: CordicNumber>(base: T, exponent: T) -> Result<T> {
+    let zero = T::zero();
+    let one = T::one();
+
+    let (frac, k) = reduce_periodic(exponent, one);
+    if frac == zero {
+        return Ok(powi(base, k));
+    }
+    if base == zero && exponent.is_positive() {
+        return Ok(zero);
+    }
+    if base <= zero {
+        return Err(Error::domain_at(
+            "pow",
+            "positive base for a non-integer exponent",
+            base,
+        ));
+    }
+
+    Ok(pow_fractional(base, ln_positive(base), frac, k))
+}
+
+/// `powi(base, k) · exp(frac · ln_base)` for `base > 0` and `|frac| < 1`.
+fn pow_fractional<T: CordicNumber>(base: T, ln_base: T, mut frac: T, mut k: i32) -> T {
+    let one = T::one();
+
+    // Choose f so that base^f ≥ 1: f ∈ (0, 1) for base ≥ 1, f ∈ (-1, 0)
+    // for base < 1.
+    if (base >= one) == frac.is_negative() {
+        let toward = if frac.is_negative() { one } else { -one };
+        frac = frac.saturating_add(toward);
+        k = if toward.is_negative() {
+            k.saturating_add(1)
+        } else {
+            k.saturating_sub(1)
+        };
+    }
+
+    let frac_pow = exp(frac.saturating_mul(ln_base));
+    powi(base, k).saturating_mul(frac_pow)
+}
+
+/// A positive base with its logarithm precomputed, for raising one base to
+/// many exponents.
+///
+/// [`pow`] takes `ln(base)` on every call that has a fractional exponent.
+/// `PowBase` takes it once, in [`new`](Self::new), and [`pow`](Self::pow)
+/// then costs one `exp` and one `powi`. Results are identical to [`pow`],
+/// including exact results for integer exponents.
+///
+/// ```
+/// use fixed::types::I16F16;
+/// use fixed_analytics::PowBase;
+///
+/// // Growth at 5% per period.
+/// let rate = PowBase::new(I16F16::from_num(1.05)).unwrap();
+/// let after_10 = rate.pow(I16F16::from_num(10));
+/// assert!((after_10.to_num::<f32>() - 1.6289).abs() < 0.01);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PowBase<T> {
+    base: T,
+    ln_base: T,
+}
+
+impl<T: CordicNumber> PowBase<T> {
+    /// Precomputes `ln(base)`.
+    ///
+    /// # Errors
+    /// Returns `DomainError` if `base ≤ 0`. Use [`pow`] for integer powers
+    /// of zero or of negative bases.
+    #[must_use = "returns the prepared base which should be handled"]
+    #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+    pub fn new(base: T) -> Result<Self> {
+        if base <= T::zero() {
+            return Err(Error::domain_at("PowBase::new", "positive base", base));
+        }
+        Ok(Self {
+            base,
+            ln_base: ln_positive(base),
+        })
+    }
+
+    /// The base.
+    #[must_use]
+    pub const fn base(&self) -> T {
+        self.base
+    }
+
+    /// The precomputed `ln(base)`.
+    #[must_use]
+    pub const fn ln_base(&self) -> T {
+        self.ln_base
+    }
+
+    /// `base^exponent`, equal to [`pow(base, exponent)`](pow).
+    #[must_use]
+    #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+    pub fn pow(&self, exponent: T) -> T {
+        let (frac, k) = reduce_periodic(exponent, T::one());
+        if frac == T::zero() {
+            return powi(self.base, k);
+        }
+        pow_fractional(self.base, self.ln_base, frac, k)
+    }
+}
+
+/// Log-sum-exp of two values, `ln(e^a + e^b)`, without overflow.
+///
+/// Computed as `max(a, b) + ln(1 + e^(-|a - b|))`. The logarithm's argument
+/// lies in `(1, 2]`, and in fixed point `1 + e^(-|a - b|)` is exact, so no
+/// separate `ln_1p` is needed to keep precision. Only the final addition can
+/// saturate.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn logaddexp<T: CordicNumber>(a: T, b: T) -> T {
+    let (max, min) = if a > b { (a, b) } else { (b, a) };
+    let diff = max.saturating_sub(min);
+    let tail = exp(T::zero().saturating_sub(diff));
+
+    // 1 + e^(-diff) ∈ (1, 2], inside the normalized ln range [0.5, 2].
+    let norm = NormalizedLnArg::from_normalized(T::one().saturating_add(tail));
+    let atanh_val = atanh_open(OpenUnitInterval::from_normalized_ln_arg(norm));
+    max.saturating_add(atanh_val.saturating_add(atanh_val))
+}
@@ -1,9 +1,13 @@
 //! Hyperbolic functions via hyperbolic CORDIC.
 
-use crate::bounded::{AtLeastOne, NonNegative, OpenUnitInterval};
+use crate::bounded::{AtLeastOne, NonNegative, OpenUnitInterval, UnitInterval};
+use crate::config::{CordicConfig, Saturation};
 use crate::error::{Error, Result};
 use crate::kernel::hyperbolic_vectoring;
 use crate::ops::algebraic::sqrt_nonneg;
+use crate::ops::exponential::{exp, largest_fitting, ln_positive, scaled_ln2};
+use crate::series::{cosh_series, sinh_series};
+use crate::tables::tanh::{TANH_TABLE, TANH_TABLE_SHIFT};
 use crate::traits::CordicNumber;
 
 /// Hyperbolic CORDIC converges for |x| < sum of atanh table ≈ 1.1182.
@@ -11,6 +15,11 @@ use crate::traits::CordicNumber;
 /// Full limit = 1 + this value.
 const HYPERBOLIC_CONVERGENCE_LIMIT_FRAC_I1F63: i64 = 0x0F22_3D70_A3D7_0A3D;
 
+/// 1/3 and 1/45 (I1F63), the coefficients of coth's Laurent series. Types
+/// with few integer bits cannot hold 45 to divide by.
+const COTH_X_COEFF_I1F63: i64 = 0x2AAA_AAAA_AAAA_AAAB;
+const COTH_X3_COEFF_I1F63: i64 = 0x02D8_2D82_D82D_82D8;
+
 /// Argument reduction threshold for atanh.
 ///
 /// For |x| > this threshold, atanh uses the identity:
@@ -19,9 +28,18 @@ const HYPERBOLIC_CONVERGENCE_LIMIT_FRAC_I1F63: i64 = 0x0F22_3D70_A3D7_0A3D;
 /// ```
 /// to reduce the argument into CORDIC's optimal convergence range.
 ///
-/// Value 0.75 keeps reduced arguments within the convergent region.
+/// Value 0.75 keeps reduced arguments within the convergent region, which
+/// ends at tanh(1.1182) ≈ 0.807.
 const ATANH_REDUCTION_THRESHOLD_I1F63: i64 = 0x6000_0000_0000_0000;
 
+/// Lower atanh reduction threshold (0.625) for types with ≥16 fractional bits.
+///
+/// Vectoring shrinks x to `K·sqrt(1 - x²)`, so the final y/x residual costs
+/// more ULPs as |x| approaches the convergence limit. With fine ULPs that
+/// outweighs the rounding of one reduction step well below 0.75; with
+/// coarse ULPs the reduction step is the larger error, so those keep 0.75.
+const ATANH_REDUCTION_THRESHOLD_PRECISE_I1F63: i64 = 0x5000_0000_0000_0000;
+
 /// Hyperbolic sine and cosine. More efficient than separate calls.
 ///
 /// # Saturation Behavior
@@ -32,12 +50,143 @@ const ATANH_REDUCTION_THRESHOLD_I1F63: i64 = 0x6000_0000_0000_0000;
 ///
 /// See [`sinh`] and [`cosh`] for threshold details.
 ///
-/// When saturation occurs, both values saturate together (they grow
-/// at the same rate), so the relationship cosh²(x) - sinh²(x) = 1
-/// will not hold for saturated outputs.
+/// Inputs are checked against `acosh(T::MAX)` before the result is built,
+/// so both values saturate together: either the pair is computed in range
+/// or it is exactly (`T::MAX` or `T::MIN`, `T::MAX`). The relationship
+/// cosh²(x) - sinh²(x) = 1 holds up to that edge but not for saturated
+/// outputs.
+///
+/// With the `fast-math` feature, every type uses a degree 5 sinh / degree 6
+/// cosh series. Relative error is about 3e-4 for |x| ≤ 1.118 and roughly
+/// doubles with each doubling step beyond, leaving ~2 decimal digits near the
+/// saturation threshold.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn sinh_cosh<T: CordicNumber>(x: T) -> (T, T) {
+    let (sh, ch, _) = sinh_cosh_core(x);
+    (sh, ch)
+}
+
+/// Hyperbolic sine and cosine, reporting overflow instead of saturating.
+///
+/// Same computation as [`sinh_cosh`], but returns an error where that
+/// function would saturate.
+///
+/// # Errors
+/// Returns `Overflow` if sinh or cosh does not fit in `T`
+/// (|x| > ~11.1 for I16F16, |x| > ~22.2 for I32F32).
+#[must_use = "returns the hyperbolic sine and cosine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_sinh_cosh<T: CordicNumber>(x: T) -> Result<(T, T)> {
+    let (sh, ch, overflowed) = sinh_cosh_core(x);
+    if overflowed {
+        Err(Error::overflow("sinh_cosh"))
+    } else {
+        Ok((sh, ch))
+    }
+}
+
+/// Hyperbolic sine, reporting overflow instead of saturating.
+///
+/// # Errors
+/// Returns `Overflow` under the same conditions as [`try_sinh_cosh`].
+#[must_use = "returns the hyperbolic sine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_sinh<T: CordicNumber>(x: T) -> Result<T> {
+    let (sh, _, overflowed) = sinh_cosh_core(x);
+    if overflowed {
+        Err(Error::overflow("sinh"))
+    } else {
+        Ok(sh)
+    }
+}
+
+/// [`sinh`] with the out-of-range policy taken from `config`: saturates like
+/// [`sinh`], or reports overflow like [`try_sinh`] under [`Saturation::Error`].
+///
+/// # Errors
+/// Returns `Overflow` if sinh(x) does not fit in `T` and `config` asks for errors.
+#[must_use = "returns the hyperbolic sine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sinh_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Error => try_sinh(x),
+        Saturation::Saturate | Saturation::Clamp => Ok(sinh(x)),
+    }
+}
+
+/// Hyperbolic cosine, reporting overflow instead of saturating.
+///
+/// # Errors
+/// Returns `Overflow` under the same conditions as [`try_sinh_cosh`].
+#[must_use = "returns the hyperbolic cosine result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn try_cosh<T: CordicNumber>(x: T) -> Result<T> {
+    let (_, ch, overflowed) = sinh_cosh_core(x);
+    if overflowed {
+        Err(Error::overflow("cosh"))
+    } else {
+        Ok(ch)
+    }
+}
+
+/// The largest `x` for which [`cosh`] does not saturate in `T`.
+///
+/// This is `acosh(T::MAX)` as the implementation rounds it: `cosh` of the
+/// result is just below `T::MAX`, and `cosh` of the next value up
+/// saturates. By symmetry the same bound applies to `-x`. Found by
+/// bisection against [`try_cosh`], so it costs about `T::total_bits()`
+/// evaluations; compute it once and keep it.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn cosh_max_arg<T: CordicNumber>() -> T {
+    largest_fitting(|x| !sinh_cosh_core(x).2)
+}
+
+/// The largest `x` for which [`sinh`] does not saturate in `T`; `sinh`
+/// saturates to `T::MIN` below its negation.
+///
+/// sinh and cosh saturate together (see [`sinh_cosh`]), so this equals
+/// [`cosh_max_arg`] and has the same cost.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sinh_max_arg<T: CordicNumber>() -> T {
+    cosh_max_arg()
+}
+
+/// Shared sinh/cosh evaluation. The flag is set if cosh(x) does not fit in `T`.
+///
+/// Evaluates at -|x| and restores the sign, so sinh is exactly odd and cosh
+/// exactly even. Fixed-point products truncate toward -∞, so on the
+/// negative side the halving rounds the argument away from zero and offsets
+/// the truncation in the doubling; on the positive side both errors push
+/// the same way and the mean error is several times larger.
+fn sinh_cosh_core<T: CordicNumber>(x: T) -> (T, T, bool) {
+    let (sh, ch, overflowed) = sinh_cosh_signed(-x.abs());
+    match (x.is_negative(), overflowed) {
+        (true, _) => (sh, ch, overflowed),
+        (false, true) => (T::max_value(), ch, true),
+        (false, false) => (-sh, ch, false),
+    }
+}
+
+/// sinh and cosh evaluated at `x` as given, without mirroring. On
+/// saturation sinh takes the sign of `x`.
+fn sinh_cosh_signed<T: CordicNumber>(x: T) -> (T, T, bool) {
+    let max = T::max_value();
+    let min = T::min_value();
+    let saturated = (if x.is_negative() { min } else { max }, max, true);
+
+    // cosh(x) fits while |x| ≤ acosh(MAX) ≈ ln(2·MAX) = int_bits·ln(2).
+    // Rejecting larger inputs up front keeps the doubling below from
+    // clamping only one of sinh and cosh, which would leave a pair that
+    // breaks cosh² - sinh² = 1.
+    let int_bits = T::total_bits().saturating_sub(T::frac_bits());
+    let cosh_limit: T = scaled_ln2(i32::try_from(int_bits).unwrap_or(i32::MAX));
+    if x.abs() > cosh_limit {
+        return saturated;
+    }
+
     let one = T::one();
     // Compute limit as 1 + fractional_part (~1.1182)
     let limit = one.saturating_add(T::from_i1f63(HYPERBOLIC_CONVERGENCE_LIMIT_FRAC_I1F63));
@@ -47,72 +196,42 @@ pub fn sinh_cosh<T: CordicNumber>(x: T) -> (T, T) {
     let mut reduced = x;
     let mut depth: u32 = 0;
     // Max depth bounded by bit width: each halving shifts right by 1,
-    // so after total_bits iterations the value is zero and below the limit.
+    // so after total_bits iterations the value is 0 or -ε, within the limit.
     while reduced.abs() > limit && depth < T::total_bits() {
         reduced = reduced >> 1;
         depth += 1;
     }
 
-    // Factored Taylor evaluation. After argument reduction, |reduced| ≤ 1.1182.
-    //
-    // sinh(x) = x * (1 + u/6 * (1 + u/20 * (1 + u/42 * ...)))
-    // cosh(x) = 1 + u/2 * (1 + u/12 * (1 + u/30 * ...))
-    // where u = x².
-    //
-    // Integer division (u/K) is used because it computes the quotient in one
-    // step without pre-rounding a reciprocal, and the factored form keeps each
-    // step's multiplicand u/K below 1 for K ≥ 2.
-    let u = reduced.saturating_mul(reduced);
-
-    // High-precision path requires enough integer bits for divisors up to 182.
-    let mut sp = one;
-    let (mut sh, mut ch) = if T::frac_bits() >= 24 && T::total_bits() >= T::frac_bits() + 9 {
-        // High precision: degree 13 sinh, degree 14 cosh
-        sp = one.saturating_add(u.div(T::from_num(156)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(110)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(72)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(42)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(20)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(6)).saturating_mul(sp));
-        let sinh_approx = reduced.saturating_mul(sp);
-
-        let mut cp = one;
-        cp = one.saturating_add(u.div(T::from_num(182)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(132)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(90)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(56)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(30)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(12)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(2)).saturating_mul(cp));
-        (sinh_approx, cp)
+    // Taylor series after argument reduction, |reduced| ≤ 1.1182.
+    // High precision needs enough integer bits for divisors up to 182.
+    let (sinh_terms, cosh_terms) = if cfg!(feature = "fast-math") {
+        // Degree 5 sinh, degree 6 cosh
+        (3, 4)
+    } else if T::frac_bits() >= 24 && T::total_bits() >= T::frac_bits() + 9 {
+        // Degree 13 sinh, degree 14 cosh
+        (7, 8)
     } else {
-        // Low precision: degree 9 sinh, degree 10 cosh
-        sp = one;
-        sp = one.saturating_add(u.div(T::from_num(72)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(42)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(20)).saturating_mul(sp));
-        sp = one.saturating_add(u.div(T::from_num(6)).saturating_mul(sp));
-        let sinh_approx = reduced.saturating_mul(sp);
-
-        let mut cp = one;
-        cp = one.saturating_add(u.div(T::from_num(90)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(56)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(30)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(12)).saturating_mul(cp));
-        cp = one.saturating_add(u.div(T::from_num(2)).saturating_mul(cp));
-        (sinh_approx, cp)
+        // Degree 9 sinh, degree 10 cosh
+        (5, 6)
     };
+    let mut sh = sinh_series(reduced, sinh_terms);
+    let mut ch = cosh_series(reduced, cosh_terms);
 
     // Reconstruct via doubling: sinh(2x) = 2·sinh(x)·cosh(x),
     //                           cosh(2x) = cosh²(x) + sinh²(x)
+    // The limit check above leaves at most rounding-level overshoot here;
+    // if a step still clamps, report both values as saturated together.
     for _ in 0..depth {
         let new_sh = sh.saturating_mul(ch).saturating_mul(T::two());
         let new_ch = ch.saturating_mul(ch).saturating_add(sh.saturating_mul(sh));
+        if new_ch == max || new_sh == max || new_sh == min {
+            return saturated;
+        }
         sh = new_sh;
         ch = new_ch;
     }
 
-    (sh, ch)
+    (sh, ch, false)
 }
 
 /// Hyperbolic sine.
@@ -131,6 +250,8 @@ pub fn sinh_cosh<T: CordicNumber>(x: T) -> (T, T) {
 /// - **I16F16:** Saturates for |x| > ~11.1
 /// - **I32F32:** Saturates for |x| > ~22.2
 ///
+/// [`sinh_max_arg`] returns the threshold for any `T`.
+///
 /// Within the non-saturating range, sinh is computed via polynomial
 /// evaluation with argument reduction (halving/doubling) for |x| > 1.118.
 #[inline]
@@ -156,6 +277,8 @@ pub fn sinh<T: CordicNumber>(x: T) -> T {
 /// The exact thresholds:
 /// - **I16F16:** Saturates for |x| > ~11.1
 /// - **I32F32:** Saturates for |x| > ~22.2
+///
+/// [`cosh_max_arg`] returns the threshold for any `T`.
 #[inline]
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
@@ -163,29 +286,142 @@ pub fn cosh<T: CordicNumber>(x: T) -> T {
     sinh_cosh(x).1
 }
 
-/// Hyperbolic tangent. Result in `(-1, 1)`.
+/// Hyperbolic tangent. Result strictly inside `(-1, 1)`.
+///
+/// For |x| < 1 this is `sinh(x) / cosh(x)`. Beyond that it uses
+/// `(1 - e^(-2|x|)) / (1 + e^(-2|x|))`, which never saturates, so the result
+/// approaches ±1 smoothly instead of jittering once sinh and cosh clamp.
+/// Where tanh is within one ULP of ±1 the result is `±(1 - T::epsilon())`.
+///
+/// With the `tanh-table` feature, |x| < 4 is instead interpolated from a
+/// 257-entry table (2 KiB), accurate to about 2.5e-5. Types with fewer than
+/// 8 integer bits always take the computed path.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn tanh<T: CordicNumber>(x: T) -> T {
+    if let Some(t) = tanh_lookup(x) {
+        return t;
+    }
+    if x.abs() < T::one() {
+        let (s, c) = sinh_cosh(x);
+        s.div(c)
+    } else {
+        tanh_large(x)
+    }
+}
+
+/// Hyperbolic sine, cosine, and tangent from a single evaluation.
+///
+/// Returns `(sinh, cosh, tanh)`. For |x| < 1 tanh is derived from the shared
+/// [`sinh_cosh`] evaluation with one division; beyond that it is computed as
+/// in [`tanh`], so the third value always equals `tanh(x)`.
+///
+/// Saturation of sinh and cosh follows [`sinh_cosh`].
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn sinh_cosh_tanh<T: CordicNumber>(x: T) -> (T, T, T) {
     let (s, c) = sinh_cosh(x);
-    s.div(c)
+    let t = tanh_lookup(x).unwrap_or_else(|| {
+        if x.abs() < T::one() {
+            s.div(c)
+        } else {
+            tanh_large(x)
+        }
+    });
+    (s, c, t)
+}
+
+/// Table-interpolated tanh for |x| < 4 when the `tanh-table` feature is on.
+fn tanh_lookup<T: CordicNumber>(x: T) -> Option<T> {
+    // Scaling the fractional part by 64 needs 8 integer bits.
+    if !cfg!(feature = "tanh-table") || T::total_bits() - T::frac_bits() < 8 {
+        return None;
+    }
+    let abs_x = x.abs();
+    let whole = abs_x.to_i32();
+    if whole >= 4 {
+        return None;
+    }
+    // Split |x| · 64 into a table index and the position between entries.
+    let scaled = abs_x.saturating_sub(T::from_num(whole)) << TANH_TABLE_SHIFT;
+    let step = scaled.to_i32();
+    let frac = scaled.saturating_sub(T::from_num(step));
+    #[allow(clippy::cast_sign_loss, reason = "whole and step are non-negative")]
+    let index = ((whole << TANH_TABLE_SHIFT) + step) as usize;
+
+    let lo = T::from_i1f63(*TANH_TABLE.get(index)?);
+    let hi = T::from_i1f63(*TANH_TABLE.get(index + 1)?);
+    Some(
+        lo.saturating_add(frac.saturating_mul(hi.saturating_sub(lo)))
+            .copysign(x),
+    )
+}
+
+/// tanh for |x| ≥ 1 via `e^(-2|x|)`. Where `2|x|` does not fit in `T`,
+/// `e^(-|x|)` is squared instead, which costs up to an extra ULP of
+/// truncation.
+fn tanh_large<T: CordicNumber>(x: T) -> T {
+    let one = T::one();
+    let limit = one.saturating_sub(T::epsilon());
+    let abs_x = x.abs();
+    let e2 = if abs_x <= T::max_value() >> 1 {
+        exp(-(abs_x << 1))
+    } else {
+        let e = exp(-abs_x);
+        e.saturating_mul(e)
+    };
+
+    // Once e^(-2|x|) is below one ULP, tanh rounds to ±1.
+    let magnitude = if e2 <= T::epsilon() {
+        limit
+    } else {
+        let ratio = one
+            .saturating_sub(e2)
+            .saturating_div(one.saturating_add(e2));
+        if ratio > limit { limit } else { ratio }
+    };
+    magnitude.copysign(x)
 }
 
 /// Hyperbolic cotangent. Domain: `x ≠ 0`.
 ///
+/// Near the pole `cosh / sinh` divides by a `sinh(x) ≈ x` whose absolute
+/// error is a fixed number of ULPs, which costs `ε/x²` in the result. For
+/// `|x| < 2^-k`, with `k = ⌈(frac_bits - 8) / 5⌉`, the Laurent series
+/// `1/x + x/3 - x³/45` is used instead; the first omitted term, `2x⁵/945`,
+/// is then below one ULP. Inputs too small for `1/x` to fit saturate.
+///
 /// # Errors
 /// Returns `DomainError` if `x = 0`.
 #[must_use = "returns the hyperbolic cotangent result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn coth<T: CordicNumber>(x: T) -> Result<T> {
     if x == T::zero() {
-        return Err(Error::domain("coth", "non-zero value"));
+        return Err(Error::domain_at("coth", "non-zero value", x));
     }
-    let (s, c) = sinh_cosh(x);
-    Ok(c.div(s))
+
+    let series_shift = (T::frac_bits().saturating_sub(8)).div_ceil(5);
+    if x.abs() < T::one() >> series_shift {
+        let x2 = x.saturating_mul(x);
+        let tail = x.saturating_mul(
+            T::from_i1f63(COTH_X_COEFF_I1F63)
+                .saturating_sub(x2.saturating_mul(T::from_i1f63(COTH_X3_COEFF_I1F63))),
+        );
+        return Ok(T::one().saturating_div(x).saturating_add(tail));
+    }
+
+    // The ratio is taken at |x|, where sinh and cosh are both truncated
+    // toward zero and their errors largely cancel; at -|x| they would not.
+    let (s, c, _) = sinh_cosh_signed(x.abs());
+    let ratio = c.saturating_div(s);
+    Ok(if x.is_negative() { -ratio } else { ratio })
 }
 
 /// Inverse hyperbolic sine. Accepts any value.
+///
+/// Uses hyperbolic CORDIC for |x| < 2. Beyond that, where `x / sqrt(1 + x²)`
+/// crowds against ±1, it uses the logarithmic form, which tends to
+/// `sign(x) · ln(2|x|)`.
 #[must_use]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn asinh<T: CordicNumber>(x: T) -> T {
@@ -193,6 +429,10 @@ pub fn asinh<T: CordicNumber>(x: T) -> T {
         return T::zero();
     }
 
+    if x.abs() >= T::two() {
+        return asinh_large(x.abs()).copysign(x);
+    }
+
     // asinh(x) = atanh(x / sqrt(1 + x²))
     // NonNegative::one_plus_square(x) returns 1 + x², which is always ≥ 1
     let sqrt_term = sqrt_nonneg(NonNegative::one_plus_square(x));
@@ -203,27 +443,55 @@ pub fn asinh<T: CordicNumber>(x: T) -> T {
     atanh_open(arg)
 }
 
+/// asinh for x ≥ 2: `ln(x) + ln(1 + sqrt(1 + 1/x²))`, with nothing squared
+/// but 1/x ≤ 1/2.
+fn asinh_large<T: CordicNumber>(x: T) -> T {
+    let one = T::one();
+    let root = sqrt_nonneg(NonNegative::one_plus_square(one.div(x)));
+    let ln_x = ln_positive(x);
+
+    // Once 1/x² is below one ULP this is exactly ln(2x).
+    if root == one {
+        return ln_x.saturating_add(T::ln_2());
+    }
+    ln_x.saturating_add(ln_positive(one.saturating_add(root)))
+}
+
 /// Inverse hyperbolic cosine. Domain: `x ≥ 1`.
 ///
+/// Computed as `ln(x + sqrt(x² - 1))`. For x ≥ 2 the x is factored out so
+/// nothing is squared, which keeps the result accurate up to `T::MAX`.
+///
 /// # Errors
 /// Returns `DomainError` if `x < 1`.
 #[must_use = "returns the inverse hyperbolic cosine result which should be handled"]
 #[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
 pub fn acosh<T: CordicNumber>(x: T) -> Result<T> {
-    let at_least_one = AtLeastOne::new(x).ok_or_else(|| Error::domain("acosh", "value >= 1"))?;
+    let at_least_one =
+        AtLeastOne::new(x).ok_or_else(|| Error::domain_at("acosh", "value >= 1", x))?;
+    let one = T::one();
 
-    if x == T::one() {
+    if x == one {
         return Ok(T::zero());
     }
 
-    // acosh(x) = atanh(sqrt(x² - 1) / x) for x > 1
-    // NonNegative::square_minus_one gives x² - 1, which is ≥ 0 since x ≥ 1
-    let sqrt_term = sqrt_nonneg(NonNegative::square_minus_one(at_least_one));
+    if x < T::two() {
+        // NonNegative::square_minus_one gives x² - 1 < 3, so nothing overflows
+        let sqrt_term = sqrt_nonneg(NonNegative::square_minus_one(at_least_one));
+        return Ok(ln_positive(x.saturating_add(sqrt_term)));
+    }
 
-    // sqrt(x² - 1) / x is in (-1, 1) for x > 1 since sqrt(x² - 1) < x
-    let arg = OpenUnitInterval::from_sqrt_square_minus_one_div(sqrt_term, at_least_one);
+    // acosh(x) = ln(x) + ln(1 + sqrt(1 - 1/x²)) for x ≥ 2.
+    // 1/x ≤ 1/2, so 1 - 1/x² ∈ [3/4, 1) and the second log is near ln(2).
+    let inv_x = UnitInterval::clamp(one.div(x));
+    let root = sqrt_nonneg(NonNegative::one_minus_square(inv_x));
+    let ln_x = ln_positive(x);
 
-    Ok(atanh_open(arg))
+    // Once 1/x² is below one ULP this is exactly ln(2x).
+    if root == one {
+        return Ok(ln_x.saturating_add(T::ln_2()));
+    }
+    Ok(ln_x.saturating_add(ln_positive(one.saturating_add(root))))
 }
 
 /// Inverse hyperbolic tangent. Domain: `(-1, 1)`.
@@ -235,7 +503,21 @@ pub fn acosh<T: CordicNumber>(x: T) -> Result<T> {
 pub fn atanh<T: CordicNumber>(x: T) -> Result<T> {
     OpenUnitInterval::new(x)
         .map(atanh_open)
-        .ok_or_else(|| Error::domain("atanh", "value in range (-1, 1)"))
+        .ok_or_else(|| Error::domain_at("atanh", "value in range (-1, 1)", x))
+}
+
+/// [`atanh`] with the out-of-range policy taken from `config`. Under
+/// [`Saturation::Clamp`] inputs are clamped to one ULP inside `(-1, 1)`.
+///
+/// # Errors
+/// Returns `DomainError` if `|x| ≥ 1`, unless `config` clamps.
+#[must_use = "returns the inverse hyperbolic tangent result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn atanh_with<T: CordicNumber>(x: T, config: &CordicConfig) -> Result<T> {
+    match config.saturation() {
+        Saturation::Clamp => Ok(atanh_open(OpenUnitInterval::clamp(x))),
+        Saturation::Saturate | Saturation::Error => atanh(x),
+    }
 }
 
 /// Infallible inverse hyperbolic tangent for values in (-1, 1).
@@ -260,7 +542,11 @@ fn atanh_core<T: CordicNumber>(x: T) -> T {
         return zero;
     }
 
-    let threshold = T::from_i1f63(ATANH_REDUCTION_THRESHOLD_I1F63);
+    let threshold = if T::frac_bits() >= 16 {
+        T::from_i1f63(ATANH_REDUCTION_THRESHOLD_PRECISE_I1F63)
+    } else {
+        T::from_i1f63(ATANH_REDUCTION_THRESHOLD_I1F63)
+    };
 
     // Fast path: no argument reduction needed, use CORDIC directly.
     if x.abs() <= threshold {
@@ -268,10 +554,9 @@ fn atanh_core<T: CordicNumber>(x: T) -> T {
         return z;
     }
 
-    // Argument reduction needed. Work with |x| and track sign.
+    // Argument reduction needed. Work with |x| and restore the sign at the end.
     let half = T::half();
     let atanh_half = T::from_i1f63(crate::tables::hyperbolic::ATANH_HALF);
-    let sign = if x.is_negative() { -one } else { one };
     let mut abs_x = x.abs();
 
     // Iterative argument reduction: each step reduces |x| and accumulates
@@ -291,7 +576,7 @@ fn atanh_core<T: CordicNumber>(x: T) -> T {
     // Direct CORDIC computation on the reduced argument
     let (_, _, z) = hyperbolic_vectoring(one, abs_x, zero);
 
-    sign.saturating_mul(accumulated.saturating_add(z))
+    accumulated.saturating_add(z).copysign(x)
 }
 
 /// Inverse hyperbolic cotangent. Domain: `|x| > 1`.
@@ -304,7 +589,7 @@ pub fn acoth<T: CordicNumber>(x: T) -> Result<T> {
     let one = T::one();
 
     if x.abs() <= one {
-        return Err(Error::domain("acoth", "|value| > 1"));
+        return Err(Error::domain_at("acoth", "|value| > 1", x));
     }
 
     // acoth(x) = atanh(1/x)
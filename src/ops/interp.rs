@@ -0,0 +1,83 @@
+//! Interpolation and smoothing helpers (lerp, smoothstep, moving average).
+//!
+//! Small building blocks for graphics, animation and filtering code. All use
+//! only saturating arithmetic, so they are total for every input.
+
+use crate::traits::CordicNumber;
+
+/// Linear interpolation `a + t·(b - a)`.
+///
+/// Returns `a` at `t = 0` and, whenever `b - a` fits in `T`, `b` at `t = 1`;
+/// `t` outside `[0, 1]` extrapolates. For fixed `a` and `b` the result is
+/// monotone in `t`.
+///
+/// The difference `b - a` and every intermediate saturates, so endpoints
+/// further apart than `T::MAX` give a clamped slope rather than wrapping.
+/// Such a pair does not reach `b` at `t = 1`: `lerp(T::MIN, T::MAX, 1)` is
+/// `T::MIN + T::MAX`, not `T::MAX`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn lerp<T: CordicNumber>(a: T, b: T, t: T) -> T {
+    a.saturating_add(t.saturating_mul(b.saturating_sub(a)))
+}
+
+/// Hermite smoothstep between `edge0` and `edge1`.
+///
+/// Maps `x` to `t = (x - edge0) / (edge1 - edge0)` clamped to `[0, 1]`,
+/// then returns `t²·(3 - 2t)`: 0 at or below `edge0`, 1 at or above
+/// `edge1`, with zero slope at both ends. With `edge0 < edge1` the rounded
+/// result never decreases as `x` increases. Reversed edges give a falling
+/// step. If the edges are equal it is a hard step at `edge0`.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn smoothstep<T: CordicNumber>(edge0: T, edge1: T, x: T) -> T {
+    let zero = T::zero();
+    let one = T::one();
+    // Division saturates toward the quotient's sign, including for a zero
+    // width, so the clamp below sees the right side of the step.
+    let t = x.saturating_sub(edge0).div(edge1.saturating_sub(edge0));
+    let t = if t < zero {
+        zero
+    } else if t > one {
+        one
+    } else {
+        t
+    };
+    // t·(t·(3 - 2t)) has an increasing exact value at every step for
+    // t ≤ 1/2, so rounding each product cannot reverse the order. The upper
+    // half mirrors it as 1 - s(1 - t), which keeps the result monotone in x
+    // and exactly symmetric about t = 1/2.
+    let rising = |u: T| {
+        let slope = T::from_num(3).saturating_sub(u.saturating_mul(T::two()));
+        u.saturating_mul(u.saturating_mul(slope))
+    };
+    if t <= one >> 1 {
+        rising(t)
+    } else {
+        one.saturating_sub(rising(one.saturating_sub(t)))
+    }
+}
+
+/// One step of an exponential moving average, `prev + alpha·(sample - prev)`.
+///
+/// `alpha` must be in `[0, 1]`: 0 holds `prev`, 1 jumps to `sample`, and
+/// values in between weight the new sample by `alpha`. Outside that range the
+/// result overshoots like [`lerp`]. Saturates in the same way as [`lerp`].
+///
+/// Fixed-point multiplication rounds toward negative infinity, so a plain
+/// update stops up to `DELTA / alpha` below a rising input. When the step
+/// rounds to zero for a positive `alpha` this moves one `DELTA` toward
+/// `sample` instead, so a constant input is reached exactly.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn ema_step<T: CordicNumber>(prev: T, sample: T, alpha: T) -> T {
+    let zero = T::zero();
+    let difference = sample.saturating_sub(prev);
+    let step = alpha.saturating_mul(difference);
+    let step = if step == zero && alpha > zero && difference != zero {
+        T::epsilon().copysign(difference)
+    } else {
+        step
+    };
+    prev.saturating_add(step)
+}
@@ -8,15 +8,40 @@
 //! - [`circular`]: Trigonometric functions (sin, cos, tan, asin, acos, atan, atan2)
 //! - [`hyperbolic`]: Hyperbolic functions (sinh, cosh, tanh, asinh, acosh, atanh, acoth)
 //! - [`exponential`]: Exponential and logarithmic functions (exp, ln, log2, log10, pow2)
-//! - [`algebraic`]: Algebraic functions (sqrt)
+//! - [`algebraic`]: Algebraic functions (sqrt, normalize3)
+//! - [`array`](mod@array): Element-wise evaluation over fixed-size arrays
+//! - [`checked`]: Overflow-reporting `try_*` variants of the saturating functions
+//! - [`complex`]: Complex arithmetic helpers (`complex_mul`, `cexp`, `csin`, `twiddle`)
+//! - [`interp`]: Interpolation and smoothing helpers (lerp, smoothstep, moving average)
+//! - [`special`]: Special functions (gamma, lgamma, erf, erfc)
 
 pub mod algebraic;
+pub mod array;
+pub mod checked;
 pub mod circular;
+pub mod complex;
 pub mod exponential;
 pub mod hyperbolic;
+pub mod interp;
+pub mod special;
 
 // Re-export all public functions
-pub use algebraic::sqrt;
-pub use circular::{acos, asin, atan, atan2, cos, sin, sin_cos, tan};
-pub use exponential::{exp, ln, log2, log10, pow2};
-pub use hyperbolic::{acosh, acoth, asinh, atanh, cosh, coth, sinh, sinh_cosh, tanh};
+pub use algebraic::{isqrt, normalize3, sqrt, sqrt_cordic, sqrt_i16f16, sqrt_with};
+pub use array::{cos_array, exp_array, map_array, sin_array};
+pub use circular::{
+    acos, acos_clamped, acos_with, angle_diff, asin, asin_clamped, asin_with, atan, atan_with,
+    atan2, atan2_deg, atan2_quadrant, atan2_turns, cos, normalize_angle, normalize2, range_bearing,
+    reduce_periodic, sin, sin_cos, sin_cos_checked, sin_cos_turns, sin_cos_with, sinc, sinc_pi,
+    tan, tan_half, trig_all, try_atan2,
+};
+pub use complex::{ccos, cexp, cexp_scaled, complex_mul, csin, twiddle};
+pub use exponential::{
+    PowBase, exp, exp_max_arg, exp_monotone_slice, exp_with, ln, ln_with, log2, log10, logaddexp,
+    pow, pow2, pow2_clamped, powi, try_exp, try_pow2,
+};
+pub use hyperbolic::{
+    acosh, acoth, asinh, atanh, atanh_with, cosh, cosh_max_arg, coth, sinh, sinh_cosh,
+    sinh_cosh_tanh, sinh_max_arg, sinh_with, tanh, try_cosh, try_sinh, try_sinh_cosh,
+};
+pub use interp::{ema_step, lerp, smoothstep};
+pub use special::{erf, erfc, gamma, lgamma};
@@ -0,0 +1,156 @@
+//! Special functions (gamma, log-gamma, error function).
+
+use crate::error::{Error, Result};
+use crate::ops::exponential::{exp, ln};
+use crate::traits::CordicNumber;
+
+/// ½·ln(2π) in I1F63.
+const HALF_LN_2PI: i64 = 0x759F_C721_92FA_D29A;
+
+/// Stirling tail coefficients 1/12, 1/360 and 1/1260 in I1F63.
+const STIRLING_12: i64 = 0x0AAA_AAAA_AAAA_AAAB;
+const STIRLING_360: i64 = 0x005B_05B0_5B05_B05B;
+const STIRLING_1260: i64 = 0x001A_01A0_1A01_A01A;
+
+/// Abramowitz & Stegun 7.1.26: `p`, in I1F63.
+const ERF_P: i64 = 0x29EE_8152_7AF7_1723;
+
+/// Abramowitz & Stegun 7.1.26: `a₅, …, a₁`, **halved** so that all fit in
+/// I1F63. Highest degree first for Horner evaluation.
+const ERF_A_HALF: [i64; 5] = [
+    0x43EE_1109_5684_B5CC,
+    -0x5D00_715C_0530_E3C5,
+    0x5AF8_7156_EB85_5AD9,
+    -0x1235_31CC_3C14_6980,
+    0x104F_20C6_EC5A_7E1D,
+];
+
+/// Natural logarithm of the gamma function, `ln Γ(x)`. Domain: `x > 0`.
+///
+/// Uses the Stirling series
+/// `(x - ½)·ln(x) - x + ½·ln(2π) + 1/(12x) - 1/(360x³) + 1/(1260x⁵)`,
+/// after shifting `x` upward with `ln Γ(x) = ln Γ(x + 1) - ln(x)` until the
+/// series is accurate: to `x ≥ 8` for types with ≥24 fractional bits, and
+/// `x ≥ 4` otherwise (truncation error ≤ 3e-10 and ≤ 4e-8 respectively).
+///
+/// Each shift costs one [`ln`], so inputs below the threshold are slower,
+/// and near `x = 1` and `x = 2`, where `ln Γ` is zero, the absolute error
+/// of the summed logarithms dominates. Saturates for large `x`
+/// (I16F16 above ~4400). Types that cannot hold the threshold shift until
+/// `x` saturates, and their result is not meaningful.
+///
+/// # Errors
+/// Returns `DomainError` if `x ≤ 0`.
+#[must_use = "returns the log-gamma result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn lgamma<T: CordicNumber>(x: T) -> Result<T> {
+    let zero = T::zero();
+    let one = T::one();
+
+    if x <= zero {
+        return Err(Error::domain_at("lgamma", "positive value", x));
+    }
+
+    let threshold = if T::frac_bits() >= 24 { 8 } else { 4 };
+    // Types that cannot hold the threshold shift until they saturate.
+    let threshold = if T::max_value().to_i32() >= threshold {
+        T::from_num(threshold)
+    } else {
+        T::max_value()
+    };
+
+    // Shift upward: ln Γ(x) = ln Γ(x + n) - Σ ln(x + k)
+    let mut shifted = x;
+    let mut correction = zero;
+    while shifted < threshold {
+        correction = correction.saturating_add(ln(shifted)?);
+        shifted = shifted.saturating_add(one);
+    }
+
+    // Stirling series, tail in Horner form:
+    //   1/(12x) - 1/(360x³) + 1/(1260x⁵) = z·(1/12 - z²·(1/360 - z²/1260))
+    let z = one.div(shifted);
+    let z2 = z.saturating_mul(z);
+    let mut tail =
+        T::from_i1f63(STIRLING_360).saturating_sub(z2.saturating_mul(T::from_i1f63(STIRLING_1260)));
+    tail = T::from_i1f63(STIRLING_12).saturating_sub(z2.saturating_mul(tail));
+    tail = z.saturating_mul(tail);
+
+    let lead = shifted
+        .saturating_sub(T::half())
+        .saturating_mul(ln(shifted)?)
+        .saturating_sub(shifted)
+        .saturating_add(T::from_i1f63(HALF_LN_2PI));
+
+    Ok(lead.saturating_add(tail).saturating_sub(correction))
+}
+
+/// Gamma function, `Γ(x) = exp(ln Γ(x))`. Domain: `x > 0`.
+///
+/// Accuracy follows [`lgamma`], with the relative error of [`exp`] on top.
+/// Saturates to `T::MAX` once `Γ(x)` overflows (I16F16 above ~8.8, since
+/// `Γ(9) = 40320`).
+///
+/// # Errors
+/// Returns `DomainError` if `x ≤ 0`.
+#[must_use = "returns the gamma result which should be handled"]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn gamma<T: CordicNumber>(x: T) -> Result<T> {
+    if x <= T::zero() {
+        return Err(Error::domain_at("gamma", "positive value", x));
+    }
+    lgamma(x).map(exp)
+}
+
+/// Error function, `erf(x) = 2/√π ∫₀ˣ e^(-t²) dt`. Accepts any value.
+/// Returns a value in `[-1, 1]`.
+///
+/// Uses the Abramowitz & Stegun 7.1.26 rational approximation, whose own
+/// error is at most 1.5e-7. That is coarser than I32F32's precision, so
+/// wider types gain nothing beyond ~7 digits. Odd symmetry,
+/// `erf(-x) = -erf(x)`, is exact.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn erf<T: CordicNumber>(x: T) -> T {
+    T::one().saturating_sub(erfc_nonneg(x.abs())).copysign(x)
+}
+
+/// Complementary error function, `erfc(x) = 1 - erf(x)`. Accepts any value.
+/// Returns a value in `[0, 2]`.
+///
+/// For `x ≥ 0` the approximation behind [`erf`] yields `erfc` directly, so
+/// small tails keep their absolute accuracy instead of cancelling in
+/// `1 - erf(x)`. Requires ≥2 integer bits to represent values near 2.
+#[must_use]
+#[cfg_attr(feature = "verify-no-panic", no_panic::no_panic)]
+pub fn erfc<T: CordicNumber>(x: T) -> T {
+    let tail = erfc_nonneg(x.abs());
+    if x.is_negative() {
+        T::two().saturating_sub(tail)
+    } else {
+        tail
+    }
+}
+
+/// `erfc(x)` for `x ≥ 0`: `t·(a₁ + a₂t + … + a₅t⁴)·e^(-x²)`, `t = 1/(1 + px)`.
+fn erfc_nonneg<T: CordicNumber>(x: T) -> T {
+    let one = T::one();
+
+    // The coefficients sum to 1 only up to rounding; pin the exact value.
+    if x == T::zero() {
+        return one;
+    }
+
+    let t = one.div(one.saturating_add(T::from_i1f63(ERF_P).saturating_mul(x)));
+
+    let mut half_poly = T::zero();
+    for &coeff in &ERF_A_HALF {
+        half_poly = half_poly
+            .saturating_mul(t)
+            .saturating_add(T::from_i1f63(coeff));
+    }
+
+    // t·poly/2 ≤ ½ and e^(-x²) ≤ 1, so doubling the product cannot overflow.
+    let gaussian = exp(-x.saturating_mul(x));
+    (t.saturating_mul(half_poly).saturating_mul(gaussian)) << 1
+}
@@ -0,0 +1,120 @@
+//! Truncated Taylor series for small arguments.
+//!
+//! Near zero, CORDIC and quotient forms lose precision to overshoot or
+//! cancellation, so several operations switch to a short series there. The
+//! series here are shared by those operations.
+//!
+//! Each series takes the number of terms to keep, counting the leading one,
+//! and is evaluated in factored Horner form, e.g.
+//!
+//! ```text
+//! sinh(x) = x·(1 + u/6·(1 + u/20·(1 + u/42·(…))))    u = x²
+//! ```
+//!
+//! Every factor divides by a small integer rather than multiplying by a
+//! rounded reciprocal, and for `|x| ≤ 1` each multiplicand stays below one.
+//! The largest divisor is `(2n)(2n+1)` for `n = terms - 1` in the odd
+//! series and `(2n-1)(2n)` in the even ones. A series stops before the
+//! first divisor `T` cannot represent, so narrow types may keep fewer terms
+//! than requested. Callers pick `terms` and the argument range for the
+//! precision of `T`.
+
+use crate::traits::CordicNumber;
+
+/// `1 ± w/d₁·(1 ± w/d₂·(…(1 ± w/dₙ)))` for `k = n, …, 1`, where `d` gives
+/// the divisor of factor `k`.
+fn factored<T: CordicNumber>(
+    w: T,
+    terms: u32,
+    alternating: bool,
+    divisor: fn(u32) -> Option<u32>,
+) -> T {
+    let one = T::one();
+    let mut p = one;
+    for k in (1..kept_terms::<T>(terms, divisor)).rev() {
+        let step = w
+            .div(T::from_num(divisor(k).unwrap_or(1)))
+            .saturating_mul(p);
+        p = if alternating {
+            one.saturating_sub(step)
+        } else {
+            one.saturating_add(step)
+        };
+    }
+    p
+}
+
+/// `terms`, cut before the first factor whose divisor overflows `u32` or
+/// `T`.
+fn kept_terms<T: CordicNumber>(terms: u32, divisor: fn(u32) -> Option<u32>) -> u32 {
+    let limit = u32::try_from(T::max_value().to_i32()).unwrap_or(0);
+    (1..terms)
+        .find(|&k| divisor(k).is_none_or(|d| d > limit))
+        .unwrap_or(terms)
+}
+
+fn odd_divisor(k: u32) -> Option<u32> {
+    let two_k = k.checked_mul(2)?;
+    two_k.checked_mul(two_k.checked_add(1)?)
+}
+
+fn even_divisor(k: u32) -> Option<u32> {
+    let two_k = k.checked_mul(2)?;
+    two_k.checked_mul(two_k - 1)
+}
+
+/// `sin(x)` from `terms` terms of `x - x³/3! + x⁵/5! - …`.
+#[must_use]
+pub fn sin_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    x.saturating_mul(sinc_series(x, terms))
+}
+
+/// `sin(x)/x` from `terms` terms of `1 - x²/3! + x⁴/5! - …`.
+#[must_use]
+pub fn sinc_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    factored(x.saturating_mul(x), terms, true, odd_divisor)
+}
+
+/// `cos(x)` from `terms` terms of `1 - x²/2! + x⁴/4! - …`.
+#[must_use]
+pub fn cos_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    factored(x.saturating_mul(x), terms, true, even_divisor)
+}
+
+/// `sinh(x)` from `terms` terms of `x + x³/3! + x⁵/5! + …`.
+#[must_use]
+pub fn sinh_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    x.saturating_mul(factored(x.saturating_mul(x), terms, false, odd_divisor))
+}
+
+/// `cosh(x)` from `terms` terms of `1 + x²/2! + x⁴/4! + …`.
+#[must_use]
+pub fn cosh_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    factored(x.saturating_mul(x), terms, false, even_divisor)
+}
+
+/// `e^x` from `terms` terms of `1 + x + x²/2! + …`.
+#[must_use]
+pub fn exp_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    factored(x, terms, false, Some)
+}
+
+/// `atanh(x)` from `terms` terms of `x + x³/3 + x⁵/5 + …`.
+///
+/// The ratio of successive terms is `u·(2k-1)/(2k+1)`, so each factor
+/// multiplies by `2k - 1` before dividing; for `|x| < 1` that product stays
+/// below `2k - 1`.
+#[must_use]
+pub fn atanh_series<T: CordicNumber>(x: T, terms: u32) -> T {
+    let one = T::one();
+    let u = x.saturating_mul(x);
+    let mut p = one;
+    let divisor = |k: u32| k.checked_mul(2)?.checked_add(1);
+    for k in (1..kept_terms::<T>(terms, divisor)).rev() {
+        let ratio = u
+            .saturating_mul(T::from_num(2 * k - 1))
+            .div(T::from_num(2 * k + 1));
+        p = one.saturating_add(ratio.saturating_mul(p));
+    }
+    x.saturating_mul(p)
+}
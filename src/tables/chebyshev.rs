@@ -27,7 +27,7 @@ use crate::traits::CordicNumber;
 #[inline]
 pub fn horner<T: CordicNumber, const N: usize>(coeffs: &[i64; N], x: T) -> T {
     let mut iter = coeffs.iter();
-    // First element is the highest-degree coefficient (N ≥ 3 for all tables).
+    // First element is the highest-degree coefficient (N ≥ 2 for all tables).
     let mut result = T::from_i1f63(*iter.next().unwrap_or(&0));
     for &coeff in iter {
         result = T::from_i1f63(coeff).saturating_add(x.saturating_mul(result));
@@ -39,6 +39,13 @@ pub fn horner<T: CordicNumber, const N: usize>(coeffs: &[i64; N], x: T) -> T {
 // Sin: P(u) = (sin(x) - x) / x³,  u = x²,  domain [0, (π/4)²]
 // =============================================================================
 
+/// Minimax coeffs for (sin(x)-x)/x³ in u=x² on [0,(π/4)²]. Fast path (`fast-math`).
+#[rustfmt::skip]
+pub const SIN_P_FAST: [i64; 2] = [
+     0x010D_1609_6BF3_6D28,  // +8.212e-03
+    -0x1555_06D8_1276_7485,  // -1.667e-01
+];
+
 /// Minimax coeffs for (sin(x)-x)/x³ in u=x² on [0,(π/4)²]. Low precision (I16F16-class).
 #[rustfmt::skip]
 pub const SIN_P_LO: [i64; 4] = [
@@ -64,6 +71,13 @@ pub const SIN_P_HI: [i64; 7] = [
 // Cos: Q(u) = (cos(x) - 1) / x²,  u = x²,  domain [0, (π/4)²]
 // =============================================================================
 
+/// Minimax coeffs for (cos(x)-1)/x² in u=x² on [0,(π/4)²]. Fast path (`fast-math`).
+#[rustfmt::skip]
+pub const COS_Q_FAST: [i64; 2] = [
+     0x0539_875E_BF07_9951,  // +4.082e-02
+    -0x3FFD_DBEB_08BA_6993,  // -4.999e-01
+];
+
 /// Minimax coeffs for (cos(x)-1)/x² in u=x² on [0,(π/4)²]. Low precision.
 #[rustfmt::skip]
 pub const COS_Q_LO: [i64; 4] = [
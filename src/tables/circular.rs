@@ -2,6 +2,10 @@
 //!
 //! Gain factor K = ∏ sqrt(1 + 2^(-2i)) ≈ 1.6468. We store 1/K ≈ 0.6073.
 //! For i ≥ 21, atan(x) ≈ x to I1F63 precision.
+//!
+//! With the `small-tables` feature the CORDIC kernel reads a private copy
+//! with 32 entries as I1F31 instead, where atan(x) ≈ x to that precision
+//! from i = 11. The public [`ATAN_TABLE`] is the same either way.
 
 /// `atan(2^-i)` as I1F63. Index 0 = atan(1) = π/4.
 #[rustfmt::skip]
@@ -72,3 +76,100 @@ pub const ATAN_TABLE: [i64; 64] = [
     0x0000_0000_0000_0002,
     0x0000_0000_0000_0001,
 ];
+
+/// `atan(2^-i)` as I1F31. Index 0 = atan(1) = π/4.
+#[cfg(feature = "small-tables")]
+#[rustfmt::skip]
+pub(crate) const ATAN_TABLE_I1F31: [i32; 32] = [
+    0x6487_ED51,  // atan(2^0) = π/4
+    0x3B58_CE0B,  // atan(2^-1)
+    0x1F5B_75F9,  // atan(2^-2)
+    0x0FEA_DD4D,  // atan(2^-3)
+    0x07FD_56EE,  // atan(2^-4)
+    0x03FF_AAB7,  // atan(2^-5)
+    0x01FF_F556,  // atan(2^-6)
+    0x00FF_FEAB,  // atan(2^-7)
+    0x007F_FFD5,  // atan(2^-8)
+    0x003F_FFFB,  // atan(2^-9)
+    0x001F_FFFF,  // atan(2^-10)
+    // For i >= 11: atan(2^-i) ≈ 2^-i to I1F31 precision
+    0x0010_0000,
+    0x0008_0000,
+    0x0004_0000,
+    0x0002_0000,
+    0x0001_0000,
+    0x0000_8000,
+    0x0000_4000,
+    0x0000_2000,
+    0x0000_1000,
+    0x0000_0800,
+    0x0000_0400,
+    0x0000_0200,
+    0x0000_0100,
+    0x0000_0080,
+    0x0000_0040,
+    0x0000_0020,
+    0x0000_0010,
+    0x0000_0008,
+    0x0000_0004,
+    0x0000_0002,
+    0x0000_0001,
+];
+
+/// `atan(2^-i)` as I1F63, rounded to nearest, for `i < 64`.
+///
+/// Evaluated with integer arithmetic only, so it runs in const context. Index
+/// 0 uses Machin's formula `π/4 = 4·atan(1/5) - atan(1/239)`, since the
+/// series at 1 converges too slowly.
+#[must_use]
+pub const fn atan_pow2(i: u32) -> i64 {
+    super::round_i126_to_i1f63(atan_pow2_i126(i))
+}
+
+/// `atan(2^-i)` in units of 2⁻¹²⁶.
+const fn atan_pow2_i126(i: u32) -> i128 {
+    if i == 0 {
+        4 * super::arctan_series_i126(5, true) - super::arctan_series_i126(239, true)
+    } else {
+        super::arctan_series_i126(1 << i, true)
+    }
+}
+
+const _: () = {
+    let mut rest: &[i64] = &ATAN_TABLE;
+    let mut i = 0;
+    while let [entry, tail @ ..] = rest {
+        assert!(
+            *entry == atan_pow2(i),
+            "ATAN_TABLE entry does not match atan(2^-i)"
+        );
+        rest = tail;
+        i += 1;
+    }
+};
+
+#[cfg(feature = "small-tables")]
+const _: () = {
+    let mut rest: &[i32] = &ATAN_TABLE_I1F31;
+    let mut i = 0;
+    while let [entry, tail @ ..] = rest {
+        assert!(
+            *entry == super::round_i126_to_i1f31(atan_pow2_i126(i)),
+            "ATAN_TABLE_I1F31 entry does not match atan(2^-i)"
+        );
+        rest = tail;
+        i += 1;
+    }
+};
+
+/// `1/K` (I1F63), where `K` ≈ 1.6468 is the gain of circular CORDIC.
+///
+/// The product converges to I1F63 precision after 32 iterations; with fewer,
+/// the difference stays below one ULP of the `frac_bits`-iteration types.
+pub const CIRCULAR_GAIN_INV: i64 = 0x4DBA_76D4_21AF_2D34;
+
+/// `1/(2π)` (I1F63), converting radians to turns.
+pub const FRAC_1_2PI: i64 = 0x145F_306D_C9C8_82A5;
+
+/// `180/π / 64` (I1F63), converting radians to degrees once scaled up by 64.
+pub const DEGREES_PER_RADIAN_DIV_64: i64 = 0x7297_7069_8F07_DEE2;
@@ -2,6 +2,10 @@
 //!
 //! Starts at i=1 (atanh(1) undefined). Requires repeats at 4, 13, 40, ...
 //! For i ≥ 21, atanh(x) ≈ x to I1F63 precision.
+//!
+//! With the `small-tables` feature the CORDIC kernel reads a private copy
+//! with 32 entries as I1F31 instead, where atanh(x) ≈ x to that precision
+//! from i = 11. The public [`ATANH_TABLE`] is the same either way.
 
 /// `atanh(2^-i)` as I1F63. Index 0 = atanh(2^-1) = atanh(0.5).
 #[rustfmt::skip]
@@ -73,6 +77,81 @@ pub const ATANH_TABLE: [i64; 64] = [
     0x0000_0000_0000_0001,  // rounds to 1 LSB
 ];
 
+/// `atanh(2^-i)` as I1F31. Index 0 = atanh(2^-1) = atanh(0.5).
+#[cfg(feature = "small-tables")]
+#[rustfmt::skip]
+pub(crate) const ATANH_TABLE_I1F31: [i32; 32] = [
+    0x464F_A9EB,  // atanh(2^-1) = 0.549
+    0x20B1_5DF5,  // atanh(2^-2)
+    0x1015_891D,  // atanh(2^-3)
+    0x0802_AC45,  // atanh(2^-4) - repeat this
+    0x0400_5562,  // atanh(2^-5)
+    0x0200_0AAB,  // atanh(2^-6)
+    0x0100_0155,  // atanh(2^-7)
+    0x0080_002B,  // atanh(2^-8)
+    0x0040_0005,  // atanh(2^-9)
+    0x0020_0001,  // atanh(2^-10)
+    // For i >= 11: atanh(2^-i) ≈ 2^-i to I1F31 precision
+    0x0010_0000,
+    0x0008_0000,
+    0x0004_0000,
+    0x0002_0000,
+    0x0001_0000,
+    0x0000_8000,
+    0x0000_4000,
+    0x0000_2000,
+    0x0000_1000,
+    0x0000_0800,
+    0x0000_0400,
+    0x0000_0200,
+    0x0000_0100,
+    0x0000_0080,
+    0x0000_0040,
+    0x0000_0020,
+    0x0000_0010,
+    0x0000_0008,
+    0x0000_0004,
+    0x0000_0002,
+    0x0000_0001,
+    0x0000_0001,  // rounds to 1 LSB
+];
+
+/// `atanh(2^-i)` as I1F63, rounded to nearest, for `1 ≤ i < 64`.
+///
+/// Evaluated with integer arithmetic only, so it runs in const context.
+/// `ATANH_TABLE[j]` holds `atanh_pow2(j + 1)`.
+#[must_use]
+pub const fn atanh_pow2(i: u32) -> i64 {
+    super::round_i126_to_i1f63(super::arctan_series_i126(1 << i, false))
+}
+
+const _: () = {
+    let mut rest: &[i64] = &ATANH_TABLE;
+    let mut i = 1;
+    while let [entry, tail @ ..] = rest {
+        assert!(
+            *entry == atanh_pow2(i),
+            "ATANH_TABLE entry does not match atanh(2^-i)"
+        );
+        rest = tail;
+        i += 1;
+    }
+};
+
+#[cfg(feature = "small-tables")]
+const _: () = {
+    let mut rest: &[i32] = &ATANH_TABLE_I1F31;
+    let mut i = 1;
+    while let [entry, tail @ ..] = rest {
+        assert!(
+            *entry == super::round_i126_to_i1f31(super::arctan_series_i126(1 << i, false)),
+            "ATANH_TABLE_I1F31 entry does not match atanh(2^-i)"
+        );
+        rest = tail;
+        i += 1;
+    }
+};
+
 /// Returns true if iteration `i` must be repeated for hyperbolic CORDIC convergence.
 ///
 /// The repeat sequence is 4, 13, 40, 121, 364, ... (each term is 3×previous + 1).
@@ -84,3 +163,12 @@ pub const fn needs_repeat(index: u32) -> bool {
 
 /// atanh(0.5) ≈ 0.549 (I1F63). Used for argument reduction.
 pub const ATANH_HALF: i64 = 0x464F_A9EA_B40C_2A5E;
+
+/// `1/K_h - 1` (I1F63), where `K_h` ≈ 0.8282 is the gain of hyperbolic
+/// vectoring including the repeated iterations. Converged to I1F63 precision
+/// after the 24 iterations the kernel always runs.
+pub const HYPERBOLIC_GAIN_INV_MINUS_ONE: i64 = 0x1A8F_4390_0776_1195;
+
+/// `K_h` ≈ 0.8282 (I1F63), the gain of hyperbolic vectoring including the
+/// repeated iterations; the reciprocal of `1 + HYPERBOLIC_GAIN_INV_MINUS_ONE`.
+pub const HYPERBOLIC_GAIN: i64 = 0x6A01_203D_99A6_3986;
@@ -2,17 +2,66 @@
 //!
 //! Tables are stored as `i64` values representing signed I1F63 fixed-point
 //! numbers (1 sign bit, 63 fractional bits), which are then converted to
-//! the target type at runtime.
+//! the target type at runtime. With the `small-tables` feature the CORDIC
+//! kernel reads private copies of the two CORDIC tables instead, 32 `i32`
+//! entries in I1F31 at 128 bytes each. The public tables keep their I1F63
+//! type and contents, and are only linked in where referenced.
 //!
 //! # Table Contents
 //!
 //! - [`ATAN_TABLE`]: `atan(2^-i)` values for circular CORDIC mode
 //! - [`ATANH_TABLE`]: `atanh(2^-i)` values for hyperbolic CORDIC mode
 //! - [`chebyshev`]: Minimax polynomial coefficients for sin/cos evaluation
+//! - [`tanh`]: `tanh` samples on `[0, 4]` for the `tanh-table` feature
+//!
+//! The two CORDIC tables are hand-entered hex. [`circular::atan_pow2`] and
+//! [`hyperbolic::atanh_pow2`] regenerate each entry from its series in const
+//! context, and a `const` assertion beside each table fails the build if any
+//! entry differs.
 
 pub mod chebyshev;
 pub mod circular;
 pub mod hyperbolic;
+pub mod tanh;
 
 pub use circular::ATAN_TABLE;
 pub use hyperbolic::ATANH_TABLE;
+
+/// `atan(1/n)` (or `atanh(1/n)` when `alternating` is false) in units of
+/// 2⁻¹²⁶, summed from the Taylor series `Σ ±n^-(2k+1) / (2k+1)`.
+///
+/// Each term is truncated, so the sum is low by at most one unit per term;
+/// for `n ≥ 2` that is under 2⁻¹¹⁹, far below the 2⁻⁶³ table resolution.
+const fn arctan_series_i126(n: i128, alternating: bool) -> i128 {
+    let mut power = (1_i128 << 126) / n;
+    let mut denominator = 1;
+    let mut sum = 0;
+    let mut negative = false;
+    while power != 0 {
+        let term = power / denominator;
+        sum += if negative { -term } else { term };
+        negative = alternating && !negative;
+        power = power / n / n;
+        denominator += 2;
+    }
+    sum
+}
+
+/// Rounds a value in units of 2⁻¹²⁶ to the nearest I1F63.
+#[allow(
+    clippy::cast_possible_truncation,
+    reason = "callers pass values below 1, which fit in 63 fractional bits"
+)]
+const fn round_i126_to_i1f63(value: i128) -> i64 {
+    ((value + (1 << 62)) >> 63) as i64
+}
+
+/// Rounds a value in units of 2⁻¹²⁶ to the nearest I1F31.
+#[cfg(feature = "small-tables")]
+#[allow(
+    clippy::cast_possible_truncation,
+    reason = "callers pass values below 1, which fit in 31 fractional bits"
+)]
+const fn round_i126_to_i1f31(value: i128) -> i32 {
+    ((value + (1 << 94)) >> 95) as i32
+}
@@ -0,0 +1,78 @@
+//! Hyperbolic tangent table for the `tanh-table` feature.
+//!
+//! `tanh(k/64)` for `k = 0..=256` as I1F63, covering `[0, 4]`. Negative
+//! inputs use odd symmetry. Linear interpolation between entries is accurate
+//! to about 2.5e-5.
+
+/// Spacing between entries is `2^-TANH_TABLE_SHIFT`.
+pub const TANH_TABLE_SHIFT: u32 = 6;
+
+/// `tanh(k / 64)` as I1F63 for `k = 0..=256`.
+#[rustfmt::skip]
+pub const TANH_TABLE: [i64; 257] = [
+    0x0000_0000_0000_0000, 0x01FF_F555_9997_DF89, 0x03FF_AAB3_3256_3BC5, 0x05FE_E040_BE0F_3EE4,  // x = 0.0000
+    0x07FD_5665_F80C_35C1, 0x09FA_CDE9_F244_3E5B, 0x0BF7_0812_3FD8_49E1, 0x0DF1_C6C1_9A5A_E03F,  // x = 0.0625
+    0x0FEA_CC95_E25C_1A7E, 0x11E1_DD05_5CD9_71B1, 0x13D6_BC7B_108C_2504, 0x15C9_3072_279C_7094,  // x = 0.1250
+    0x17B8_FF90_3BF7_7632, 0x19A5_F1BE_7665_0748, 0x1B8F_D041_6A7C_9258, 0x1D76_65CF_9BB8_287F,  // x = 0.1875
+    0x1F59_7EA6_9A1C_85F1, 0x2138_E89E_A737_C99D, 0x2314_733C_D690_8BF9, 0x24EB_EFC3_9EFE_C7B7,  // x = 0.2500
+    0x26BF_3141_D4CA_1BE4, 0x288E_0CA0_06C8_0E8A, 0x2A58_58AC_3B0C_1163, 0x2C1D_EE24_0A05_52BB,  // x = 0.3125
+    0x2DDE_A7BD_191F_5FE3, 0x2F9A_622B_F81E_2C01, 0x3150_FC29_6678_713C, 0x3302_5676_07E1_4A50,  // x = 0.3750
+    0x34AE_53DC_9100_6E71, 0x3654_D932_7705_3B9B, 0x37F5_CD57_2E49_BBAE, 0x3991_1932_059E_8AA0,  // x = 0.4375
+    0x3B26_A7AE_AD15_E6CA, 0x3CB6_65B8_783A_9D2D, 0x3E40_4234_6C8E_BB49, 0x3FC4_2DFA_2DF6_237E,  // x = 0.5000
+    0x4142_1BCB_DB52_DA76, 0x42BA_004C_EE15_04B1, 0x442B_D1F8_2FD8_4143, 0x4597_8914_D95C_CD94,  // x = 0.5625
+    0x46FD_1FAA_EE3E_5522, 0x485C_9176_E8BE_6215, 0x49B5_DBDC_C8CF_1C70, 0x4B08_FDDA_9946_929F,  // x = 0.6250
+    0x4C55_F7FA_7DC5_6D00, 0x4D9C_CC44_5B68_042D, 0x4EDD_7E2F_2DD0_B5B6, 0x5018_1292_1980_6359,  // x = 0.6875
+    0x514C_8F95_4BC8_A09D, 0x527A_FCA2_B7EC_7FBF, 0x53A3_6256_C043_7069, 0x54C5_CA70_D967_613A,  // x = 0.7500
+    0x55E2_3FC4_34A6_61BA, 0x56F8_CE28_7E1A_50A8, 0x5809_826A_B9F0_886F, 0x5914_6A3E_4B91_ADA3,  // x = 0.8125
+    0x5A19_942E_3080_5468, 0x5B19_0F8E_77ED_914D, 0x5C12_EC6D_FF2E_0503, 0x5D07_3B88_7A69_B179,  // x = 0.8750
+    0x5DF6_0E38_D015_F0C4, 0x5EDF_766B_CD04_1F85, 0x5FC3_8693_3611_B88B, 0x60A2_5199_3BD0_6289,  // x = 0.9375
+    0x617B_EAD4_53CE_5156, 0x6250_65FB_7A81_C3D7, 0x631F_D71A_E03D_A68E, 0x63EA_5289_0301_AFCB,  // x = 1.0000
+    0x64AF_ECDC_366D_C663, 0x6570_BAE0_9A9F_3B21, 0x662C_D18E_8246_4956, 0x66E4_4601_47D3_5D86,  // x = 1.0625
+    0x6797_2D6E_9135_A4B8, 0x6845_9D1E_0147_3150, 0x68EF_AA61_55AE_5664, 0x6995_6A8C_EFAF_71D5,  // x = 1.1250
+    0x6A36_F2F0_C625_E985, 0x6AD4_58D1_BE9E_4CE8, 0x6B6D_B163_6B55_FF78, 0x6C03_11C2_2BB6_2E15,  // x = 1.1875
+    0x6C94_8EED_ACB7_B89E, 0x6D22_3DC3_C67B_C93F, 0x6DAC_32FB_B448_AA16, 0x6E32_8321_A403_A669,  // x = 1.2500
+    0x6EB5_4292_9A2F_061C, 0x6F34_8578_A766_1941, 0x6FB0_5FC7_6C48_79D1, 0x7028_E538_E8C0_C860,  // x = 1.3125
+    0x709E_294A_9391_DECC, 0x7110_3F3A_B717_6D56, 0x717F_3A06_102B_E2D7, 0x71EB_2C65_AC2D_131F,  // x = 1.3750
+    0x7254_28CD_0322_FDFB, 0x72BA_4168_4B18_20F4, 0x731D_881B_01C0_9C90, 0x737E_0E7E_A99C_EEB2,  // x = 1.4375
+    0x73DB_E5E1_B7D5_DD83, 0x7437_1F46_B021_33F9, 0x748F_CB63_6C11_F136, 0x74E5_FAA0_8B4A_58EB,  // x = 1.5000
+    0x7539_BD19_0A1A_C2A7, 0x758B_2299_FC2C_F1F6, 0x75DA_3AA2_68F1_05F0, 0x7627_1463_4796_8CC9,  // x = 1.5625
+    0x7671_BEBF_9871_E148, 0x76BA_484C_99C3_935A, 0x7700_BF52_15ED_223F, 0x7745_31CA_C933_A92E,  // x = 1.6250
+    0x7787_AD64_DD46_3CA9, 0x77C8_3F82_78D2_85E6, 0x7806_F53A_6186_9D77, 0x7843_DB58_AEF3_3153,  // x = 1.6875
+    0x787E_FE5F_8CD4_9B04, 0x78B8_6A88_0B5C_A749, 0x78F0_2BC2_FC29_6A1E, 0x7926_4DB9_DAA7_8A80,  // x = 1.7500
+    0x795A_DBCF_BEAF_F3E5, 0x798D_E122_5841_CC5C, 0x79BF_688A_F349_EB50, 0x79EF_7C9F_8278_D441,  // x = 1.8125
+    0x7A1E_27B3_B037_697E, 0x7A4B_73D9_F4D9_2548, 0x7A77_6AE4_B138_AC11, 0x7AA2_1667_4CF8_FA3E,  // x = 1.8750
+    0x7ACB_7FB7_57B2_4C6C, 0x7AF3_AFED_AC5E_2D30, 0x7B1A_AFE7_9661_D0F1, 0x7B40_8847_F7A1_1ABB,  // x = 1.9375
+    0x7B65_4178_6F0F_4F4D, 0x7B88_E3AA_7F3C_A100, 0x7BAB_76D8_B469_63AD, 0x7BCD_02C7_C9B0_DED2,  // x = 2.0000
+    0x7BED_8F07_CCE6_61E9, 0x7C0D_22F5_40C7_771E, 0x7C2B_C5BA_3D2D_D77F, 0x7C49_7E4F_8CF3_1D3D,  // x = 2.0625
+    0x7C66_537D_C93F_20D4, 0x7C82_4BDE_7200_792F, 0x7C9D_6DDD_0354_C0B0, 0x7CB7_BFB8_07AC_0E15,  // x = 2.1250
+    0x7CD1_4782_2678_83C7, 0x7CEA_0B23_2F3F_F6C2, 0x7D02_1059_20EA_7B2A, 0x7D19_5CB9_2D2D_23FF,  // x = 2.1875
+    0x7D2F_F5B0_B7F4_7899, 0x7D45_E086_52B6_1143, 0x7D5B_225A_B394_77D7, 0x7D6F_C029_A843_D514,  // x = 2.2500
+    0x7D83_BECB_04A1_22AA, 0x7D97_22F3_8CF0_8FCB, 0x7DA9_F135_DBBA_8586, 0x7DBC_2E03_4341_4413,  // x = 2.3125
+    0x7DCD_DDAC_AA8B_6D6F, 0x7DDF_0463_6601_FEC8, 0x7DEF_A63A_0BA2_3BDD, 0x7DFF_C725_42C5_E821,  // x = 2.3750
+    0x7E0F_6AFC_8F85_DB5A, 0x7E1E_957B_19BC_8D21, 0x7E2D_4A40_6FAF_9DC9, 0x7E3B_8CD1_4468_AE7F,  // x = 2.4375
+    0x7E49_6098_29C7_071E, 0x7E56_C8E6_4654_98C5, 0x7E63_C8F4_06E9_E20F, 0x7E70_63E1_CC2D_16A2,  // x = 2.5000
+    0x7E7C_9CB8_93F9_B18D, 0x7E88_766A_9EBE_497D, 0x7E93_F3D4_10DF_1968, 0x7E9F_17BB_902C_38A4,  // x = 2.5625
+    0x7EA9_E4D2_DD7A_F403, 0x7EB4_5DB7_6A72_1FF0, 0x7EBE_84F2_EB99_922D, 0x7EC8_5CFB_E6BD_3AB8,  // x = 2.6250
+    0x7ED1_E836_3DB4_8E8F, 0x7EDB_28F3_B59F_2671, 0x7EE4_2174_7AA7_9770, 0x7EEC_D3E7_A05D_A4B8,  // x = 2.6875
+    0x7EF5_426B_9EB8_F997, 0x7EFD_6F0E_CBD5_A1CA, 0x7F05_5BCF_D27B_74D1, 0x7F0D_0A9E_2581_A03A,  // x = 2.7500
+    0x7F14_7D5A_701F_6D4A, 0x7F1B_B5D7_033B_4888, 0x7F22_B5D8_3FC8_F618, 0x7F29_7F14_FE47_BD21,  // x = 2.8125
+    0x7F30_1336_F371_2EF4, 0x7F36_73DB_1229_0516, 0x7F3C_A291_EABE_63BA, 0x7F42_A0E0_078E_9D59,  // x = 2.8750
+    0x7F48_703E_4719_602E, 0x7F4E_121A_3395_FFA5, 0x7F53_87D6_5819_52CE, 0x7F58_D2CA_935B_65D5,  // x = 2.9375
+    0x7F5D_F444_682B_FF88, 0x7F62_ED87_4BA4_BD99, 0x7F67_BFCC_F127_4A7A, 0x7F6C_6C45_9435_EEF4,  // x = 3.0000
+    0x7F70_F418_4034_7FD7, 0x7F75_5863_161F_65C7, 0x7F79_9A3B_9046_3A51, 0x7F7D_BAAE_C417_3228,  // x = 3.0625
+    0x7F81_BAC1_A208_4905, 0x7F85_9B71_33AA_E01A, 0x7F89_5DB2_D7F6_3CA1, 0x7F8D_0274_7DD5_10C7,  // x = 3.1250
+    0x7F90_8A9C_DD01_F630, 0x7F93_F70B_AD3E_7EB5, 0x7F97_4899_DBF0_3DD6, 0x7F9A_8019_C02E_EBB3,  // x = 3.1875
+    0x7F9D_9E57_4D4E_815B, 0x7FA0_A418_43EF_EDE5, 0x7FA3_921C_61A2_C425, 0x7FA6_691D_8F22_01F9,  // x = 3.2500
+    0x7FA9_29D0_0D35_D412, 0x7FAB_D4E2_A043_FC0A, 0x7FAE_6AFE_BA98_4239, 0x7FB0_ECC8_A56E_216C,  // x = 3.3125
+    0x7FB3_5ADF_A8C4_A037, 0x7FB5_B5DE_3205_1238, 0x7FB7_FE59_F985_4316, 0x7FBA_34E4_26ED_55BA,  // x = 3.3750
+    0x7FBC_5A09_7489_6BBF, 0x7FBE_6E52_518E_F3BA, 0x7FC0_7243_035D_48A5, 0x7FC2_665B_C5C1_195C,  // x = 3.4375
+    0x7FC4_4B18_EA41_DCEF, 0x7FC6_20F2_F680_671F, 0x7FC7_E85E_C1AD_806B, 0x7FC9_A1CD_911F_35A4,  // x = 3.5000
+    0x7FCB_4DAD_340B_6518, 0x7FCC_EC68_1E6D_E217, 0x7FCE_7E65_8320_5B9C, 0x7FD0_0409_6D2A_07C3,  // x = 3.5625
+    0x7FD1_7DB4_D84C_EB85, 0x7FD2_EBC5_C8D6_6D44, 0x7FD4_4E97_62B8_B859, 0x7FD5_A681_FFF2_50CA,  // x = 3.6250
+    0x7FD6_F3DB_4649_1008, 0x7FD8_36F6_3C5D_9E3B, 0x7FD9_7023_5E1C_574F, 0x7FDA_9FB0_B090_6772,  // x = 3.6875
+    0x7FDB_C5E9_D51D_C81B, 0x7FDC_E318_1C26_A4FB, 0x7FDD_F782_9720_8F5E, 0x7FDF_036E_2A1D_C678,  // x = 3.7500
+    0x7FE0_071D_9CCE_BBEA, 0x7FE1_02D1_AAFF_DD79, 0x7FE1_F6C9_1497_8F37, 0x7FE2_E340_AD18_24B8,  // x = 3.8125
+    0x7FE3_C873_6AA9_8BC6, 0x7FE4_A69A_74AE_3FC2, 0x7FE5_7DED_31E7_0250, 0x7FE6_4EA1_5628_BC17,  // x = 3.8750
+    0x7FE7_18EA_EFA7_CF1C, 0x7FE7_DCFC_73DC_0BF7, 0x7FE8_9B06_CC00_6315, 0x7FE9_5339_6131_544C,  // x = 3.9375
+    0x7FEA_05C2_282D_085A,  // x = 4.0000
+];
@@ -1,5 +1,6 @@
 //! Trait definitions for types compatible with CORDIC algorithms.
 
+use core::fmt;
 use core::ops::{Add, AddAssign, Mul, Neg, Shl, Shr, Sub, SubAssign};
 use fixed::traits::{Fixed, FixedSigned};
 use fixed::types::extra::{IsLessOrEqual, LeEqU128, True, Unsigned};
@@ -23,8 +24,26 @@ use fixed::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128};
 /// - [`FixedI128<Fract>`](fixed::FixedI128) where Fract ≤ 125
 ///
 /// Common type aliases like `I16F16`, `I32F32`, `I8F24`, `I24F8` all work.
+/// Types with fewer than 3 integer bits are rejected at compile time:
+///
+/// ```compile_fail,E0277
+/// use fixed::types::I1F15;
+///
+/// // error: `FixedI16<U15>` is not a fixed-point type with at least 3 integer bits
+/// let _ = fixed_analytics::sin(I1F15::from_num(0.5));
+/// ```
+///
+/// The trait is sealed: it cannot be implemented outside this crate, so
+/// methods can be added to it without a breaking release.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a fixed-point type with at least 3 integer bits",
+    label = "`CordicNumber` needs a signed fixed-point type with at least 3 integer bits",
+    note = "π takes 2 integer bits and the sign one more",
+    note = "use at most 5, 13, 29, 61 or 125 fractional bits in 8, 16, 32, 64 or 128 bits, e.g. `I3F13` instead of `I1F15`"
+)]
 pub trait CordicNumber:
-    Copy
+    sealed::Sealed
+    + Copy
     + PartialEq
     + PartialOrd
     + Add<Output = Self>
@@ -52,6 +71,12 @@ pub trait CordicNumber:
     }
     /// π. Requires ≥2 integer bits.
     fn pi() -> Self;
+    /// Two-part π for Cody–Waite range reduction: `π ≈ hi + lo·ε`.
+    ///
+    /// `hi` is [`pi`](Self::pi). The residual `π - hi` is below the type's
+    /// resolution, so `lo` holds it in units of [`epsilon`](Self::epsilon)
+    /// (|lo| < 1) rather than as a value of its own.
+    fn pi_hi_lo() -> (Self, Self);
     /// π/2. Requires ≥1 integer bit.
     fn frac_pi_2() -> Self;
     /// π/4.
@@ -70,6 +95,8 @@ pub trait CordicNumber:
     fn abs(self) -> Self;
     /// Fractional bits. Determines CORDIC iteration count.
     fn frac_bits() -> u32;
+    /// Machine epsilon: the smallest positive value, `2^-frac_bits`.
+    fn epsilon() -> Self;
     /// Total bits.
     fn total_bits() -> u32;
     /// Converts from a raw I1F63 representation (1 sign bit, 63 fractional bits).
@@ -81,20 +108,69 @@ pub trait CordicNumber:
     fn is_positive(self) -> bool {
         !self.is_negative() && self != Self::zero()
     }
+    /// Sign: `1` if positive, `-1` if negative, `0` if zero.
+    #[must_use]
+    fn signum(self) -> Self {
+        if self.is_negative() {
+            -Self::one()
+        } else if self == Self::zero() {
+            Self::zero()
+        } else {
+            Self::one()
+        }
+    }
+    /// `|self|` with the sign of `sign`. A zero `sign` counts as positive.
+    /// Saturates for `MIN` like [`abs`](Self::abs).
+    #[must_use]
+    fn copysign(self, sign: Self) -> Self {
+        let magnitude = self.abs();
+        if sign.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
     /// Saturating multiplication.
     #[must_use]
     fn saturating_mul(self, rhs: Self) -> Self;
+    /// Checked multiplication. Returns `None` if the product overflows.
+    #[must_use]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
     /// Saturating addition.
     #[must_use]
     fn saturating_add(self, rhs: Self) -> Self;
     /// Saturating subtraction.
     #[must_use]
     fn saturating_sub(self, rhs: Self) -> Self;
-    /// Division.
+    /// Fused multiply-add, `self × a + b`, with a single rounding toward -∞.
+    ///
+    /// The product is kept at full width before the addition, so this is
+    /// more accurate than `saturating_mul` followed by `saturating_add`.
+    /// Saturates on overflow.
+    #[must_use]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// Wrapping (modular) addition.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping (modular) subtraction.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Division. Saturates like [`saturating_div`](Self::saturating_div).
     #[must_use]
     fn div(self, rhs: Self) -> Self;
+    /// Saturating division.
+    ///
+    /// Returns `max_value` or `min_value`, by the signs of the operands, if
+    /// `rhs` is zero or the quotient overflows. Unlike the inherent
+    /// `saturating_div` on fixed types, this never panics.
+    #[must_use]
+    fn saturating_div(self, rhs: Self) -> Self;
     /// Convert from numeric type.
     fn from_num<N: fixed::traits::ToFixed>(n: N) -> Self;
+    /// Converts an `f64`, rounding to the nearest representable value.
+    /// Returns `None` if `x` is NaN, infinite, or rounds outside the range.
+    #[must_use]
+    fn checked_from_f64(x: f64) -> Option<Self>;
     /// Maximum value.
     fn max_value() -> Self;
     /// Minimum value.
@@ -102,9 +178,79 @@ pub trait CordicNumber:
     /// Round to nearest integer (half away from zero).
     #[must_use]
     fn round(self) -> Self;
-    /// Convert to i32 (truncates toward zero).
+    /// Euclidean remainder, in `[0, |rhs|)`.
+    ///
+    /// Unlike `%`, which takes the sign of `self`, the result is never
+    /// negative, so for a positive period it wraps negative inputs forward.
+    /// The result is exact. Returns zero if `rhs` is zero.
+    #[must_use]
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// Convert to i32, truncating toward zero and saturating outside the
+    /// `i32` range.
     #[must_use]
     fn to_i32(self) -> i32;
+    /// The raw bits widened to `i64`, or `None` for 128-bit types whose bits
+    /// do not fit.
+    #[must_use]
+    fn to_bits_i64(self) -> Option<i64>;
+    /// Writes the decimal value using integer arithmetic only.
+    ///
+    /// The output is the shortest decimal that rounds back to `self`, and
+    /// honors the formatter's precision, width and sign flags.
+    ///
+    /// # Errors
+    /// Returns an error if the formatter does.
+    fn fmt_decimal(self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Converts external `f64` data to `T`, rejecting values `T` cannot hold.
+///
+/// [`from_num`](CordicNumber::from_num) panics on NaN and infinities, and on
+/// out-of-range values panics in debug builds and wraps in release builds.
+/// This returns `None` for all of them instead. In-range values round to the
+/// nearest representable value.
+///
+/// ```
+/// use fixed::types::I16F16;
+/// use fixed_analytics::try_from_f64;
+///
+/// assert_eq!(try_from_f64::<I16F16>(1.5), Some(I16F16::from_num(1.5)));
+/// assert_eq!(try_from_f64::<I16F16>(1e9), None);
+/// assert_eq!(try_from_f64::<I16F16>(f64::NAN), None);
+/// ```
+#[must_use]
+pub fn try_from_f64<T: CordicNumber>(x: f64) -> Option<T> {
+    T::checked_from_f64(x)
+}
+
+/// Keeps [`CordicNumber`] and [`IntegerBitBudget`] to the `FixedI*` impls
+/// below.
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Supplies the constants whose `fixed` definitions need integer bits, for
+/// types where `Fits`, the result of comparing the fractional bits against
+/// the budget, is [`True`].
+///
+/// Only implemented for `Fits = True`, and that impl carries the `typenum`
+/// bounds. The [`CordicNumber`] impls ask for it without them, so a type with
+/// too many fractional bits fails as an unimplemented `CordicNumber` and gets
+/// its diagnostic, rather than as a type mismatch deep in `typenum`.
+#[doc(hidden)]
+pub trait IntegerBitBudget<Fits>: sealed::Sealed {
+    /// One.
+    fn one() -> Self;
+    /// π.
+    fn pi() -> Self;
+    /// π/2.
+    fn frac_pi_2() -> Self;
+    /// e.
+    fn e() -> Self;
+    /// ln(2).
+    fn ln_2() -> Self;
+    /// ln(10).
+    fn ln_10() -> Self;
 }
 
 // =============================================================================
@@ -128,20 +274,16 @@ macro_rules! impl_cordic_generic {
         $frac_pi_2:ty,     // Max frac bits where FRAC_PI_2 fits (total - 1)
         $frac_pi_4:ty      // Max frac bits where FRAC_PI_4 and LN_2 fit
     ) => {
-        impl<Fract> CordicNumber for $fixed_type<Fract>
+        impl<Fract> sealed::Sealed for $fixed_type<Fract> {}
+
+        impl<Fract> IntegerBitBudget<True> for $fixed_type<Fract>
         where
-            Fract: Unsigned
-                + IsLessOrEqual<$max_frac, Output = True>
+            Fract: IsLessOrEqual<$max_frac, Output = True>
                 + IsLessOrEqual<$pi_frac, Output = True>
                 + IsLessOrEqual<$frac_pi_2, Output = True>
                 + IsLessOrEqual<$frac_pi_4, Output = True>
                 + LeEqU128,
         {
-            #[inline]
-            fn zero() -> Self {
-                Self::ZERO
-            }
-
             #[inline]
             fn one() -> Self {
                 Self::ONE
@@ -171,6 +313,79 @@ macro_rules! impl_cordic_generic {
             fn ln_10() -> Self {
                 Self::LN_10
             }
+        }
+
+        // The constants come from `IntegerBitBudget`, so a type that is too
+        // narrow fails this impl on a missing trait, not a `typenum` mismatch,
+        // and gets the `CordicNumber` diagnostic.
+        impl<Fract> CordicNumber for $fixed_type<Fract>
+        where
+            Self: IntegerBitBudget<<Fract as IsLessOrEqual<$pi_frac>>::Output>,
+            Fract: Unsigned
+                + IsLessOrEqual<$max_frac, Output = True>
+                + IsLessOrEqual<$pi_frac>
+                + LeEqU128,
+        {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn one() -> Self {
+                <Self as IntegerBitBudget<_>>::one()
+            }
+
+            #[inline]
+            fn pi() -> Self {
+                <Self as IntegerBitBudget<_>>::pi()
+            }
+
+            #[inline]
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss,
+                reason = "π is positive, and the residual is within one ULP and below 2^63 once rescaled"
+            )]
+            fn pi_hi_lo() -> (Self, Self) {
+                // FRAC_NBITS ≤ 125, so the shift to U2F126 is at least one bit.
+                let shift = 126 - Self::FRAC_NBITS;
+                // U2F126 π is about 2^127.65: it fits a u128 but not an
+                // i128. Both products are below 2^128, and their difference
+                // is within one ULP, so the wrapped u128 difference read as
+                // i128 is the signed residual.
+                let exact = fixed::consts::PI.to_bits();
+                let pi = <Self as IntegerBitBudget<_>>::pi();
+                let residual = exact.wrapping_sub((pi.to_bits() as u128) << shift) as i128;
+                // residual / 2^shift is π - PI in ULPs; rescale it to I1F63.
+                let lo = if shift <= 63 {
+                    residual << (63 - shift)
+                } else {
+                    residual >> (shift - 63)
+                };
+                (pi, Self::from_i1f63(lo as i64))
+            }
+
+            #[inline]
+            fn frac_pi_2() -> Self {
+                <Self as IntegerBitBudget<_>>::frac_pi_2()
+            }
+
+            #[inline]
+            fn e() -> Self {
+                <Self as IntegerBitBudget<_>>::e()
+            }
+
+            #[inline]
+            fn ln_2() -> Self {
+                <Self as IntegerBitBudget<_>>::ln_2()
+            }
+
+            #[inline]
+            fn ln_10() -> Self {
+                <Self as IntegerBitBudget<_>>::ln_10()
+            }
 
             #[inline]
             fn abs(self) -> Self {
@@ -182,6 +397,11 @@ macro_rules! impl_cordic_generic {
                 Self::FRAC_NBITS
             }
 
+            #[inline]
+            fn epsilon() -> Self {
+                Self::DELTA
+            }
+
             #[inline]
             fn total_bits() -> u32 {
                 $total_bits
@@ -230,6 +450,11 @@ macro_rules! impl_cordic_generic {
                 Fixed::saturating_mul(self, rhs)
             }
 
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Fixed::checked_mul(self, rhs)
+            }
+
             #[inline]
             fn saturating_add(self, rhs: Self) -> Self {
                 Fixed::saturating_add(self, rhs)
@@ -240,8 +465,28 @@ macro_rules! impl_cordic_generic {
                 Fixed::saturating_sub(self, rhs)
             }
 
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Fixed::saturating_mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                Fixed::wrapping_add(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                Fixed::wrapping_sub(self, rhs)
+            }
+
             #[inline]
             fn div(self, rhs: Self) -> Self {
+                CordicNumber::saturating_div(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_div(self, rhs: Self) -> Self {
                 match Fixed::checked_div(self, rhs) {
                     Some(v) => v,
                     // Division by zero or overflow: saturate based on sign agreement.
@@ -260,6 +505,11 @@ macro_rules! impl_cordic_generic {
                 Self::from_num(n)
             }
 
+            #[inline]
+            fn checked_from_f64(x: f64) -> Option<Self> {
+                Fixed::checked_from_num(x)
+            }
+
             #[inline]
             fn max_value() -> Self {
                 Self::MAX
@@ -275,13 +525,28 @@ macro_rules! impl_cordic_generic {
                 Fixed::round(self)
             }
 
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                Fixed::checked_rem_euclid(self, rhs).unwrap_or(Self::ZERO)
+            }
+
+            #[inline]
+            fn fmt_decimal(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self, f)
+            }
+
+            #[inline]
+            fn to_i32(self) -> i32 {
+                self.saturating_to_num::<i32>()
+            }
+
             #[inline]
             #[allow(
-                clippy::cast_possible_truncation,
-                reason = "intentional truncation to target type"
+                clippy::cast_lossless,
+                reason = "the same macro widens every width up to i128"
             )]
-            fn to_i32(self) -> i32 {
-                self.to_num::<i32>()
+            fn to_bits_i64(self) -> Option<i64> {
+                i64::try_from(self.to_bits() as i128).ok()
             }
         }
     };
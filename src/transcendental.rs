@@ -0,0 +1,79 @@
+//! Interop with generic code written against [`num_traits`].
+//!
+//! The orphan rule prevents implementing `num_traits::Float` for the `fixed`
+//! types here, and much of `Float` (NaN, infinities) has no fixed-point
+//! meaning anyway. [`Transcendental`] instead extends [`num_traits::Num`] with
+//! the float-style methods this crate provides, so generic code can bound on
+//! `T: Transcendental` and accept any [`CordicNumber`].
+
+use crate::traits::CordicNumber;
+
+/// Float-style transcendental methods for fixed-point types.
+///
+/// Implemented for every [`CordicNumber`] that also implements
+/// [`num_traits::Num`] (all `FixedI*` types, via the `fixed` crate's
+/// `num-traits` support).
+///
+/// Where a float method would return NaN or an infinity, these saturate
+/// instead: `sqrt` of a negative value is zero, and `ln` of a non-positive
+/// value is `T::MIN`.
+///
+/// ```
+/// use fixed::types::I32F32;
+/// use fixed_analytics::Transcendental;
+///
+/// fn hypot_angle<T: Transcendental>(x: T) -> T {
+///     (x.sin() * x.sin() + x.cos() * x.cos()).sqrt()
+/// }
+///
+/// // fast-math's sin and cos are only accurate to about 1e-4.
+/// let tolerance = if cfg!(feature = "fast-math") { 1e-3 } else { 1e-6 };
+/// let one = hypot_angle(I32F32::from_num(0.7));
+/// assert!((one.to_num::<f64>() - 1.0).abs() < tolerance);
+/// ```
+pub trait Transcendental: num_traits::Num + Copy {
+    /// Square root. See [`sqrt`](crate::sqrt).
+    #[must_use]
+    fn sqrt(self) -> Self;
+    /// Sine. See [`sin`](crate::sin).
+    #[must_use]
+    fn sin(self) -> Self;
+    /// Cosine. See [`cos`](crate::cos).
+    #[must_use]
+    fn cos(self) -> Self;
+    /// Exponential. See [`exp`](crate::exp).
+    #[must_use]
+    fn exp(self) -> Self;
+    /// Natural logarithm. See [`ln`](crate::ln).
+    #[must_use]
+    fn ln(self) -> Self;
+    /// Integer power. See [`powi`](crate::powi).
+    #[must_use]
+    fn powi(self, n: i32) -> Self;
+}
+
+impl<T: CordicNumber + num_traits::Num> Transcendental for T {
+    fn sqrt(self) -> Self {
+        crate::sqrt(self).unwrap_or_else(|_| <T as CordicNumber>::zero())
+    }
+
+    fn sin(self) -> Self {
+        crate::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        crate::cos(self)
+    }
+
+    fn exp(self) -> Self {
+        crate::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        crate::ln(self).unwrap_or_else(|_| <T as CordicNumber>::min_value())
+    }
+
+    fn powi(self, n: i32) -> Self {
+        crate::powi(self, n)
+    }
+}
@@ -0,0 +1,190 @@
+//! Tests for configurable iteration counts and saturation policy
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
+mod tests {
+    use fixed::types::{I8F8, I16F16, I32F32};
+    use fixed_analytics::{
+        CordicConfig, Error, Saturation, asin_with, atan, atan_with, atanh_with, exp, exp_with, ln,
+        ln_with, sin_cos, sin_cos_with, sqrt, sqrt_with,
+    };
+
+    fn policy(saturation: Saturation) -> CordicConfig {
+        CordicConfig::new().with_saturation(saturation)
+    }
+
+    /// Largest `atan` error over `[-4, 4]` for the given configuration.
+    fn atan_max_error(config: &CordicConfig) -> f64 {
+        (-400..=400)
+            .map(|i| {
+                let x = I32F32::from_num(i) / 100;
+                let expected = x.to_num::<f64>().atan();
+                (atan_with(x, config).to_num::<f64>() - expected).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Largest `sin_cos` error over `[-4, 4]` for the given configuration.
+    fn sin_cos_max_error(config: &CordicConfig) -> f64 {
+        (-400..=400)
+            .map(|i| {
+                let x = I32F32::from_num(i) / 100;
+                let (s, c) = sin_cos_with(x, config);
+                let x = x.to_num::<f64>();
+                let sin_error = (s.to_num::<f64>() - x.sin()).abs();
+                sin_error.max((c.to_num::<f64>() - x.cos()).abs())
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Largest `sqrt` error over `(0, 1)` for the given configuration.
+    fn sqrt_max_error(config: &CordicConfig) -> f64 {
+        (1..1000)
+            .map(|i| {
+                let x = I32F32::from_num(i) / 1000;
+                let expected = x.to_num::<f64>().sqrt();
+                (sqrt_with(x, config).unwrap().to_num::<f64>() - expected).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn default_reproduces_plain_functions() {
+        let config = CordicConfig::default();
+        assert_eq!(config, CordicConfig::new());
+        for i in -50..=50 {
+            let x = I16F16::from_num(i) / 7;
+            assert_eq!(atan_with(x, &config), atan(x));
+            let y = I32F32::from_num(i32::abs(i)) / 7;
+            assert_eq!(sqrt_with(y, &config).unwrap(), sqrt(y).unwrap());
+        }
+    }
+
+    #[test]
+    fn more_cordic_iterations_improve_accuracy() {
+        let coarse = atan_max_error(&CordicConfig::new().with_cordic_iterations(8));
+        let medium = atan_max_error(&CordicConfig::new().with_cordic_iterations(16));
+        let default = atan_max_error(&CordicConfig::new());
+        assert!(coarse > medium, "8: {coarse}, 16: {medium}");
+        assert!(medium > default, "16: {medium}, default: {default}");
+        assert!(default < 1e-8, "default: {default}");
+    }
+
+    #[test]
+    fn more_cordic_iterations_improve_narrow_atan() {
+        let error = |iterations| {
+            let config = CordicConfig::new().with_cordic_iterations(iterations);
+            (-100..=100)
+                .map(|i| {
+                    let x = I16F16::from_num(i) / 25;
+                    let expected = x.to_num::<f64>().atan();
+                    (atan_with(x, &config).to_num::<f64>() - expected).abs()
+                })
+                .fold(0.0, f64::max)
+        };
+        let (coarse, medium, fine) = (error(4), error(8), error(16));
+        assert!(coarse > medium, "4: {coarse}, 8: {medium}");
+        assert!(medium > fine, "8: {medium}, 16: {fine}");
+        assert!(fine < 2e-4, "16: {fine}");
+    }
+
+    #[test]
+    fn cordic_iterations_beyond_type_width_are_capped() {
+        let x = I16F16::from_num(0.5);
+        let excessive = CordicConfig::new().with_cordic_iterations(40);
+        let full = CordicConfig::new().with_cordic_iterations(31);
+        assert_eq!(atan_with(x, &excessive), atan_with(x, &full));
+        assert!((atan_with(x, &excessive).to_num::<f64>() - 0.5_f64.atan()).abs() < 1e-4);
+        assert_eq!(sin_cos_with(x, &excessive), sin_cos_with(x, &full));
+
+        let narrow = I8F8::from_num(0.5);
+        let past_width = CordicConfig::new().with_cordic_iterations(20);
+        assert!((atan_with(narrow, &past_width).to_num::<f64>() - 0.5_f64.atan()).abs() < 0.02);
+        assert!((atan_with(-narrow, &past_width).to_num::<f64>() + 0.5_f64.atan()).abs() < 0.02);
+        let (s, c) = sin_cos_with(narrow, &past_width);
+        assert!((s.to_num::<f64>() - 0.5_f64.sin()).abs() < 0.02);
+        assert!((c.to_num::<f64>() - 0.5_f64.cos()).abs() < 0.02);
+    }
+
+    #[test]
+    fn sin_cos_with_default_is_polynomial() {
+        for i in -50..=50 {
+            let x = I32F32::from_num(i) / 7;
+            assert_eq!(sin_cos_with(x, &CordicConfig::new()), sin_cos(x));
+        }
+    }
+
+    #[test]
+    fn more_cordic_iterations_improve_sin_cos() {
+        let coarse = sin_cos_max_error(&CordicConfig::new().with_cordic_iterations(8));
+        let medium = sin_cos_max_error(&CordicConfig::new().with_cordic_iterations(16));
+        let fine = sin_cos_max_error(&CordicConfig::new().with_cordic_iterations(32));
+        assert!(coarse > medium, "8: {coarse}, 16: {medium}");
+        assert!(medium > fine, "16: {medium}, 32: {fine}");
+        assert!(fine < 1e-8, "32: {fine}");
+    }
+
+    #[test]
+    fn fewer_newton_iterations_reduce_accuracy() {
+        let few = sqrt_max_error(&CordicConfig::new().with_newton_iterations(2));
+        let default = sqrt_max_error(&CordicConfig::new());
+        assert!(few > default * 1000.0, "2: {few}, default: {default}");
+    }
+
+    #[test]
+    fn sqrt_with_rejects_negative() {
+        assert!(sqrt_with(I16F16::from_num(-1), &CordicConfig::new()).is_err());
+    }
+
+    #[test]
+    fn saturation_defaults_to_saturate() {
+        assert_eq!(CordicConfig::new().saturation(), Saturation::Saturate);
+        assert_eq!(CordicConfig::default(), policy(Saturation::Saturate));
+    }
+
+    #[test]
+    fn saturate_policy_saturates_overflow_and_rejects_domain() {
+        let config = policy(Saturation::Saturate);
+        let big = I16F16::from_num(20);
+        assert_eq!(exp_with(big, &config), Ok(I16F16::MAX));
+        assert_eq!(
+            exp_with(I16F16::ONE, &config),
+            Ok(exp(I16F16::ONE)),
+            "in-range values are unaffected"
+        );
+        assert!(matches!(
+            ln_with(I16F16::ZERO, &config),
+            Err(Error::DomainError { .. })
+        ));
+    }
+
+    #[test]
+    fn error_policy_reports_overflow_and_domain() {
+        let config = policy(Saturation::Error);
+        assert!(matches!(
+            exp_with(I16F16::from_num(20), &config),
+            Err(Error::Overflow { .. })
+        ));
+        assert!(matches!(
+            asin_with(I16F16::from_num(1.5), &config),
+            Err(Error::DomainError { .. })
+        ));
+        assert_eq!(exp_with(I16F16::ONE, &config), Ok(exp(I16F16::ONE)));
+    }
+
+    #[test]
+    fn clamp_policy_never_errors() {
+        let config = policy(Saturation::Clamp);
+        let eps = I16F16::DELTA;
+        assert_eq!(exp_with(I16F16::from_num(20), &config), Ok(I16F16::MAX));
+        assert_eq!(sqrt_with(I16F16::from_num(-4), &config), Ok(I16F16::ZERO));
+        assert_eq!(ln_with(I16F16::from_num(-1), &config), ln(eps));
+        assert_eq!(
+            asin_with(I16F16::from_num(2), &config),
+            asin_with(I16F16::ONE, &config)
+        );
+        let near_one = atanh_with(I16F16::ONE, &config).unwrap();
+        assert_eq!(Ok(near_one), atanh_with(I16F16::ONE - eps, &config));
+        assert!(near_one > I16F16::from_num(5));
+    }
+}
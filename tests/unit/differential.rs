@@ -0,0 +1,215 @@
+//! Differential property tests against the `f64` reference.
+//!
+//! Where [`verification`](super::verification) sweeps a fixed set of 2000
+//! LCG samples, these draw bit patterns with `proptest`, uniformly over
+//! each function's domain. The generator is seeded deterministically, so
+//! every run samples the same inputs and a failure reproduces. A failure
+//! is shrunk and reported as the minimal failing bit pattern together with
+//! its value. Its seed is saved to `differential.proptest-regressions`
+//! beside this file and replayed first on later runs; commit that file.
+//!
+//! Error is measured as `|got - expected| / max(1, |expected|)`: absolute
+//! below 1, relative above. Set `PROPTEST_CASES` to sample more than the
+//! default 256 inputs per function.
+
+#![allow(
+    clippy::panic,
+    reason = "test code reports the shrunk proptest failure by panicking"
+)]
+
+#[cfg(test)]
+mod tests {
+    use core::ops::RangeInclusive;
+
+    use fixed::traits::Fixed;
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::{
+        CordicNumber, Result, acos, acosh, asin, asinh, atan, atanh, cos, cosh, cosh_max_arg, exp,
+        exp_max_arg, ln, log2, log10, sin, sinh, sinh_max_arg, sqrt, tan, tanh,
+    };
+    use proptest::prop_assert;
+    use proptest::strategy::Strategy;
+    use proptest::test_runner::{Config, FileFailurePersistence, TestRng, TestRunner};
+
+    /// Largest accepted error per function, for `I16F16` and `I32F32`.
+    ///
+    /// At least three times the worst error seen over 10⁶ samples of each
+    /// domain below, so a pass is not down to a lucky draw.
+    #[cfg(not(feature = "fast-math"))]
+    const TOLERANCES: &[(&str, f64, f64)] = &[
+        ("sin", 2e-4, 5e-7),
+        ("cos", 2e-4, 5e-7),
+        ("tan", 8e-3, 1.5e-7),
+        ("atan", 5e-4, 4e-9),
+        ("asin", 1e-3, 1.5e-8),
+        ("acos", 1e-3, 1.5e-8),
+        ("sqrt", 4e-5, 8e-12),
+        ("exp", 1e-4, 2e-9),
+        ("ln", 1e-3, 2e-9),
+        ("log2", 1e-3, 3e-9),
+        ("log10", 5e-4, 2e-9),
+        ("sinh", 4e-3, 1.2e-7),
+        ("cosh", 4e-3, 1.2e-7),
+        ("tanh", 2e-4, 1e-9),
+        ("asinh", 1e-3, 3e-9),
+        ("acosh", 1e-3, 3e-9),
+        ("atanh", 5e-3, 1.2e-7),
+    ];
+
+    /// `fast-math` evaluates sin, cos, sinh and cosh with shorter
+    /// polynomials, which dominates their `I32F32` error and that of tan.
+    #[cfg(feature = "fast-math")]
+    const TOLERANCES: &[(&str, f64, f64)] = &[
+        ("sin", 2e-4, 2e-4),
+        ("cos", 2e-4, 2e-4),
+        ("tan", 8e-3, 2e-4),
+        ("atan", 5e-4, 4e-9),
+        ("asin", 1e-3, 1.5e-8),
+        ("acos", 1e-3, 1.5e-8),
+        ("sqrt", 4e-5, 8e-12),
+        ("exp", 1e-4, 2e-9),
+        ("ln", 1e-3, 2.5e-9),
+        ("log2", 1e-3, 3e-9),
+        ("log10", 5e-4, 3e-9),
+        ("sinh", 8e-3, 1.2e-2),
+        ("cosh", 8e-3, 1.2e-2),
+        ("tanh", 4e-4, 1e-9),
+        ("asinh", 1e-3, 3e-9),
+        ("acosh", 1e-3, 3e-9),
+        ("atanh", 5e-3, 1.2e-7),
+    ];
+
+    /// One function under test.
+    struct Case<T> {
+        name: &'static str,
+        /// Inclusive input range; inputs are drawn uniformly from its bits.
+        domain: (T, T),
+        /// Inputs inside `domain` to leave out, such as the poles of tan.
+        skip: fn(f64) -> bool,
+        fixed: fn(T) -> Result<T>,
+        reference: fn(f64) -> f64,
+    }
+
+    impl<T> Case<T> {
+        const fn new(
+            name: &'static str,
+            domain: (T, T),
+            fixed: fn(T) -> Result<T>,
+            reference: fn(f64) -> f64,
+        ) -> Self {
+            Self {
+                name,
+                domain,
+                skip: |_| false,
+                fixed,
+                reference,
+            }
+        }
+    }
+
+    fn cases<T: Fixed + CordicNumber>() -> [Case<T>; 17] {
+        let min = <T as CordicNumber>::min_value();
+        let max = <T as CordicNumber>::max_value();
+        let (zero, one) = (<T as CordicNumber>::zero(), <T as CordicNumber>::one());
+        let delta = <T as CordicNumber>::epsilon();
+        let hundred = T::saturating_from_num(100);
+        let sinh_limit = sinh_max_arg::<T>();
+        let cosh_limit = cosh_max_arg::<T>();
+        let near_one = T::saturating_from_num(0.999);
+        [
+            Case::new("sin", (min, max), |x| Ok(sin(x)), f64::sin),
+            Case::new("cos", (min, max), |x| Ok(cos(x)), f64::cos),
+            Case {
+                skip: |x| x.cos().abs() < 0.01,
+                ..Case::new("tan", (-hundred, hundred), |x| Ok(tan(x)), f64::tan)
+            },
+            Case::new("atan", (min, max), |x| Ok(atan(x)), f64::atan),
+            Case::new("asin", (-one, one), asin, f64::asin),
+            Case::new("acos", (-one, one), acos, f64::acos),
+            Case::new("sqrt", (zero, max), sqrt, f64::sqrt),
+            Case::new(
+                "exp",
+                (T::saturating_from_num(-30), exp_max_arg()),
+                |x| Ok(exp(x)),
+                f64::exp,
+            ),
+            Case::new("ln", (delta, max), ln, f64::ln),
+            Case::new("log2", (delta, max), log2, f64::log2),
+            Case::new("log10", (delta, max), log10, f64::log10),
+            Case::new(
+                "sinh",
+                (-sinh_limit, sinh_limit),
+                |x| Ok(sinh(x)),
+                f64::sinh,
+            ),
+            Case::new(
+                "cosh",
+                (-cosh_limit, cosh_limit),
+                |x| Ok(cosh(x)),
+                f64::cosh,
+            ),
+            Case::new("tanh", (min, max), |x| Ok(tanh(x)), f64::tanh),
+            Case::new("asinh", (min, max), |x| Ok(asinh(x)), f64::asinh),
+            Case::new("acosh", (one, max), acosh, f64::acosh),
+            // atanh's condition number 1/(1 - x²) is unbounded at ±1.
+            Case::new("atanh", (-near_one, near_one), atanh, f64::atanh),
+        ]
+    }
+
+    /// Runs every case against `f64`, taking each tolerance from the
+    /// `column` of [`TOLERANCES`] that belongs to `T`.
+    fn check_all<T>(column: fn(&(&str, f64, f64)) -> f64)
+    where
+        T: Fixed + CordicNumber,
+        T::Bits: core::fmt::Debug,
+        RangeInclusive<T::Bits>: Strategy<Value = T::Bits>,
+    {
+        for case in cases::<T>() {
+            let Some(entry) = TOLERANCES.iter().find(|entry| entry.0 == case.name) else {
+                panic!("{} has no entry in TOLERANCES", case.name);
+            };
+            let tolerance = column(entry);
+            let config = Config {
+                failure_persistence: Some(Box::new(FileFailurePersistence::WithSource(
+                    "proptest-regressions",
+                ))),
+                source_file: Some(file!()),
+                ..Config::default()
+            };
+            let rng = TestRng::deterministic_rng(config.rng_algorithm);
+            let mut runner = TestRunner::new_with_rng(config, rng);
+            let bits = case.domain.0.to_bits()..=case.domain.1.to_bits();
+            let outcome = runner.run(&bits, |bits| {
+                let x = T::from_bits(bits);
+                let input: f64 = x.to_num();
+                if (case.skip)(input) {
+                    return Ok(());
+                }
+                let expected = (case.reference)(input);
+                let got = (case.fixed)(x);
+                prop_assert!(got.is_ok(), "{}({input}) returned {got:?}", case.name);
+                let got: f64 = got.map_or(f64::NAN, Fixed::to_num);
+                let error = (got - expected).abs() / expected.abs().max(1.0);
+                prop_assert!(
+                    error <= tolerance,
+                    "{}({input}): got {got}, expected {expected}, error {error:e} > {tolerance:e}",
+                    case.name
+                );
+                Ok(())
+            });
+            if let Err(failure) = outcome {
+                panic!("{}: {failure}", case.name);
+            }
+        }
+    }
+
+    #[test]
+    fn i16f16_within_tolerance_of_f64() {
+        check_all::<I16F16>(|entry| entry.1);
+    }
+
+    #[test]
+    fn i32f32_within_tolerance_of_f64() {
+        check_all::<I32F32>(|entry| entry.2);
+    }
+}
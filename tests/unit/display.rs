@@ -0,0 +1,34 @@
+//! Tests for decimal formatting
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I8F8, I16F16, I32F32};
+    use fixed_analytics::{CordicNumber, Decimal, sqrt};
+
+    fn show<T: CordicNumber>(x: T) -> String {
+        format!("{}", Decimal(x))
+    }
+
+    #[test]
+    fn formats_exact_values() {
+        assert_eq!(show(I16F16::from_num(1.5)), "1.5");
+        assert_eq!(show(I16F16::from_num(-0.25)), "-0.25");
+        assert_eq!(show(I16F16::ZERO), "0");
+        assert_eq!(show(I8F8::from_num(3)), "3");
+    }
+
+    #[test]
+    fn honors_precision_and_width() {
+        let x = I32F32::from_num(2);
+        let root = sqrt(x).unwrap_or(x);
+        assert_eq!(format!("{:.4}", Decimal(root)), "1.4142");
+        assert_eq!(format!("{:>6}", Decimal(I16F16::from_num(1.5))), "   1.5");
+        assert_eq!(format!("{:+}", Decimal(I16F16::from_num(1.5))), "+1.5");
+    }
+
+    #[test]
+    fn debug_matches_display() {
+        let x = I16F16::from_num(-7.125);
+        assert_eq!(format!("{:?}", Decimal(x)), format!("{}", Decimal(x)));
+    }
+}
@@ -1,15 +1,14 @@
 //! Tests for error types
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
 mod tests {
-    use fixed_analytics::Error;
+    use fixed::types::{I16F16, I32F32, I64F64};
+    use fixed_analytics::{Error, asin, ln, try_atan2};
 
     #[test]
     fn error_display_domain_error() {
-        let err = Error::DomainError {
-            function: "test_fn",
-            expected: "positive value",
-        };
+        let err = Error::domain("test_fn", "positive value");
         let msg = format!("{err}");
         assert!(msg.contains("test_fn"));
         assert!(msg.contains("positive value"));
@@ -32,4 +31,93 @@ mod tests {
         assert_eq!(err1, err2);
         assert_ne!(err1, err3);
     }
+
+    #[test]
+    fn domain_error_carries_offending_bits() {
+        let two = I16F16::from_num(2);
+        assert_eq!(
+            asin(two).unwrap_err(),
+            Error::domain_at("asin", "value in range [-1, 1]", two)
+        );
+
+        let half = I32F32::from_num(-0.5);
+        let Error::DomainError { value, .. } = ln(half).unwrap_err() else {
+            unreachable!("ln reports domain errors");
+        };
+        assert_eq!(value.map(I32F32::from_bits), Some(half));
+
+        assert!(format!("{}", asin(two).unwrap_err()).contains("131072"));
+    }
+
+    #[test]
+    fn domain_error_value_is_none_when_unrepresentable() {
+        assert!(matches!(
+            try_atan2(I16F16::ZERO, I16F16::ZERO),
+            Err(Error::DomainError { value: None, .. })
+        ));
+        // I64F64 bits of -2^62 do not fit in an i64.
+        let huge = I64F64::from_num(-(1_i64 << 62));
+        assert!(matches!(
+            ln(huge),
+            Err(Error::DomainError { value: None, .. })
+        ));
+        assert!(matches!(
+            ln(-I64F64::ONE),
+            Err(Error::DomainError { value: None, .. })
+        ));
+    }
+
+    #[test]
+    fn error_display_overflow() {
+        let err = Error::overflow("sinh_cosh");
+        assert!(matches!(
+            err,
+            Error::Overflow {
+                function: "sinh_cosh",
+                ..
+            }
+        ));
+        let msg = format!("{err}");
+        assert!(msg.contains("sinh_cosh"));
+        assert!(msg.contains("range"));
+        assert_ne!(err, Error::domain("sinh_cosh", "any value"));
+    }
+}
+
+#[cfg(all(test, feature = "defmt"))]
+mod defmt_format {
+    use fixed_analytics::Error;
+
+    const fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn error_implements_defmt_format() {
+        assert_format::<Error>();
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+#[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
+mod serde_roundtrip {
+    use fixed_analytics::Error;
+
+    #[test]
+    fn domain_error_json_roundtrip() {
+        let err = Error::domain("asin", "value in range [-1, 1]");
+        let json = serde_json::to_string(&err).unwrap();
+        assert!(json.contains("DomainError"));
+        assert!(json.contains("asin"));
+
+        // Error borrows its strings for 'static, so the input must outlive it.
+        let json: &'static str = json.leak();
+        let back: Error = serde_json::from_str(json).unwrap();
+        assert_eq!(back, err);
+    }
+
+    #[test]
+    fn domain_error_without_value_field_still_parses() {
+        let json = r#"{"DomainError":{"function":"ln","expected":"positive value"}}"#;
+        let err: Error = serde_json::from_str(json).unwrap();
+        assert_eq!(err, Error::domain("ln", "positive value"));
+    }
 }
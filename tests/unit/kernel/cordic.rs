@@ -3,7 +3,10 @@
 #[cfg(test)]
 mod tests {
     use fixed::types::I16F16;
-    use fixed_analytics::kernel::circular_vectoring;
+    use fixed_analytics::kernel::{
+        Saturating, Wrapping, circular_vectoring, circular_vectoring_with, hyperbolic_vectoring,
+        hyperbolic_vectoring_with,
+    };
 
     #[test]
     fn circular_vectoring_atan_one() {
@@ -13,4 +16,75 @@ mod tests {
         let expected = core::f32::consts::FRAC_PI_4;
         assert!((z_f32 - expected).abs() < 0.01);
     }
+
+    #[test]
+    fn wrapping_kernel_differs_at_overflow() {
+        // The first micro-rotation computes x + y, which overflows.
+        let (x, y, z) = (I16F16::MAX, I16F16::MAX, I16F16::ZERO);
+        let (sat_x, _, _) = circular_vectoring(x, y, z);
+        let (wrap_x, _, _) = circular_vectoring_with::<Wrapping, _>(x, y, z);
+        assert!(sat_x > I16F16::ZERO);
+        assert!(wrap_x < I16F16::ZERO, "wrapped x = {wrap_x}");
+    }
+
+    #[test]
+    fn policies_agree_without_overflow() {
+        let (x, y, z) = (I16F16::ONE, I16F16::from_num(0.5), I16F16::ZERO);
+        assert_eq!(
+            circular_vectoring(x, y, z),
+            circular_vectoring_with::<Wrapping, _>(x, y, z)
+        );
+        assert_eq!(
+            hyperbolic_vectoring(x, y, z),
+            hyperbolic_vectoring_with::<Wrapping, _>(x, y, z)
+        );
+        assert_eq!(
+            circular_vectoring(x, y, z),
+            circular_vectoring_with::<Saturating, _>(x, y, z)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "trace")]
+    fn circular_rotation_traced_residual_converges() {
+        use fixed::types::I32F32;
+        use fixed_analytics::kernel::circular_rotation_traced;
+
+        let eps = I32F32::DELTA.to_num::<f64>();
+        for z0 in [0.1_f64, 0.7, -1.2, 1.7] {
+            let mut residuals = Vec::new();
+            let (x, y, z) = circular_rotation_traced(
+                I32F32::ONE,
+                I32F32::ZERO,
+                I32F32::from_num(z0),
+                |i, r: I32F32| residuals.push((i, r.to_num::<f64>())),
+            );
+            assert_eq!(residuals.len(), 32);
+
+            // The envelope 2^-i shrinks monotonically; each residual stays inside it.
+            for &(i, r) in &residuals {
+                let bound = 32.0_f64.mul_add(eps, (-f64::from(i)).exp2());
+                assert!(
+                    r.abs() <= bound,
+                    "z0 = {z0}: residual {r} after {i} > {bound}"
+                );
+            }
+            assert_eq!(residuals.last().map(|&(_, r)| r), Some(z.to_num()));
+            assert!(
+                z.to_num::<f64>().abs() < 4.0 * eps,
+                "z0 = {z0}: final z = {z}"
+            );
+
+            // x, y carry the circular gain K ≈ 1.64676.
+            let gain = 1.646_760_258_121_066;
+            assert!(
+                (x.to_num::<f64>() - gain * z0.cos()).abs() < 1e-7,
+                "x = {x}"
+            );
+            assert!(
+                (y.to_num::<f64>() - gain * z0.sin()).abs() < 1e-7,
+                "y = {y}"
+            );
+        }
+    }
 }
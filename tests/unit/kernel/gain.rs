@@ -0,0 +1,55 @@
+//! Tests for the typed CORDIC gain constants
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I1F63, I16F16, I32F32};
+    use fixed_analytics::tables::hyperbolic::{HYPERBOLIC_GAIN, HYPERBOLIC_GAIN_INV_MINUS_ONE};
+    use fixed_analytics::{
+        circular_gain_inv_value, hyperbolic_gain_inv_value, hyperbolic_gain_value,
+    };
+
+    #[test]
+    fn circular_gain_inv_is_documented_value() {
+        let short: I16F16 = circular_gain_inv_value();
+        let long: I32F32 = circular_gain_inv_value();
+        assert!((short.to_num::<f64>() - 0.6073).abs() < 1e-4, "{short}");
+        assert!(
+            (long.to_num::<f64>() - 0.607_252_935).abs() < 1e-9,
+            "{long}"
+        );
+    }
+
+    #[test]
+    fn hyperbolic_gains_are_documented_values() {
+        let gain: I32F32 = hyperbolic_gain_value();
+        let inverse: I32F32 = hyperbolic_gain_inv_value();
+        assert!(
+            (gain.to_num::<f64>() - 0.828_159_361).abs() < 1e-9,
+            "{gain}"
+        );
+        assert!(
+            (inverse.to_num::<f64>() - 1.207_497_068).abs() < 1e-9,
+            "{inverse}"
+        );
+        assert!(((gain * inverse).to_num::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hyperbolic_gain_constants_are_reciprocal() {
+        let gain = I1F63::from_bits(HYPERBOLIC_GAIN).to_num::<f64>();
+        let inverse = 1.0 + I1F63::from_bits(HYPERBOLIC_GAIN_INV_MINUS_ONE).to_num::<f64>();
+        assert!(gain.mul_add(inverse, -1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn circular_gain_inv_undoes_vectoring_gain() {
+        // Vectoring (3, 4) leaves K·5 in x.
+        let (x, _, _) = fixed_analytics::kernel::circular_vectoring(
+            I32F32::from_num(3),
+            I32F32::from_num(4),
+            I32F32::ZERO,
+        );
+        let length = x * circular_gain_inv_value::<I32F32>();
+        assert!((length.to_num::<f64>() - 5.0).abs() < 1e-7, "{length}");
+    }
+}
@@ -1,3 +1,4 @@
 //! Tests for CORDIC kernel operations
 
 mod cordic;
+mod gain;
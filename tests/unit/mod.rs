@@ -1,9 +1,14 @@
 //! Unit tests mirroring the src directory structure
 
+mod config;
+mod differential;
+mod display;
 mod error;
 mod kernel;
 mod ops;
+mod series;
 mod smoke;
 mod tables;
 mod traits;
+mod transcendental;
 mod verification;
@@ -2,9 +2,14 @@
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "test code uses f64 casts for verification"
+)]
 mod tests {
-    use fixed::types::I16F16;
-    use fixed_analytics::sqrt;
+    use fixed::traits::Fixed;
+    use fixed::types::{I16F0, I16F16, I32F0, I32F32};
+    use fixed_analytics::{isqrt, normalize2, normalize3, sqrt, sqrt_cordic, sqrt_i16f16};
 
     const TOLERANCE: f32 = 0.02;
 
@@ -55,4 +60,256 @@ mod tests {
             );
         }
     }
+
+    // Raw bits b represent b/2^16, so the nearest root in raw bits is
+    // round(√(b·2^16)). The smallest inputs once started Newton from x
+    // itself and ran out of iterations tens of ULPs away.
+    #[test]
+    fn sqrt_small_inputs_round_to_nearest() {
+        for bits in 1..=1 << 14 {
+            let scaled = u64::try_from(bits).unwrap() << 16;
+            let floor = scaled.isqrt();
+            let nearest = if 4 * scaled > (2 * floor + 1).pow(2) {
+                floor + 1
+            } else {
+                floor
+            };
+            let root = sqrt(I16F16::from_bits(bits)).unwrap();
+            assert_eq!(
+                u64::try_from(root.to_bits()).unwrap(),
+                nearest,
+                "sqrt({bits:#x})"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "trace")]
+    fn sqrt_traced_exits_early_on_perfect_squares() {
+        use fixed_analytics::ops::algebraic::sqrt_traced;
+
+        // Default limit for I16F16 is frac_bits / 2 = 8.
+        for n in 2..=180 {
+            let mut steps = 0;
+            let root = sqrt_traced(I16F16::from_num(n * n), |_, _| steps += 1).unwrap();
+            assert_eq!(root, I16F16::from_num(n));
+            assert!(steps < 8, "sqrt({}) took {steps} steps", n * n);
+        }
+        for n in [2, 3, 10, 1000, 40_000] {
+            let mut estimates = Vec::new();
+            let x = I32F32::from_num(n) * I32F32::from_num(n);
+            let root = sqrt_traced(x, |i, g| estimates.push((i, g))).unwrap();
+            assert_eq!(root, I32F32::from_num(n));
+            assert!(estimates.len() < 16, "sqrt({n}²) took {estimates:?}");
+        }
+        let mut steps = 0;
+        assert_eq!(
+            sqrt_traced(I16F16::ONE, |_, _| steps += 1).unwrap(),
+            I16F16::ONE
+        );
+        assert_eq!(steps, 0);
+        assert!(sqrt_traced(-I16F16::ONE, |_, _| {}).is_err());
+    }
+
+    #[test]
+    fn normalize3_unit_length_and_direction() {
+        let cases: [(f64, f64, f64); 5] = [
+            (1.0, 2.0, 2.0),
+            (-3.0, 0.5, 4.0),
+            (0.001, -0.002, 0.0005),
+            (30_000.0, -30_000.0, 30_000.0),
+            (7.0, 0.0, 0.0),
+        ];
+        for case in cases {
+            let [x, y, z] = <[f64; 3]>::from(case).map(I32F32::from_num);
+            let unit: [f64; 3] =
+                <[I32F32; 3]>::from(normalize3(x, y, z).unwrap()).map(I32F32::to_num);
+            // Compare against the quantized inputs, not the f64 literals.
+            let input: [f64; 3] = [x, y, z].map(I32F32::to_num);
+
+            let length = unit.iter().map(|c| c * c).sum::<f64>().sqrt();
+            assert!((length - 1.0).abs() < 1e-8, "{case:?} -> {length}");
+
+            let norm = input.iter().map(|c| c * c).sum::<f64>().sqrt();
+            for (got, component) in unit.into_iter().zip(input) {
+                let want = component / norm;
+                assert!((got - want).abs() < 1e-8, "{case:?}: {got} vs {want}");
+            }
+        }
+    }
+
+    #[test]
+    fn normalize3_extremes_and_axes() {
+        // Squaring MAX would overflow; the prescaling keeps it in range.
+        let (u, v, w) = normalize3(I16F16::MAX, I16F16::MAX, I16F16::MIN).unwrap();
+        let third = (1.0_f64 / 3.0).sqrt();
+        for (got, want) in [(u, third), (v, third), (w, -third)] {
+            assert!((got.to_num::<f64>() - want).abs() < 1e-4, "{got}");
+        }
+
+        let tiny = I16F16::DELTA;
+        assert_eq!(
+            normalize3(I16F16::ZERO, tiny, I16F16::ZERO).unwrap(),
+            (I16F16::ZERO, I16F16::ONE, I16F16::ZERO)
+        );
+        assert_eq!(
+            normalize3(-I16F16::from_num(5), I16F16::ZERO, I16F16::ZERO).unwrap(),
+            (-I16F16::ONE, I16F16::ZERO, I16F16::ZERO)
+        );
+    }
+
+    #[test]
+    fn normalize3_in_plane_matches_normalize2() {
+        for i in 1..=12 {
+            let x = I32F32::from_num(i) / 4 - I32F32::from_num(1.3);
+            let y = I32F32::from_num(13 - i) / 3;
+            let unit = normalize3(x, y, I32F32::ZERO).unwrap();
+            let (cos, sin) = normalize2(x, y).unwrap();
+            let tolerance = I32F32::from_num(if cfg!(feature = "fast-math") {
+                1e-4
+            } else {
+                1e-8
+            });
+            assert_eq!(unit.2, I32F32::ZERO);
+            assert!((unit.0 - cos).abs() < tolerance, "{} vs {cos}", unit.0);
+            assert!((unit.1 - sin).abs() < tolerance, "{} vs {sin}", unit.1);
+        }
+    }
+
+    #[test]
+    fn normalize3_rejects_zero_vector() {
+        assert!(normalize3(I16F16::ZERO, I16F16::ZERO, I16F16::ZERO).is_err());
+    }
+
+    #[test]
+    fn sqrt_i16f16_in_const_context() {
+        const ROOT: i32 = sqrt_i16f16(I16F16::from_bits(4 << 16).to_bits());
+        assert_eq!(ROOT, 2 << 16);
+    }
+
+    #[test]
+    fn sqrt_i16f16_truncates_exact_root() {
+        for bits in [1, 0x4000, 0x8000, 3 << 16, 100 << 16, i32::MAX] {
+            let expected = i32::try_from((i64::from(bits) << 16).isqrt()).unwrap();
+            assert_eq!(sqrt_i16f16(bits), expected, "sqrt_i16f16({bits:#x})");
+        }
+    }
+
+    #[test]
+    fn sqrt_i16f16_non_positive_is_zero() {
+        assert_eq!(sqrt_i16f16(0), 0);
+        assert_eq!(sqrt_i16f16(-1 << 16), 0);
+        assert_eq!(sqrt_i16f16(i32::MIN), 0);
+    }
+
+    #[test]
+    fn isqrt_perfect_squares() {
+        for n in [0, 1, 4, 9, 144, 10_000, 46_340 * 46_340] {
+            let root = isqrt(I32F0::from_num(n));
+            assert_eq!(root * root, I32F0::from_num(n), "isqrt({n})");
+        }
+    }
+
+    #[test]
+    fn isqrt_non_perfect_squares_round_down() {
+        assert_eq!(isqrt(I32F0::from_num(17)), I32F0::from_num(4));
+        assert_eq!(isqrt(I32F0::from_num(2)), I32F0::from_num(1));
+        assert_eq!(isqrt(I32F0::from_num(99)), I32F0::from_num(9));
+        assert_eq!(isqrt(I32F0::MAX), I32F0::from_num(46_340));
+        assert_eq!(isqrt(I16F0::MAX), I16F0::from_num(181));
+    }
+
+    #[test]
+    fn isqrt_negative_is_zero() {
+        assert_eq!(isqrt(I32F0::from_num(-17)), I32F0::ZERO);
+        assert_eq!(isqrt(I16F16::from_num(-4)), I16F16::ZERO);
+    }
+
+    #[test]
+    fn isqrt_falls_back_to_sqrt_with_fractional_bits() {
+        let x = I16F16::from_num(2);
+        assert_eq!(isqrt(x), sqrt(x).unwrap());
+    }
+
+    /// Newton-Raphson using only division and a truncating halve, as `sqrt`
+    /// did before rounding through `mul_add`.
+    fn division_only_sqrt<T: Fixed>(x: T) -> T {
+        let one = T::from_num(1);
+        let mut guess = if x > one { x } else { one };
+        for _ in 0..64 {
+            let next = (guess + x / guess) >> 1_u32;
+            if next == guess {
+                break;
+            }
+            guess = next;
+        }
+        guess
+    }
+
+    /// Mean and maximum error in ULPs over a spread of positive inputs.
+    fn ulp_errors<T: Fixed>(root: impl Fn(T) -> T) -> (f64, f64) {
+        let ulp: f64 = T::DELTA.to_num();
+        let (mut sum, mut max) = (0.0, 0.0_f64);
+        let count = 5000;
+        for i in 1..=count {
+            let t = f64::from(i);
+            let x = T::from_num(t.mul_add(1e-4 * t, 0.0137 * t));
+            let exact = x.to_num::<f64>().sqrt();
+            let err = (root(x).to_num::<f64>() - exact).abs() / ulp;
+            sum += err;
+            max = max.max(err);
+        }
+        (sum / f64::from(count), max)
+    }
+
+    #[test]
+    fn sqrt_mul_add_rounding_beats_division_only_i16f16() {
+        let (old_mean, old_max) = ulp_errors(division_only_sqrt::<I16F16>);
+        let (new_mean, new_max) = ulp_errors(|x: I16F16| sqrt(x).unwrap());
+        assert!(new_mean < old_mean, "I16F16 mean {new_mean} vs {old_mean}");
+        assert!(new_max <= 0.5 + 1e-9, "I16F16 max {new_max} vs {old_max}");
+    }
+
+    #[test]
+    fn sqrt_mul_add_rounding_beats_division_only_i32f32() {
+        let (old_mean, old_max) = ulp_errors(division_only_sqrt::<I32F32>);
+        let (new_mean, new_max) = ulp_errors(|x: I32F32| sqrt(x).unwrap());
+        assert!(new_mean < old_mean, "I32F32 mean {new_mean} vs {old_mean}");
+        assert!(new_max <= 0.5 + 1e-9, "I32F32 max {new_max} vs {old_max}");
+    }
+
+    /// Largest error of `sqrt_cordic` over `[0, 100]`, in ULPs.
+    fn sqrt_cordic_max_ulps<T: Fixed + fixed_analytics::CordicNumber>() -> f64 {
+        let ulp: f64 = T::DELTA.to_num();
+        (0..=10_000)
+            .map(|i| {
+                let x = <T as Fixed>::from_num(f64::from(i) / 100.0);
+                let expected = x.to_num::<f64>().sqrt();
+                (sqrt_cordic(x).to_num::<f64>() - expected).abs() / ulp
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn sqrt_cordic_accurate_on_zero_to_hundred() {
+        let i16f16 = sqrt_cordic_max_ulps::<I16F16>();
+        assert!(i16f16 < 2.0, "I16F16 max error {i16f16} ULP");
+        let i32f32 = sqrt_cordic_max_ulps::<I32F32>();
+        assert!(i32f32 < 2.0, "I32F32 max error {i32f32} ULP");
+    }
+
+    #[test]
+    fn sqrt_cordic_extremes() {
+        assert_eq!(sqrt_cordic(I16F16::ZERO), I16F16::ZERO);
+        assert_eq!(sqrt_cordic(I16F16::from_num(-4)), I16F16::ZERO);
+        let tiny: f64 = sqrt_cordic(I16F16::DELTA).to_num();
+        assert!(
+            (tiny - 1.0 / 256.0).abs() < 3e-5,
+            "sqrt_cordic(DELTA) = {tiny}"
+        );
+        let max: f64 = sqrt_cordic(I16F16::MAX).to_num();
+        let expected = I16F16::MAX.to_num::<f64>().sqrt();
+        assert!((max - expected).abs() < 3e-5, "sqrt_cordic(MAX) = {max}");
+        assert_eq!(sqrt_cordic(I32F0::from_num(17)), I32F0::from_num(4));
+    }
 }
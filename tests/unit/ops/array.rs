@@ -0,0 +1,40 @@
+//! Tests for element-wise array helpers
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::{cos, cos_array, exp, exp_array, map_array, sin, sin_array};
+
+    #[test]
+    fn sin_array_matches_sin() {
+        let input: [I32F32; 8] = core::array::from_fn(|i| {
+            I32F32::from_num(i32::try_from(i).unwrap_or(0) - 4) * I32F32::from_num(0.9)
+        });
+        let output = sin_array(input);
+        for (x, s) in input.into_iter().zip(output) {
+            assert_eq!(s, sin(x), "sin_array at {x}");
+        }
+    }
+
+    #[test]
+    fn cos_and_exp_arrays_match_scalar_functions() {
+        let input = [-3.5, -1.0, 0.0, 0.25, 2.0, 9.0].map(I16F16::from_num);
+        for ((x, c), e) in input
+            .into_iter()
+            .zip(cos_array(input))
+            .zip(exp_array(input))
+        {
+            assert_eq!(c, cos(x), "cos_array at {x}");
+            assert_eq!(e, exp(x), "exp_array at {x}");
+        }
+    }
+
+    #[test]
+    fn map_array_applies_closure_in_order() {
+        let input = [1, 2, 3].map(I16F16::from_num);
+        let doubled = map_array(input, |x| x + x);
+        assert_eq!(doubled, [2, 4, 6].map(I16F16::from_num));
+        let empty: [I16F16; 0] = map_array([], sin);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+//! Tests for the overflow-reporting function set
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
+mod tests {
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::ops::checked::*;
+    use fixed_analytics::{Error, cosh, exp, pow2, sinh, sinh_cosh};
+
+    #[test]
+    fn overflow_is_err() {
+        let big = I16F16::from_num(20);
+        assert_eq!(try_exp(big).unwrap_err(), Error::overflow("exp"));
+        assert_eq!(try_pow2(big).unwrap_err(), Error::overflow("pow2"));
+        assert_eq!(try_sinh(big).unwrap_err(), Error::overflow("sinh"));
+        assert_eq!(try_sinh(-big).unwrap_err(), Error::overflow("sinh"));
+        assert_eq!(try_cosh(-big).unwrap_err(), Error::overflow("cosh"));
+        assert_eq!(
+            try_sinh_cosh(big).unwrap_err(),
+            Error::overflow("sinh_cosh")
+        );
+        assert!(try_pow2(I32F32::from_num(31.5)).is_err());
+    }
+
+    #[test]
+    fn in_range_matches_saturating() {
+        for i in -40..=40 {
+            let x = I16F16::from_num(i) / 4;
+            assert_eq!(try_exp(x).unwrap(), exp(x));
+            assert_eq!(try_pow2(x).unwrap(), pow2(x));
+            assert_eq!(try_sinh(x).unwrap(), sinh(x));
+            assert_eq!(try_cosh(x).unwrap(), cosh(x));
+            assert_eq!(try_sinh_cosh(x).unwrap(), sinh_cosh(x));
+        }
+    }
+
+    #[test]
+    fn underflow_is_ok() {
+        let small = I16F16::from_num(-20);
+        assert_eq!(try_exp(small).unwrap(), I16F16::ZERO);
+        assert_eq!(try_pow2(small).unwrap(), I16F16::ZERO);
+    }
+}
@@ -8,8 +8,14 @@
     reason = "test code uses unwrap and f32/f64 casts for conciseness"
 )]
 mod tests {
-    use fixed::types::{I16F16, I32F32};
-    use fixed_analytics::{acos, asin, atan, atan2, cos, sin, sin_cos, tan};
+    use fixed::traits::Fixed;
+    use fixed::types::{I3F13, I16F16, I24F8, I32F0, I32F32, I48F16, I64F64};
+    use fixed_analytics::{
+        CordicNumber, acos, acos_clamped, angle_diff, asin, asin_clamped, atan, atan2, atan2_deg,
+        atan2_quadrant, atan2_turns, cos, normalize_angle, normalize2, range_bearing,
+        reduce_periodic, sin, sin_cos, sin_cos_checked, sin_cos_turns, sinc, sinc_pi, sqrt, tan,
+        tan_half, trig_all, try_atan2,
+    };
 
     const TOLERANCE: f32 = 0.002;
 
@@ -54,6 +60,32 @@ mod tests {
         }
     }
 
+    // sin and cos skip the polynomial they don't need but must agree bit
+    // for bit with sin_cos, including across octant and half-turn boundaries.
+    #[test]
+    fn sin_and_cos_match_sin_cos_projections() {
+        for i in -4000..=4000 {
+            let x16 = I16F16::from_bits(i * 53);
+            let (s16, c16) = sin_cos(x16);
+            assert_eq!(sin(x16), s16, "sin({x16})");
+            assert_eq!(cos(x16), c16, "cos({x16})");
+
+            let x32 = I32F32::from_bits(i64::from(i) * 3_473_457);
+            let (s32, c32) = sin_cos(x32);
+            assert_eq!(sin(x32), s32, "sin({x32})");
+            assert_eq!(cos(x32), c32, "cos({x32})");
+        }
+        for x in [
+            I16F16::FRAC_PI_4,
+            -I16F16::FRAC_PI_4,
+            I16F16::MAX,
+            I16F16::MIN,
+        ] {
+            assert_eq!(sin(x), sin_cos(x).0);
+            assert_eq!(cos(x), sin_cos(x).1);
+        }
+    }
+
     #[test]
     fn atan_special_values() {
         assert!(approx_eq(atan(I16F16::ZERO), 0.0));
@@ -61,6 +93,29 @@ mod tests {
         assert!(approx_eq(atan(-I16F16::ONE), -core::f32::consts::FRAC_PI_4));
     }
 
+    // MIN has no positive counterpart, but abs saturates it to MAX and the
+    // 1/x path keeps the sign, so the extremes are odd-symmetric and land
+    // within a few ULPs of atan(±MAX) rather than on a special-cased ±π/2.
+    #[test]
+    fn atan_extremes_approach_frac_pi_2() {
+        let eps16 = I16F16::DELTA.to_num::<f64>();
+        let max16 = atan(I16F16::MAX).to_num::<f64>();
+        let expected16 = I16F16::MAX.to_num::<f64>().atan();
+        assert!((max16 - expected16).abs() < 8.0 * eps16, "{max16}");
+        assert_eq!(atan(I16F16::MIN), -atan(I16F16::MAX));
+        assert!(atan(I16F16::MAX) < I16F16::FRAC_PI_2);
+
+        let eps32 = I32F32::DELTA.to_num::<f64>();
+        let max32 = atan(I32F32::MAX).to_num::<f64>();
+        assert!((max32 - core::f64::consts::FRAC_PI_2).abs() < 8.0 * eps32);
+        assert_eq!(atan(I32F32::MIN), -atan(I32F32::MAX));
+
+        // With three integer bits MAX is about 4, far from the asymptote.
+        let narrow = atan(I3F13::MIN).to_num::<f64>();
+        let expected_narrow = I3F13::MIN.to_num::<f64>().atan();
+        assert!((narrow - expected_narrow).abs() < 1e-3, "{narrow}");
+    }
+
     // Tests atan2 quadrant correctness
     #[test]
     fn atan2_quadrants() {
@@ -84,6 +139,103 @@ mod tests {
         assert!(q4 < I16F16::ZERO && q4 > -I16F16::FRAC_PI_2);
     }
 
+    #[test]
+    fn atan2_quadrant_indices() {
+        let one = I16F16::ONE;
+        let quarter = core::f32::consts::FRAC_PI_4;
+        for (y, x, quadrant, expected) in [
+            (one, one, 0, quarter),
+            (one, -one, 1, 3.0 * quarter),
+            (-one, -one, 2, -3.0 * quarter),
+            (-one, one, 3, -quarter),
+        ] {
+            let (angle, q) = atan2_quadrant(y, x);
+            assert_eq!(q, quadrant, "atan2_quadrant({y}, {x})");
+            assert_eq!(angle, atan2(y, x));
+            assert!(approx_eq(angle, expected), "atan2({y}, {x}) = {angle}");
+        }
+    }
+
+    #[test]
+    fn atan2_quadrant_on_axes() {
+        let one = I16F16::ONE;
+        let zero = I16F16::ZERO;
+        assert_eq!(atan2_quadrant(one, zero).1, 0);
+        assert_eq!(atan2_quadrant(zero, -one).1, 1);
+        assert_eq!(atan2_quadrant(-one, zero).1, 3);
+        assert_eq!(atan2_quadrant(zero, zero), (zero, 0));
+    }
+
+    #[test]
+    fn atan2_deg_and_turns_quadrants() {
+        let one = I16F16::ONE;
+        for (y, x, degrees) in [
+            (one, one, 45.0),
+            (one, -one, 135.0),
+            (-one, -one, -135.0),
+            (-one, one, -45.0),
+            (one, I16F16::ZERO, 90.0),
+            (I16F16::ZERO, -one, 180.0),
+            (I16F16::ZERO, I16F16::ZERO, 0.0),
+        ] {
+            let deg: f64 = atan2_deg(y, x).to_num();
+            let turns: f64 = atan2_turns(y, x).to_num();
+            assert!((deg - degrees).abs() < 5e-3, "atan2_deg({y}, {x}) = {deg}");
+            assert!(
+                (turns - degrees / 360.0).abs() < 4e-5,
+                "atan2_turns({y}, {x}) = {turns}"
+            );
+        }
+    }
+
+    #[test]
+    fn atan2_deg_and_turns_stay_in_range() {
+        let half_turn = I32F32::from_num(180);
+        let (tiny, behind) = (I32F32::DELTA, I32F32::from_num(-1000));
+        for (y, x) in [
+            (tiny, behind),
+            (-tiny, behind),
+            (I32F32::ZERO, -I32F32::ONE),
+        ] {
+            let deg = atan2_deg(y, x);
+            assert!(deg.abs() <= half_turn, "atan2_deg({y}, {x}) = {deg}");
+            assert!((deg.abs() - half_turn).abs() < I32F32::from_num(1e-6));
+            assert!(atan2_turns(y, x).abs() <= I32F32::ONE / 2);
+        }
+        // I3F13 cannot hold 45; it saturates by sign.
+        assert_eq!(atan2_deg(I3F13::ONE, I3F13::ONE), I3F13::MAX);
+        assert_eq!(atan2_deg(-I3F13::ONE, I3F13::ONE), I3F13::MIN);
+        let eighth: f64 = atan2_turns(I3F13::ONE, I3F13::ONE).to_num();
+        assert!((eighth - 0.125).abs() < 1e-3);
+    }
+
+    #[test]
+    fn atan2_deg_and_turns_on_integer_types() {
+        // No fractional bits to split the conversion constant across.
+        // The degrees are the exact product of the radians, rounded down.
+        let one = I32F0::ONE;
+        for (y, x) in [(one, -one), (one, one), (I32F0::ZERO, -one), (-one, -one)] {
+            let radians: f64 = atan2(y, x).to_num();
+            let expected = radians.to_degrees().floor();
+            assert_eq!(atan2_deg(y, x), I32F0::from_num(expected), "({y}, {x})");
+            assert_eq!(atan2_turns(y, x), I32F0::ZERO, "atan2_turns({y}, {x})");
+        }
+    }
+
+    #[test]
+    fn atan2_deg_rounds_like_the_exact_conversion() {
+        // Converting in T must not add error beyond the radian result's own.
+        for i in -40..=40 {
+            let (y, x) = (I32F32::from_num(i) / 7, I32F32::from_num(3));
+            let radians: f64 = atan2(y, x).to_num();
+            let deg: f64 = atan2_deg(y, x).to_num();
+            assert!(
+                (deg - radians.to_degrees()).abs() <= 2.0 * I32F32::DELTA.to_num::<f64>(),
+                "atan2_deg({y}, {x}) = {deg}"
+            );
+        }
+    }
+
     #[test]
     fn atan2_precise_quadrant_values() {
         // Test precise values for each quadrant
@@ -120,6 +272,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn atan2_accurate_near_pi_and_at_full_range() {
+        // Left half-plane results come from one CORDIC sum seeded at ±π/2,
+        // and large inputs are scaled so the kernel gain cannot saturate.
+        let ulp = I32F32::DELTA.to_num::<f64>();
+        let max = I32F32::MAX;
+        for (fy, fx) in [
+            (I32F32::from_num(1e-6), -I32F32::ONE),
+            (I32F32::from_num(-1e-6), -I32F32::ONE),
+            (I32F32::ONE, I32F32::from_num(-1e6)),
+            (I32F32::from_num(-3), I32F32::from_num(-7.5)),
+            (max, -max),
+            (-max, max / 2),
+            (max - (max >> 10), max),
+        ] {
+            let expected = fy.to_num::<f64>().atan2(fx.to_num::<f64>());
+            let got: f64 = atan2(fy, fx).to_num();
+            assert!(
+                (got - expected).abs() <= 24.0 * ulp,
+                "atan2({fy}, {fx}) = {got}, expected {expected}"
+            );
+            assert!(got.abs() <= core::f64::consts::PI + ulp);
+        }
+    }
+
     #[test]
     fn atan2_axis_values() {
         // Test values along the axes
@@ -156,6 +333,396 @@ mod tests {
         assert!(asin(I16F16::from_num(0.5)).is_ok());
     }
 
+    #[test]
+    fn asin_clamped_saturates_outside_domain() {
+        let over = I16F16::from_num(1.0001);
+        assert!(asin(over).is_err());
+        assert_eq!(asin_clamped(over), I16F16::FRAC_PI_2);
+        assert_eq!(asin_clamped(-over), -I16F16::FRAC_PI_2);
+        assert_eq!(asin_clamped(I16F16::from_num(40)), I16F16::FRAC_PI_2);
+    }
+
+    #[test]
+    fn asin_clamped_matches_asin_inside_domain() {
+        for i in -10..=10 {
+            let x = I16F16::from_num(i) / 10;
+            assert_eq!(asin_clamped(x), asin(x).unwrap(), "x = {x}");
+            assert_eq!(acos_clamped(x), acos(x).unwrap(), "x = {x}");
+        }
+    }
+
+    #[test]
+    fn asin_unit_accepts_proven_bound() {
+        use fixed_analytics::bounded::UnitInterval;
+        use fixed_analytics::ops::circular::{acos_unit, asin_unit};
+
+        let half = UnitInterval::new(I32F32::from_num(0.5)).unwrap();
+        let asin_half: f64 = asin_unit(half).to_num();
+        let acos_half: f64 = acos_unit(half).to_num();
+        assert!((asin_half - core::f64::consts::FRAC_PI_6).abs() < 1e-8);
+        assert!((acos_half - core::f64::consts::FRAC_PI_3).abs() < 1e-8);
+
+        for i in -10..=10 {
+            let x = I16F16::from_num(i) / 10;
+            let unit = UnitInterval::new(x).unwrap();
+            assert_eq!(asin_unit(unit), asin(x).unwrap());
+            assert_eq!(acos_unit(unit), acos(x).unwrap());
+        }
+    }
+
+    #[test]
+    fn unit_interval_clamp_saturates_overshoot() {
+        use fixed_analytics::bounded::UnitInterval;
+
+        let over = I16F16::from_num(1.01);
+        assert!(UnitInterval::new(over).is_none());
+        assert_eq!(UnitInterval::clamp(over).get(), I16F16::ONE);
+        assert_eq!(UnitInterval::clamp(-over).get(), -I16F16::ONE);
+
+        let inside = I16F16::from_num(0.5);
+        assert_eq!(UnitInterval::clamp(inside).get(), inside);
+        assert_eq!(asin_clamped(over), asin(I16F16::ONE).unwrap());
+    }
+
+    #[test]
+    fn acos_clamped_saturates_outside_domain() {
+        let over = I16F16::from_num(1.0001);
+        assert!(acos(over).is_err());
+        assert_eq!(acos_clamped(over), I16F16::ZERO);
+        assert!(approx_eq(acos_clamped(-over), core::f32::consts::PI));
+    }
+
+    #[test]
+    fn tan_half_matches_tan_away_from_poles() {
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-3
+        } else {
+            1e-6
+        };
+        for i in -26..=26 {
+            let x = I32F32::from_num(i) / 20;
+            let expected: f64 = tan(x).to_num();
+            let actual: f64 = tan_half(tan(x / 2)).to_num();
+            assert!(
+                (actual - expected).abs() < tolerance * expected.abs().max(1.0),
+                "x = {x}: {actual} vs {expected}"
+            );
+        }
+        assert_eq!(tan_half(I16F16::ZERO), I16F16::ZERO);
+        assert_eq!(
+            tan_half(-I16F16::from_num(0.5)),
+            -tan_half(I16F16::from_num(0.5))
+        );
+    }
+
+    #[test]
+    fn tan_half_saturates_at_the_pole() {
+        assert_eq!(tan_half(I16F16::ONE), I16F16::MAX);
+        assert_eq!(tan_half(-I16F16::ONE), I16F16::MIN);
+        // Just inside the pole the result is large and keeps its sign.
+        let near = I16F16::ONE - I16F16::DELTA;
+        assert!(tan_half(near) > I16F16::from_num(30_000));
+        assert!(tan_half(-near) < I16F16::from_num(-30_000));
+        // Past it, x is beyond π/2 and tan is negative.
+        assert!(tan_half(I16F16::from_num(3)) < I16F16::ZERO);
+    }
+
+    #[test]
+    fn normalize2_has_unit_length_and_keeps_direction() {
+        let (ux, uy) = normalize2(I16F16::from_num(3), I16F16::from_num(4)).unwrap();
+        assert!(approx_eq(ux, 0.6), "x = {ux}");
+        assert!(approx_eq(uy, 0.8), "y = {uy}");
+
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-6
+        };
+        for (x, y) in [(1.0, 0.0), (-2.5, 7.0), (-100.0, -0.5), (0.001, -0.003)] {
+            let (cx, cy) = normalize2(I32F32::from_num(x), I32F32::from_num(y)).unwrap();
+            let (cx, cy): (f64, f64) = (cx.to_num(), cy.to_num());
+            let scale = f64::hypot(x, y);
+            assert!((cx.hypot(cy) - 1.0).abs() < tolerance, "({x}, {y})");
+            assert!(
+                (cx.mul_add(y, -(cy * x)) / scale).abs() < tolerance,
+                "({x}, {y}) direction"
+            );
+            assert!(cx.mul_add(x, cy * y) > 0.0, "({x}, {y}) orientation");
+        }
+    }
+
+    #[test]
+    fn normalize2_rejects_zero_vector() {
+        assert!(normalize2(I16F16::ZERO, I16F16::ZERO).is_err());
+    }
+
+    #[test]
+    fn range_bearing_matches_hypot_and_atan2() {
+        for (x, y) in [
+            (3.0, 4.0),
+            (-3.0, 4.0),
+            (-3.0, -4.0),
+            (3.0, -4.0),
+            (0.01, 250.0),
+            (-1000.0, 0.5),
+        ] {
+            let (fx, fy) = (I32F32::from_num(x), I32F32::from_num(y));
+            let (range, bearing) = range_bearing(fx, fy);
+            assert_eq!(bearing, atan2(fy, fx), "bearing of ({x}, {y})");
+            let err = (range.to_num::<f64>() - f64::hypot(x, y)) * 2f64.powi(32);
+            assert!(err.abs() <= 8.0, "range of ({x}, {y}) off by {err} ULP");
+
+            let (hx, hy) = (I16F16::from_num(x), I16F16::from_num(y));
+            let (range16, bearing16) = range_bearing(hx, hy);
+            assert_eq!(bearing16, atan2(hy, hx));
+            let err16 = (range16.to_num::<f64>() - f64::hypot(x, y)) * 65536.0;
+            assert!(
+                err16.abs() <= 4.0,
+                "I16F16 range of ({x}, {y}) off by {err16} ULP"
+            );
+        }
+    }
+
+    #[test]
+    fn range_bearing_does_not_overflow_squares() {
+        // 20000² overflows I16F16, but the range itself fits.
+        let c = I16F16::from_num(20000);
+        let (range, bearing) = range_bearing(c, -c);
+        let expected = f64::hypot(20000.0, 20000.0);
+        let err = (range.to_num::<f64>() - expected) * 65536.0;
+        assert!(err.abs() <= 16.0, "range off by {err} ULP");
+        assert_eq!(bearing, atan2(-c, c));
+
+        assert_eq!(range_bearing(I16F16::MAX, I16F16::MAX).0, I16F16::MAX);
+    }
+
+    #[test]
+    fn range_bearing_on_axes_and_origin() {
+        let zero = I16F16::ZERO;
+        let two = I16F16::from_num(2);
+        assert_eq!(range_bearing(zero, zero), (zero, zero));
+        assert_eq!(range_bearing(-two, zero), (two, I16F16::PI));
+        assert_eq!(range_bearing(zero, -two), (two, -I16F16::FRAC_PI_2));
+    }
+
+    #[test]
+    fn range_bearing_on_integer_types() {
+        // The gain correction has no fractional bits to split across.
+        for (x, y) in [(3, 4), (-300, 400), (I32F0::MAX.to_num(), -7)] {
+            let (fx, fy) = (I32F0::from_num(x), I32F0::from_num(y));
+            let (range, bearing) = range_bearing(fx, fy);
+            assert_eq!(bearing, atan2(fy, fx), "bearing of ({x}, {y})");
+            assert!(range >= I32F0::ZERO, "range of ({x}, {y}) = {range}");
+        }
+        let two = I32F0::from_num(2);
+        assert_eq!(range_bearing(-two, I32F0::ZERO).0, two);
+    }
+
+    #[test]
+    fn sin_cos_checked_rejects_beyond_threshold() {
+        // π·2^(2·8 - 5) ≈ 6434
+        assert!(sin_cos_checked(I24F8::from_num(6_500)).is_err());
+        assert!(sin_cos_checked(I24F8::from_num(-6_500)).is_err());
+        assert!(sin_cos_checked(I24F8::MAX).is_err());
+        // π·2^(2·16 - 5) ≈ 4.2e8
+        assert!(sin_cos_checked(I48F16::from_num(4.3e8)).is_err());
+        assert!(sin_cos_checked(I48F16::MIN).is_err());
+
+        // Unchecked, the reduction stays in range even where the correction
+        // for the rounded π exceeds π itself.
+        let (s, c) = sin_cos(I24F8::MAX);
+        assert!(s.abs() <= 1 && c.abs() <= 1, "sin_cos(MAX) = ({s}, {c})");
+    }
+
+    #[test]
+    fn sin_cos_checked_matches_sin_cos_in_range() {
+        for angle in [0.0, 1.0, -3.0, 100.0, 26_000.0, -26_000.0] {
+            let x = I16F16::from_num(angle);
+            assert_eq!(sin_cos_checked(x).unwrap(), sin_cos(x), "angle = {angle}");
+        }
+        for x in [I16F16::MAX, I16F16::MIN] {
+            assert_eq!(sin_cos_checked(x).unwrap(), sin_cos(x));
+        }
+        for x in [I32F32::from_num(1.8e9), I32F32::MAX] {
+            assert_eq!(sin_cos_checked(x).unwrap(), sin_cos(x));
+        }
+        let narrow = I24F8::from_num(6_400);
+        assert_eq!(sin_cos_checked(narrow).unwrap(), sin_cos(narrow));
+    }
+
+    #[test]
+    fn sin_cos_checked_accepts_only_accurate_reductions() {
+        // Within the fast-math polynomial's 5e-5 as well.
+        let error = |s: f64, angle: f64| (s - angle.sin()).abs();
+        let medium = I16F16::from_num(26_000);
+        assert!(error(sin_cos_checked(medium).unwrap().0.to_num(), 26_000.0) < 1e-4);
+        let wide = I32F32::from_num(1.8e9);
+        assert!(error(sin_cos_checked(wide).unwrap().0.to_num(), 1.8e9) < 1e-4);
+        for i in 0..1000 {
+            let narrow = I24F8::from_num(6_400) + I24F8::from_bits(i);
+            let s = sin_cos_checked(narrow).unwrap().0;
+            assert!(
+                error(s.to_num(), narrow.to_num()) < 0.0625,
+                "angle = {narrow}"
+            );
+        }
+    }
+
+    #[test]
+    fn trig_all_matches_individual_functions() {
+        for i in -20..=20 {
+            let angle = I32F32::from_num(i) * I32F32::from_num(0.4);
+            let (s, c, t) = trig_all(angle);
+            assert_eq!(s, sin(angle));
+            assert_eq!(c, cos(angle));
+            assert_eq!(t, tan(angle));
+        }
+    }
+
+    #[test]
+    fn tan_on_integer_types_uses_the_quotient() {
+        // Integer types have no sub-ULP residual for the rotation to carry.
+        for i in -4..=4 {
+            let angle = I32F0::from_num(i);
+            let (s, c) = sin_cos(angle);
+            let quotient = fixed_analytics::CordicNumber::saturating_div(s, c);
+            assert_eq!(tan(angle), quotient, "tan({angle})");
+            assert_eq!(trig_all(angle), (s, c, quotient), "trig_all({angle})");
+        }
+    }
+
+    /// Worst and mean error in ULPs of `tan` and of `sin / cos` at `count`
+    /// angles evenly spaced over `center ± 0.35`.
+    fn tan_errors_near<T>(center: f64, count: u32) -> ((f64, f64), (f64, f64))
+    where
+        T: fixed::traits::Fixed + fixed_analytics::CordicNumber,
+    {
+        let ulp = <T as fixed::traits::Fixed>::DELTA.to_num::<f64>();
+        let (mut rotation, mut quotient) = ((0.0_f64, 0.0_f64), (0.0_f64, 0.0_f64));
+        for i in 0..count {
+            let offset = 0.7_f64.mul_add(f64::from(i) / f64::from(count), -0.35);
+            let x = <T as fixed::traits::Fixed>::from_num(center + offset);
+            let reference = x.to_num::<f64>().tan();
+            let (s, c) = sin_cos(x);
+            let via_tan = (tan(x).to_num::<f64>() - reference).abs() / ulp;
+            let quotient_value = fixed_analytics::CordicNumber::div(s, c);
+            let via_quotient = (quotient_value.to_num::<f64>() - reference).abs() / ulp;
+            rotation = (rotation.0.max(via_tan), rotation.1 + via_tan);
+            quotient = (quotient.0.max(via_quotient), quotient.1 + via_quotient);
+        }
+        let n = f64::from(count);
+        ((rotation.0, rotation.1 / n), (quotient.0, quotient.1 / n))
+    }
+
+    #[test]
+    fn tan_near_pi_over_4_beats_sin_cos_quotient() {
+        if cfg!(feature = "fast-math") || cfg!(feature = "small-tables") {
+            return;
+        }
+        let quarter = core::f64::consts::FRAC_PI_4;
+        for center in [
+            quarter,
+            -quarter,
+            3.0 * quarter,
+            -3.0 * quarter,
+            5.0 * quarter,
+        ] {
+            for (name, (rotation, quotient)) in [
+                ("I16F16", tan_errors_near::<I16F16>(center, 4000)),
+                ("I32F32", tan_errors_near::<I32F32>(center, 4000)),
+            ] {
+                assert!(
+                    rotation.0 <= 2.0,
+                    "{name} near {center}: worst {} ULP",
+                    rotation.0
+                );
+                assert!(rotation.0 < quotient.0 && rotation.1 < quotient.1);
+            }
+        }
+    }
+
+    #[test]
+    fn tan_continuous_at_rotation_band_edges() {
+        // The band is π/8 either side of π/4.
+        for edge in [
+            core::f64::consts::FRAC_PI_8,
+            3.0 * core::f64::consts::FRAC_PI_8,
+        ] {
+            let center = I32F32::from_num(edge);
+            for step in -3..=3 {
+                let x = center + I32F32::DELTA * step;
+                let error = (tan(x).to_num::<f64>() - x.to_num::<f64>().tan()).abs();
+                let tolerance = if cfg!(feature = "fast-math") {
+                    1e-4
+                } else {
+                    1e-8
+                };
+                assert!(error < tolerance, "tan({x}) off by {error}");
+            }
+        }
+    }
+
+    #[test]
+    fn sinc_at_zero_is_one() {
+        assert_eq!(sinc(I16F16::ZERO), I16F16::ONE);
+        assert_eq!(sinc(I32F32::ZERO), I32F32::ONE);
+        assert_eq!(sinc_pi(I32F32::ZERO), I32F32::ONE);
+    }
+
+    #[test]
+    fn sinc_is_continuous_near_zero() {
+        // Sweep across the Taylor/quotient switch at 1/8 and compare to f64.
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-8
+        };
+        for i in -300..=300 {
+            let x = I32F32::from_num(i) / 1000;
+            let xf: f64 = x.to_num();
+            let expected = if xf == 0.0 { 1.0 } else { xf.sin() / xf };
+            let result: f64 = sinc(x).to_num();
+            assert!(
+                (result - expected).abs() < tolerance,
+                "sinc({xf}) = {result}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn sinc_pi_zeros_at_integers() {
+        for n in [1, 2, 3, -4, 100, -1000, 16_000] {
+            let result = sinc_pi(I16F16::from_num(n));
+            assert!(
+                result.abs() < I16F16::from_num(1e-3),
+                "sinc_pi({n}) = {result}"
+            );
+        }
+        let result = sinc_pi(I16F16::MAX);
+        assert!(
+            result.abs() < I16F16::from_num(1e-3),
+            "sinc_pi(MAX) = {result}"
+        );
+    }
+
+    #[test]
+    fn sinc_pi_matches_reference() {
+        use core::f64::consts::PI;
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-7
+        };
+        for x in [0.01, 0.5, -0.5, 1.5, -2.25, 7.3, 40.125] {
+            let expected = (PI * x).sin() / (PI * x);
+            let result: f64 = sinc_pi(I32F32::from_num(x)).to_num();
+            assert!(
+                (result - expected).abs() < tolerance,
+                "sinc_pi({x}) = {result}, expected {expected}"
+            );
+        }
+    }
+
     #[test]
     fn acos_special_values() {
         let result: f32 = acos(I16F16::ONE).unwrap().to_num();
@@ -274,6 +841,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sin_cos_large_angles_correct_pi_rounding() {
+        // 9999·PI is exact in I16F16, but removing 4999 turns of the rounded
+        // 2π would leave thousands of ULPs of error without the correction.
+        let fast = cfg!(feature = "fast-math");
+        let x = I16F16::PI * 9999;
+        let reference = x.to_num::<f64>().sin();
+        let value: f64 = sin(x).to_num();
+        assert!(
+            (value - reference).abs() < if fast { 2e-4 } else { 4.0 / 65_536.0 },
+            "sin({x}) = {value}, expected {reference}"
+        );
+
+        for angle in [1e6, -1e6, 1e8, 2e9] {
+            let result: f64 = sin(I32F32::from_num(angle)).to_num();
+            let expected = f64::sin(angle);
+            assert!(
+                (result - expected).abs() < if fast { 1e-4 } else { 1e-9 },
+                "sin({angle}) = {result}, expected {expected}"
+            );
+        }
+    }
+
     #[test]
     fn atan_large_values() {
         // atan of large values should approach ±π/2
@@ -322,10 +912,8 @@ mod tests {
 
     #[test]
     fn asin_boundary_very_close() {
-        // Test with higher precision type to hit the small sqrt_term boundary path
-        // The threshold is 2^(-15) ≈ 0.0000305, so we need sqrt(1-x²) < 0.0000305
-        // This requires 1-x² < 2^(-30), meaning x must be within 2^(-31) of ±1
-        // Using I32F32 for higher precision
+        // Within one ULP of ±1 the cosine term sqrt(1 - x²) is about 2^-15.5,
+        // the smallest it gets for I32F32.
 
         // Create a value extremely close to -1 but not exactly -1
         // -1 + 2^(-32) (smallest representable distance)
@@ -351,6 +939,45 @@ mod tests {
         );
     }
 
+    /// Largest error of `asin` over `[0.999, 1)` in ULPs of `T`, and the same
+    /// for the `atan(x / sqrt(1 - x²))` formulation it replaced.
+    fn asin_worst_ulps_near_one<T: Fixed + CordicNumber>() -> (f64, f64) {
+        let ulp: f64 = T::DELTA.to_num();
+        let one = <T as CordicNumber>::one();
+        let samples = (0..=4000)
+            .map(|i| <T as Fixed>::from_num(0.999 + 0.001 * f64::from(i) / 4000.0))
+            .chain([one - T::DELTA]);
+        let mut worst = (0.0_f64, 0.0_f64);
+        for x in samples.filter(|&x| x < one) {
+            let expected = x.to_num::<f64>().asin();
+            let root = sqrt(one - x * x).unwrap();
+            let old = atan(CordicNumber::div(x, root));
+            worst.0 = worst
+                .0
+                .max((asin(x).unwrap().to_num::<f64>() - expected).abs() / ulp);
+            worst.1 = worst.1.max((old.to_num::<f64>() - expected).abs() / ulp);
+        }
+        worst
+    }
+
+    #[test]
+    fn asin_accurate_near_one() {
+        let (narrow, _) = asin_worst_ulps_near_one::<I16F16>();
+        assert!(narrow < 16.0, "I16F16: {narrow} ULPs");
+        // Rounding 1 - x² cost the old form up to ~94 ULPs here, and snapping
+        // to π/2 within 2^-31 of 1 cost ~92 000.
+        let (new, old) = asin_worst_ulps_near_one::<I32F32>();
+        assert!(
+            new < 16.0 && old > 4.0 * new,
+            "I32F32: {new} ULPs, was {old}"
+        );
+        // acos is π/2 - asin, so it shares the bound.
+        let x = I32F32::ONE - I32F32::DELTA;
+        let expected = x.to_num::<f64>().acos();
+        let error = (acos(x).unwrap().to_num::<f64>() - expected).abs();
+        assert!(error < 16.0 * I32F32::DELTA.to_num::<f64>(), "{error}");
+    }
+
     #[test]
     fn asin_domain_error_message() {
         // Test that domain errors are properly returned for out-of-range values
@@ -447,6 +1074,248 @@ mod tests {
         );
     }
 
+    #[test]
+    fn angle_diff_takes_short_way_around() {
+        // 3 - (-3) = 6, which wraps to 6 - 2π ≈ -0.283.
+        let expected = 6.0 - core::f32::consts::TAU;
+        let forward: f32 = angle_diff(I16F16::from_num(3.0), I16F16::from_num(-3.0)).to_num();
+        assert!(
+            (forward - expected).abs() < TOLERANCE,
+            "angle_diff = {forward}"
+        );
+
+        let backward: f32 = angle_diff(I16F16::from_num(-3.0), I16F16::from_num(3.0)).to_num();
+        assert!(
+            (backward + expected).abs() < TOLERANCE,
+            "angle_diff = {backward}"
+        );
+    }
+
+    #[test]
+    fn angle_diff_small_and_large_inputs() {
+        let small: f32 = angle_diff(I16F16::from_num(0.5), I16F16::from_num(0.2)).to_num();
+        assert!((small - 0.3).abs() < TOLERANCE);
+
+        // Far apart in raw value but the same heading modulo 2π.
+        let a = I16F16::from_num(20_000.0);
+        let b = a - I16F16::from_num(100.0 * core::f64::consts::TAU);
+        let same_heading: f32 = angle_diff(a, b).to_num();
+        assert!(same_heading.abs() < 0.01, "angle_diff = {same_heading}");
+
+        // Would overflow if subtracted before reducing.
+        let opposite = angle_diff(I16F16::from_num(30_000.0), I16F16::from_num(-30_000.0));
+        assert!(opposite.abs() <= I16F16::PI);
+    }
+
+    #[test]
+    fn sin_cos_turns_exact_at_quarter_turns() {
+        let one = I16F16::ONE;
+        let zero = I16F16::ZERO;
+        for (turns, expected) in [
+            (0.0, (zero, one)),
+            (0.25, (one, zero)),
+            (0.5, (zero, -one)),
+            (0.75, (-one, zero)),
+            (1.0, (zero, one)),
+            (-0.25, (-one, zero)),
+            (1000.5, (zero, -one)),
+        ] {
+            assert_eq!(
+                sin_cos_turns(I16F16::from_num(turns)),
+                expected,
+                "turns = {turns}"
+            );
+        }
+    }
+
+    #[test]
+    fn sin_cos_turns_matches_radians() {
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-8
+        };
+        for i in -200..=200 {
+            let turns = I32F32::from_num(i) / 37;
+            let radians = turns.to_num::<f64>() * core::f64::consts::TAU;
+            let (s, c) = sin_cos_turns(turns);
+            assert!(
+                (s.to_num::<f64>() - radians.sin()).abs() < tolerance,
+                "turns = {turns}"
+            );
+            assert!(
+                (c.to_num::<f64>() - radians.cos()).abs() < tolerance,
+                "turns = {turns}"
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_angle_in_range() {
+        let reduced = normalize_angle(I16F16::from_num(7.0));
+        assert!(reduced >= -I16F16::PI && reduced <= I16F16::PI);
+
+        let expected = 7.0 - core::f32::consts::TAU;
+        assert!(
+            approx_eq(reduced, expected),
+            "normalize_angle(7) = {}, expected {expected}",
+            reduced.to_num::<f32>()
+        );
+    }
+
+    #[test]
+    fn normalize_angle_identity_within_range() {
+        for i in -30..=30 {
+            let angle = I16F16::from_num(i) * I16F16::from_num(0.1);
+            assert_eq!(normalize_angle(angle), angle);
+        }
+    }
+
+    #[test]
+    fn normalize_angle_large_and_negative() {
+        for &angle in &[-7.0_f32, 100.0, -100.0, 1000.0] {
+            let reduced = normalize_angle(I16F16::from_num(angle));
+            assert!(reduced >= -I16F16::PI && reduced <= I16F16::PI);
+            // Same point on the circle as the input
+            let (s, c) = sin_cos(reduced);
+            assert!((s.to_num::<f32>() - angle.sin()).abs() < 0.01);
+            assert!((c.to_num::<f32>() - angle.cos()).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn normalize_angle_at_type_extremes() {
+        for angle in [I16F16::MAX, I16F16::MIN, -I16F16::PI] {
+            let reduced = normalize_angle(angle);
+            assert!(reduced >= -I16F16::PI && reduced <= I16F16::PI, "{angle}");
+        }
+        let wide = normalize_angle(I32F32::MIN);
+        assert!(wide >= -I32F32::PI && wide <= I32F32::PI);
+    }
+
+    #[test]
+    fn reduce_periodic_range_and_count() {
+        for period in [
+            I16F16::ONE,
+            I16F16::from_num(0.75),
+            I16F16::from_num(2.5),
+            I16F16::PI,
+            I16F16::from_num(10),
+        ] {
+            let half = period / 2;
+            for i in -400..=400 {
+                let x = I16F16::from_num(i) * I16F16::from_num(0.37);
+                let (reduced, k) = reduce_periodic(x, period);
+                assert!(
+                    reduced >= -half && reduced <= half,
+                    "reduce_periodic({x}, {period}) = {reduced}"
+                );
+                assert_eq!(
+                    I16F16::from_num(k) * period + reduced,
+                    x,
+                    "reduce_periodic({x}, {period}) removed {k} periods"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_periodic_counts() {
+        let period = I16F16::from_num(2.5);
+        assert_eq!(
+            reduce_periodic(I16F16::from_num(1.25), period),
+            (I16F16::from_num(1.25), 0)
+        );
+        assert_eq!(
+            reduce_periodic(I16F16::from_num(7), period),
+            (I16F16::from_num(-0.5), 3)
+        );
+        assert_eq!(
+            reduce_periodic(I16F16::from_num(-7), period),
+            (I16F16::from_num(0.5), -3)
+        );
+        // The sign of the period is ignored.
+        assert_eq!(
+            reduce_periodic(I16F16::from_num(7), -period),
+            (I16F16::from_num(-0.5), 3)
+        );
+        assert_eq!(
+            reduce_periodic(I16F16::from_num(7), I16F16::ZERO),
+            (I16F16::from_num(7), 0)
+        );
+    }
+
+    #[test]
+    fn reduce_periodic_at_type_extremes() {
+        // Neither the count nor k·period fits in I16F16 here.
+        assert_eq!(
+            reduce_periodic(I16F16::MAX, I16F16::ONE),
+            (-I16F16::DELTA, 32768)
+        );
+        assert_eq!(
+            reduce_periodic(I16F16::MIN, I16F16::ONE),
+            (I16F16::ZERO, -32768)
+        );
+        let (reduced, k) = reduce_periodic(I64F64::MAX, I64F64::ONE);
+        assert_eq!((reduced, k), (-I64F64::DELTA, i32::MAX));
+    }
+
+    #[test]
+    fn normalize_angle_matches_reduce_periodic() {
+        let two_pi = I16F16::PI * 2;
+        for i in -200..=200 {
+            let angle = I16F16::from_num(i) * I16F16::from_num(1.7);
+            assert_eq!(normalize_angle(angle), reduce_periodic(angle, two_pi).0);
+        }
+    }
+
+    #[test]
+    fn try_atan2_rejects_origin() {
+        let err = try_atan2(I16F16::ZERO, I16F16::ZERO).unwrap_err();
+        assert_eq!(
+            err,
+            fixed_analytics::Error::domain("atan2", "non-zero (x, y)")
+        );
+    }
+
+    #[test]
+    fn try_atan2_matches_atan2_off_origin() {
+        let values = [-2.0_f32, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0];
+        for &y in &values {
+            for &x in &values {
+                if x == 0.0 && y == 0.0 {
+                    continue;
+                }
+                let (y, x) = (I16F16::from_num(y), I16F16::from_num(x));
+                assert_eq!(try_atan2(y, x).unwrap(), atan2(y, x));
+            }
+        }
+    }
+
+    fn max_sin_error_i32f32() -> f64 {
+        (-4000..=4000)
+            .map(|i| {
+                let x = f64::from(i) / 1000.0;
+                let computed: f64 = sin(I32F32::from_num(x)).to_num();
+                (computed - x.sin()).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    #[cfg(not(feature = "fast-math"))]
+    fn sin_default_path_tight_tolerance() {
+        let max_err = max_sin_error_i32f32();
+        assert!(max_err < 1e-8, "max sin error {max_err}");
+    }
+
+    #[test]
+    #[cfg(feature = "fast-math")]
+    fn sin_fast_math_loose_tolerance() {
+        let max_err = max_sin_error_i32f32();
+        assert!(max_err < 1e-4, "max sin error {max_err}");
+    }
+
     mod saturation {
         use super::*;
         use core::f64::consts::FRAC_PI_2;
@@ -502,7 +1371,8 @@ mod tests {
             let pole_3pi2 = 3.0 * PI / 2.0;
 
             let far_from_pole = I16F16::from_num(pole_3pi2 - 0.0001);
-            let near_pole = I16F16::from_num(pole_3pi2 - 0.00005);
+            // tan(3π/2 - 0.00005) ≈ 20977 still fits, so step closer.
+            let near_pole = I16F16::from_num(pole_3pi2 - 0.00002);
 
             // Near 3π/2, tan approaches +∞ from below
             assert!(
@@ -511,7 +1381,7 @@ mod tests {
             );
             assert!(
                 is_max_16(tan(near_pole)),
-                "tan(3π/2 - 0.00005) should saturate to MAX"
+                "tan(3π/2 - 0.00002) should saturate to MAX"
             );
         }
     }
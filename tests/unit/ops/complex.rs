@@ -0,0 +1,167 @@
+//! Tests for complex arithmetic helpers
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::{ccos, cexp, cexp_scaled, complex_mul, cos, csin, sin, sin_cos, twiddle};
+
+    /// The fast sin/cos polynomials are only accurate to ~1e-4.
+    const TOLERANCE: f64 = if cfg!(feature = "fast-math") {
+        1e-4
+    } else {
+        1e-8
+    };
+
+    fn approx_eq(a: I32F32, b: f64) -> bool {
+        (a.to_num::<f64>() - b).abs() < TOLERANCE
+    }
+
+    #[test]
+    fn complex_mul_hand_computed() {
+        // (1 + 2i)(3 + 4i) = 3 + 4i + 6i - 8 = -5 + 10i
+        assert_eq!(
+            complex_mul(
+                I16F16::from_num(1),
+                I16F16::from_num(2),
+                I16F16::from_num(3),
+                I16F16::from_num(4),
+            ),
+            (I16F16::from_num(-5), I16F16::from_num(10))
+        );
+
+        // i · i = -1
+        assert_eq!(
+            complex_mul(I16F16::ZERO, I16F16::ONE, I16F16::ZERO, I16F16::ONE),
+            (-I16F16::ONE, I16F16::ZERO)
+        );
+
+        // (0.5 - 0.25i)(2 + 0i) = 1 - 0.5i
+        assert_eq!(
+            complex_mul(
+                I16F16::from_num(0.5),
+                I16F16::from_num(-0.25),
+                I16F16::from_num(2),
+                I16F16::ZERO,
+            ),
+            (I16F16::ONE, I16F16::from_num(-0.5))
+        );
+    }
+
+    #[test]
+    fn complex_mul_saturates() {
+        let big = I16F16::from_num(1000);
+        // re = big² + big² overflows; each product saturates first.
+        let (re, _) = complex_mul(big, big, big, -big);
+        assert_eq!(re, I16F16::MAX);
+    }
+
+    #[test]
+    fn cexp_zero_is_one() {
+        assert_eq!(cexp(I16F16::ZERO), (I16F16::ONE, I16F16::ZERO));
+        assert_eq!(cexp(I32F32::ZERO), (I32F32::ONE, I32F32::ZERO));
+    }
+
+    #[test]
+    fn cexp_matches_sin_cos() {
+        for i in -20..=20 {
+            let theta = I32F32::from_num(i) / 6;
+            let (sin, cos) = sin_cos(theta);
+            assert_eq!(cexp(theta), (cos, sin), "cexp({theta})");
+        }
+    }
+
+    #[test]
+    fn cexp_scaled_preserves_magnitude() {
+        for r in [0.5, 1.0, 3.0, 100.0] {
+            for i in -12..=12 {
+                let theta = I32F32::from_num(i) / 4;
+                let (re, im) = cexp_scaled(I32F32::from_num(r), theta);
+                let (re, im): (f64, f64) = (re.to_num(), im.to_num());
+                let magnitude = re.hypot(im);
+                assert!(
+                    (magnitude - r).abs() < TOLERANCE * r.max(1.0),
+                    "|cexp_scaled({r}, {theta})| = {magnitude}"
+                );
+            }
+        }
+    }
+
+    /// The products scale the sin/cos error by up to cosh(1) ≈ 1.54, and
+    /// `sinh_cosh` adds error of its own.
+    fn approx_eq_loose(a: I32F32, b: f64) -> bool {
+        (a.to_num::<f64>() - b).abs() < 2.0 * TOLERANCE.max(1e-6)
+    }
+
+    #[test]
+    fn csin_ccos_hand_computed() {
+        // sin(1 + i) = sin 1·cosh 1 + i·cos 1·sinh 1
+        let (sin_re, sin_im) = csin(I32F32::ONE, I32F32::ONE);
+        assert!(approx_eq_loose(sin_re, 1.298_457_581_415_977_3), "{sin_re}");
+        assert!(approx_eq_loose(sin_im, 0.634_963_914_784_736_1), "{sin_im}");
+
+        // cos(1 + i) = cos 1·cosh 1 - i·sin 1·sinh 1
+        let (cos_re, cos_im) = ccos(I32F32::ONE, I32F32::ONE);
+        assert!(approx_eq_loose(cos_re, 0.833_730_025_131_149_1), "{cos_re}");
+        assert!(
+            approx_eq_loose(cos_im, -0.988_897_705_762_865_1),
+            "{cos_im}"
+        );
+
+        // sin(i·y) = i·sinh(y) and cos(i·y) = cosh(y)
+        let half = I32F32::from_num(0.5);
+        let (zero, sinh) = csin(I32F32::ZERO, half);
+        assert_eq!(zero, I32F32::ZERO);
+        assert!(approx_eq_loose(sinh, 0.5_f64.sinh()), "{sinh}");
+        let (cosh, neg_zero) = ccos(I32F32::ZERO, half);
+        assert!(approx_eq_loose(cosh, 0.5_f64.cosh()), "{cosh}");
+        assert_eq!(neg_zero, I32F32::ZERO);
+    }
+
+    #[test]
+    fn csin_ccos_on_real_axis_match_sin_cos() {
+        for i in -20..=20 {
+            let x = I32F32::from_num(i) / 6;
+            assert_eq!(csin(x, I32F32::ZERO), (sin(x), I32F32::ZERO), "csin({x})");
+            assert_eq!(ccos(x, I32F32::ZERO), (cos(x), I32F32::ZERO), "ccos({x})");
+
+            let x16 = I16F16::from_num(i) / 6;
+            assert_eq!(csin(x16, I16F16::ZERO), (sin(x16), I16F16::ZERO));
+        }
+    }
+
+    #[test]
+    fn csin_saturates_for_large_imaginary_part() {
+        let (re, im) = csin(I16F16::FRAC_PI_2, I16F16::from_num(20));
+        assert_eq!(re, I16F16::MAX);
+        assert!(im.abs() < re);
+    }
+
+    #[test]
+    fn twiddle_zero_is_one() {
+        for n in [1, 2, 8, 1024] {
+            assert_eq!(twiddle::<I16F16>(0, n), (I16F16::ONE, I16F16::ZERO));
+        }
+        assert_eq!(twiddle::<I16F16>(5, 0), (I16F16::ONE, I16F16::ZERO));
+    }
+
+    #[test]
+    fn twiddle_matches_reference() {
+        for n in [4_usize, 8, 12, 1024] {
+            for k in 0..n.min(64) {
+                let (re, im) = twiddle::<I32F32>(k, n);
+                #[allow(clippy::cast_precision_loss, reason = "small test values")]
+                let angle = -2.0 * core::f64::consts::PI * k as f64 / n as f64;
+                assert!(approx_eq(re, angle.cos()), "twiddle({k}, {n}).re = {re}");
+                assert!(approx_eq(im, angle.sin()), "twiddle({k}, {n}).im = {im}");
+            }
+        }
+    }
+
+    #[test]
+    fn twiddle_wraps_k_modulo_n() {
+        assert_eq!(twiddle::<I32F32>(9, 8), twiddle::<I32F32>(1, 8));
+        // k = n/4 is a quarter turn clockwise: -i.
+        let (re, im) = twiddle::<I32F32>(2, 8);
+        assert!(approx_eq(re, 0.0) && approx_eq(im, -1.0));
+    }
+}
@@ -3,8 +3,12 @@
 #[cfg(test)]
 #[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
 mod tests {
-    use fixed::types::I16F16;
-    use fixed_analytics::{exp, ln, log2, log10, pow2};
+    use fixed::traits::Fixed;
+    use fixed::types::{I3F13, I3F29, I8F8, I8F24, I16F16, I24F8, I32F32, I64F64};
+    use fixed_analytics::{
+        CordicNumber, PowBase, exp, exp_max_arg, exp_monotone_slice, ln, log2, log10, logaddexp,
+        pow, pow2, pow2_clamped, powi, try_exp,
+    };
 
     const TOLERANCE: f32 = 0.15;
 
@@ -51,6 +55,33 @@ mod tests {
         assert!(approx_eq(log2(I16F16::from_num(8.0)).unwrap(), 3.0, 0.4));
     }
 
+    #[test]
+    fn log2_exact_for_powers_of_two() {
+        for (x, expected) in [(1.0, 0), (2.0, 1), (256.0, 8), (0.5, -1), (0.25, -2)] {
+            assert_eq!(
+                log2(I16F16::from_num(x)).unwrap(),
+                I16F16::from_num(expected),
+                "log2({x})"
+            );
+        }
+        assert_eq!(log2(I16F16::DELTA).unwrap(), I16F16::from_num(-16));
+        assert_eq!(
+            log2(I32F32::from_num(1 << 30)).unwrap(),
+            I32F32::from_num(30)
+        );
+
+        // Not a power of two: the general path is used.
+        let near = log2(I16F16::from_num(256) + I16F16::DELTA).unwrap();
+        assert!(approx_eq(near, 8.0, 0.001));
+    }
+
+    #[test]
+    fn log2_exponent_out_of_range_falls_back() {
+        // -29 does not fit in I3F29, so the general (saturating) path runs.
+        let result = log2(I3F29::DELTA).unwrap();
+        assert_eq!(result, I3F29::MIN);
+    }
+
     #[test]
     fn log10_powers_of_ten() {
         assert!(approx_eq(
@@ -81,6 +112,34 @@ mod tests {
         }
     }
 
+    // exp and ln both form k·ln(2) from the same two-word constant, so the
+    // round trip only carries ln's own error: a few tens of ε relative.
+    #[test]
+    fn exp_ln_round_trip_within_type_precision() {
+        let bound16 = 48.0 * I16F16::DELTA.to_num::<f64>();
+        let bound32 = 48.0 * I32F32::DELTA.to_num::<f64>();
+        for i in 0..=2000 {
+            // Log-spaced over [0.1, 100].
+            let x = 0.1 * 1000_f64.powf(f64::from(i) / 2000.0);
+
+            let x16 = I16F16::from_num(x);
+            let ratio16 = exp(ln(x16).unwrap()).to_num::<f64>() / x16.to_num::<f64>();
+            assert!(
+                (ratio16 - 1.0).abs() < bound16,
+                "I16F16 exp(ln({x16}))/x - 1 = {:e}",
+                ratio16 - 1.0
+            );
+
+            let x32 = I32F32::from_num(x);
+            let ratio32 = exp(ln(x32).unwrap()).to_num::<f64>() / x32.to_num::<f64>();
+            assert!(
+                (ratio32 - 1.0).abs() < bound32,
+                "I32F32 exp(ln({x32}))/x - 1 = {:e}",
+                ratio32 - 1.0
+            );
+        }
+    }
+
     #[test]
     fn pow2_log2_inverse() {
         // pow2(log2(x)) ≈ x for positive x
@@ -96,6 +155,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pow2_clamped_never_exceeds_ceiling() {
+        let ceiling = I16F16::from_num(100);
+        for i in -80..=200 {
+            let x = I16F16::from_num(i) / 8;
+            let result = pow2_clamped(x, ceiling);
+            assert!(result <= ceiling, "pow2_clamped({x}) = {result}");
+            if result < ceiling {
+                assert_eq!(result, pow2(x));
+            }
+        }
+        assert_eq!(pow2_clamped(I16F16::MAX, ceiling), ceiling);
+    }
+
+    #[test]
+    fn pow2_clamped_is_continuous_at_ceiling() {
+        let ceiling = I16F16::from_num(100);
+        // log2(100) ≈ 6.6439; step across it in increments of 2^-12.
+        let step = I16F16::from_num(1.0 / 4096.0);
+        let mut x = I16F16::from_num(6.6);
+        let mut prev = pow2_clamped(x, ceiling);
+        while x < I16F16::from_num(6.7) {
+            x += step;
+            let next = pow2_clamped(x, ceiling);
+            assert!(next >= prev, "not monotonic at {x}");
+            assert!(next - prev < I16F16::from_num(0.05), "jump at {x}");
+            prev = next;
+        }
+        assert_eq!(prev, ceiling);
+    }
+
     #[test]
     fn ln_exp_inverse() {
         // ln(exp(x)) ≈ x for small x (where exp doesn't overflow)
@@ -141,6 +231,39 @@ mod tests {
         assert!(val.abs() < 0.01, "ln(1) = {val}, expected 0");
     }
 
+    fn check_exp_max_arg<T: Fixed + CordicNumber>() {
+        let limit = exp_max_arg::<T>();
+        let above = limit + T::DELTA;
+        assert!(try_exp(limit).is_ok());
+        assert!(try_exp(above).is_err());
+        assert_eq!(exp(above), T::MAX);
+
+        // Just below saturation, and close to ln(MAX).
+        assert!(exp(limit) < T::MAX);
+        let max: f64 = T::MAX.to_num();
+        let at_limit: f64 = exp(limit).to_num();
+        assert!(at_limit > 0.99 * max, "{at_limit}");
+        let expected = max.ln();
+        assert!((limit.to_num::<f64>() - expected).abs() < 1e-3 * expected);
+    }
+
+    #[test]
+    fn exp_max_arg_is_the_saturation_threshold() {
+        check_exp_max_arg::<I8F24>();
+        check_exp_max_arg::<I16F16>();
+        check_exp_max_arg::<I32F32>();
+        check_exp_max_arg::<I64F64>();
+    }
+
+    #[test]
+    fn exp_saturates_far_beyond_i32_range() {
+        // x / ln(2) does not fit in an i32 here; it must still overflow.
+        let x = I64F64::from_num(1e12);
+        assert!(try_exp(x).is_err());
+        assert_eq!(exp(x), I64F64::MAX);
+        assert_eq!(exp(-x), I64F64::ZERO);
+    }
+
     #[test]
     fn exp_large_negative() {
         // exp of large negative values should approach 0
@@ -183,7 +306,7 @@ mod tests {
 
     #[test]
     fn exp_large_positive() {
-        // exp of large positive values should exercise the argument reduction loop
+        // exp of large positive values should exercise the argument reduction
         let large = I16F16::from_num(5.0);
         let result: f32 = exp(large).to_num();
         // exp(5) ≈ 148.41
@@ -192,13 +315,88 @@ mod tests {
             "exp(5) = {result}, expected ~148"
         );
 
-        // Test even larger value to ensure multiple reduction iterations
+        // Test even larger value to ensure a larger power-of-two scale
         let larger = I16F16::from_num(8.0);
         let result2: f32 = exp(larger).to_num();
         // exp(8) ≈ 2981
         assert!(result2 > 2000.0, "exp(8) = {result2}, expected > 2000");
     }
 
+    #[test]
+    fn exp_10_matches_f64() {
+        // k = 14 here; forming k*ln2 without rounding ln2 to the type first
+        // keeps the relative error near the precision of the reduced argument.
+        let result: f64 = exp(I32F32::from_num(10)).to_num();
+        let expected = 10.0_f64.exp();
+        let rel = ((result - expected) / expected).abs();
+        assert!(
+            rel < 2e-10,
+            "exp(10) = {result}, expected {expected} (rel {rel:e})"
+        );
+    }
+
+    #[test]
+    fn exp_minus_15_matches_f64() {
+        let result: f64 = exp(I32F32::from_num(-15)).to_num();
+        let expected = (-15.0_f64).exp();
+        let ulps = (result - expected).abs() * 4_294_967_296.0;
+        assert!(
+            ulps <= 1.0,
+            "exp(-15) = {result}, expected {expected} ({ulps} ULP)"
+        );
+    }
+
+    #[test]
+    fn exp_monotone_slice_strictly_increasing() {
+        // 10000 sorted inputs in [-5, 5): neighbours differ by 1e-3, far more
+        // than an I32F32 ULP relative to e^x, so equal outputs would be a bug.
+        let inputs: Vec<I32F32> = (0..10_000)
+            .map(|i| I32F32::from_num(i - 5000) / 1000)
+            .collect();
+        let mut out = vec![I32F32::ZERO; inputs.len()];
+        exp_monotone_slice(&inputs, &mut out);
+        for ((x, prev), next) in inputs.iter().skip(1).zip(&out).zip(out.iter().skip(1)) {
+            assert!(prev < next, "exp({x}) = {next} not above previous {prev}");
+        }
+        assert_eq!(out.get(5000), Some(&I32F32::ONE));
+    }
+
+    #[test]
+    fn exp_monotone_slice_non_decreasing_through_saturation() {
+        // Sweeps both saturation regions, where plain ties are expected.
+        let inputs: Vec<I16F16> = (0..10_000)
+            .map(|i| I16F16::from_num(i - 5000) / 400)
+            .collect();
+        let mut out = vec![I16F16::ZERO; inputs.len()];
+        exp_monotone_slice(&inputs, &mut out);
+        assert!(out.iter().zip(out.iter().skip(1)).all(|(a, b)| a <= b));
+        assert_eq!(out.first(), Some(&I16F16::ZERO));
+        assert_eq!(out.last(), Some(&I16F16::MAX));
+        for (&x, &y) in inputs.iter().zip(&out) {
+            assert_eq!(y, exp(x), "clamp changed exp({x})");
+        }
+    }
+
+    #[test]
+    fn exp_monotone_slice_stops_at_shorter_slice() {
+        let inputs = [I16F16::ZERO, I16F16::ONE, I16F16::from_num(2)];
+        let mut out = [I16F16::from_num(-1); 2];
+        exp_monotone_slice(&inputs, &mut out);
+        assert_eq!(out, [I16F16::ONE, exp(I16F16::ONE)]);
+
+        let mut longer = [I16F16::from_num(-1); 4];
+        exp_monotone_slice(&[I16F16::ZERO], &mut longer);
+        assert_eq!(
+            longer,
+            [
+                I16F16::ONE,
+                I16F16::from_num(-1),
+                I16F16::from_num(-1),
+                I16F16::from_num(-1)
+            ]
+        );
+    }
+
     #[test]
     fn ln_large_values() {
         // ln of large values should exercise the argument reduction loop
@@ -232,6 +430,53 @@ mod tests {
         assert!(val < -8.0, "ln(0.0001) = {val}, expected < -8.0");
     }
 
+    #[test]
+    fn ln_far_from_one_adds_k_ln2_once() {
+        // The exponent contributes k * ln(2) rounded once, so the error at large
+        // |k| is the mantissa's alone rather than growing with every halving.
+        let ulp = 2f64.powi(-32);
+        for x in [1000.0, 30000.0] {
+            let fx = I32F32::from_num(x);
+            let err = (ln(fx).unwrap().to_num::<f64>() - fx.to_num::<f64>().ln()) / ulp;
+            assert!(err.abs() <= 6.0, "ln({x}) off by {err} ULP");
+        }
+
+        // Wide types must not trip the range check on k * ln(2).
+        let wide = ln(I64F64::from_num(1000)).unwrap().to_num::<f64>();
+        assert!(
+            (wide - 1000f64.ln()).abs() < 1e-6,
+            "I64F64 ln(1000) = {wide}"
+        );
+    }
+
+    #[test]
+    fn ln_k_ln2_term_uses_two_word_ln2() {
+        // 2^61 and 4 both reduce to a mantissa of exactly 2, so the difference
+        // of their logarithms is the k * ln(2) term alone: 59 * ln(2). With a
+        // single-word ln(2) that term is 12 ULP high in I64F64.
+        let big = ln(I64F64::from_bits(1 << 125)).unwrap();
+        let small = ln(I64F64::from_num(4)).unwrap();
+        let expected = I64F64::from_bits(754_392_210_066_956_683_928);
+        let diff = (big - small - expected).to_bits();
+        assert!(diff.abs() <= 2, "59 * ln(2) off by {diff} ULP");
+    }
+
+    #[test]
+    fn ln_near_epsilon_matches_f64() {
+        let x16 = I16F16::from_num(0.0001);
+        let err16 = (ln(x16).unwrap().to_num::<f64>() - x16.to_num::<f64>().ln()) * 65536.0;
+        assert!(err16.abs() <= 10.0, "I16F16 ln(0.0001) off by {err16} ULP");
+
+        let x32 = I32F32::from_num(0.0001);
+        let err32 = (ln(x32).unwrap().to_num::<f64>() - x32.to_num::<f64>().ln()) * 2f64.powi(32);
+        assert!(err32.abs() <= 20.0, "I32F32 ln(0.0001) off by {err32} ULP");
+
+        // The smallest positive value takes the full 16 doublings.
+        let min = I16F16::DELTA;
+        let err = (ln(min).unwrap().to_num::<f64>() - min.to_num::<f64>().ln()) * 65536.0;
+        assert!(err.abs() <= 10.0, "ln(DELTA) off by {err} ULP");
+    }
+
     #[test]
     fn exp_overflow_to_max() {
         // exp of very large positive values should return max when scale > max_shift
@@ -260,6 +505,175 @@ mod tests {
         assert!(result == 0.0, "exp(-25) = {result}, expected 0");
     }
 
+    #[test]
+    fn try_exp_matches_exp_in_range() {
+        for i in -120..=100 {
+            let x = I16F16::from_num(i) / 10;
+            assert_eq!(try_exp(x).unwrap(), exp(x), "x = {x}");
+        }
+    }
+
+    #[test]
+    fn try_exp_reports_overflow() {
+        // Both the early exit (scale > max_shift) and the headroom check.
+        assert!(try_exp(I16F16::from_num(25)).is_err());
+        assert!(try_exp(I16F16::from_num(10.5)).is_err());
+        assert!(try_exp(I16F16::MAX).is_err());
+        // Underflow to zero is not an error.
+        assert_eq!(try_exp(I16F16::from_num(-25)).unwrap(), I16F16::ZERO);
+    }
+
+    #[test]
+    fn powi_exact_for_representable_results() {
+        assert_eq!(powi(I16F16::from_num(2), 10), I16F16::from_num(1024));
+        assert_eq!(powi(I16F16::from_num(-3), 3), I16F16::from_num(-27));
+        assert_eq!(powi(I16F16::from_num(1.5), 2), I16F16::from_num(2.25));
+        assert_eq!(powi(I16F16::from_num(7), 0), I16F16::ONE);
+        assert_eq!(powi(I16F16::ZERO, 0), I16F16::ONE);
+    }
+
+    #[test]
+    fn powi_negative_exponent() {
+        assert_eq!(powi(I16F16::from_num(2), -3), I16F16::from_num(0.125));
+        assert!(approx_eq(powi(I16F16::from_num(3), -2), 1.0 / 9.0, 1e-4));
+        assert_eq!(powi(I16F16::ZERO, -1), I16F16::MAX);
+    }
+
+    #[test]
+    fn powi_saturates() {
+        assert_eq!(powi(I16F16::from_num(10), 10), I16F16::MAX);
+        assert_eq!(powi(I16F16::from_num(-10), 9), I16F16::MIN);
+        assert_eq!(powi(I16F16::from_num(2), i32::MAX), I16F16::MAX);
+    }
+
+    #[test]
+    fn pow_integer_exponent_is_exact() {
+        let two = I16F16::from_num(2);
+        assert_eq!(
+            pow(two, I16F16::from_num(10)).unwrap(),
+            I16F16::from_num(1024)
+        );
+        assert_eq!(
+            pow(two, I16F16::from_num(-3)).unwrap(),
+            I16F16::from_num(0.125)
+        );
+        assert_eq!(
+            pow(I16F16::from_num(-3), I16F16::from_num(3)).unwrap(),
+            I16F16::from_num(-27)
+        );
+        assert_eq!(pow(I16F16::ZERO, I16F16::ZERO).unwrap(), I16F16::ONE);
+        let wide = pow(I32F32::from_num(1.5), I32F32::from_num(7)).unwrap();
+        assert_eq!(wide, powi(I32F32::from_num(1.5), 7));
+    }
+
+    #[test]
+    fn pow_fractional_exponent_matches_f64() {
+        // 2^10.5 goes through exp/ln for the 2^0.5 factor only.
+        let half_step = pow(I32F32::from_num(2), I32F32::from_num(10.5)).unwrap();
+        let expected = 10.5_f64.exp2();
+        let rel = (half_step.to_num::<f64>() - expected).abs() / expected;
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-8
+        };
+        assert!(
+            rel < tolerance,
+            "pow(2, 10.5) = {half_step}, rel error {rel}"
+        );
+
+        for &(base, exponent) in &[(3.0_f64, 2.25_f64), (0.5, 3.7), (0.3, -2.4), (7.5, -0.5)] {
+            let result: f64 = pow(I32F32::from_num(base), I32F32::from_num(exponent))
+                .unwrap()
+                .to_num();
+            let reference = base.powf(exponent);
+            let rel_err = (result - reference).abs() / reference;
+            assert!(
+                rel_err < tolerance,
+                "pow({base}, {exponent}) = {result}, expected {reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn pow_is_continuous_across_integers() {
+        let base = I32F32::from_num(2);
+        let exact = I32F32::from_num(1024);
+        for offset in [I32F32::DELTA, -I32F32::DELTA, I32F32::from_num(1e-6)] {
+            let near = pow(base, I32F32::from_num(10) + offset).unwrap();
+            assert!(
+                (near - exact).abs() < I32F32::from_num(0.002),
+                "pow(2, 10 + {offset}) = {near}"
+            );
+        }
+    }
+
+    #[test]
+    fn pow_domain() {
+        let half = I16F16::from_num(0.5);
+        assert!(pow(I16F16::from_num(-2), half).is_err());
+        assert!(pow(I16F16::ZERO, -half).is_err());
+        assert_eq!(pow(I16F16::ZERO, half).unwrap(), I16F16::ZERO);
+        assert_eq!(
+            pow(I16F16::from_num(10), I16F16::from_num(5.5)).unwrap(),
+            I16F16::MAX
+        );
+    }
+
+    #[test]
+    fn pow_base_matches_pow() {
+        let two = PowBase::new(I16F16::from_num(2)).unwrap();
+        assert_eq!(two.pow(I16F16::from_num(10)), I16F16::from_num(1024));
+        assert_eq!(
+            two.pow(I16F16::from_num(10)),
+            pow(I16F16::from_num(2), I16F16::from_num(10)).unwrap()
+        );
+
+        for base in [0.3, 1.05, 2.0, 7.5] {
+            let b = I32F32::from_num(base);
+            let prepared = PowBase::new(b).unwrap();
+            for e in [-3.25, -0.5, 0.0, 0.75, 2.0, 4.4] {
+                let e = I32F32::from_num(e);
+                assert_eq!(prepared.pow(e), pow(b, e).unwrap(), "{base}^{e}");
+            }
+        }
+    }
+
+    #[test]
+    fn pow_base_rejects_non_positive_base() {
+        assert!(PowBase::new(I16F16::ZERO).is_err());
+        assert!(PowBase::new(I16F16::from_num(-2)).is_err());
+    }
+
+    #[test]
+    fn logaddexp_equal_zero_is_ln2() {
+        let result = logaddexp(I16F16::ZERO, I16F16::ZERO);
+        assert!(approx_eq(result, core::f32::consts::LN_2, 1e-3));
+    }
+
+    #[test]
+    fn logaddexp_matches_reference() {
+        for (a, b) in [(1.0_f32, 2.0_f32), (-3.0, 0.5), (5.0, -5.0), (-8.0, -8.5)] {
+            let result = logaddexp(I16F16::from_num(a), I16F16::from_num(b));
+            let expected = (a.exp() + b.exp()).ln();
+            assert!(
+                approx_eq(result, expected, 1e-3),
+                "logaddexp({a}, {b}) = {result}, expected {expected}"
+            );
+            assert_eq!(result, logaddexp(I16F16::from_num(b), I16F16::from_num(a)));
+        }
+    }
+
+    #[test]
+    fn logaddexp_avoids_overflow() {
+        // e^10 + e^10 exceeds I16F16::MAX, so the naive form saturates.
+        let ten = I16F16::from_num(10);
+        let naive = ln(exp(ten).saturating_add(exp(ten))).unwrap();
+        let expected = 10.0 + core::f32::consts::LN_2;
+        assert!(!approx_eq(naive, expected, 0.1));
+        assert!(approx_eq(logaddexp(ten, ten), expected, 1e-3));
+    }
+
     mod saturation {
         use super::*;
         use fixed::types::I32F32;
@@ -404,4 +818,45 @@ mod tests {
             );
         }
     }
+
+    /// Largest `|ln(x) - ln_exact(x)|` in ULPs over every positive `x` with
+    /// `T` bits in `bits`, stepping by `stride`.
+    fn ln_worst_ulps<T: Fixed + CordicNumber>(bits: core::ops::Range<i32>, stride: usize) -> f64
+    where
+        T::Bits: TryFrom<i32>,
+    {
+        let ulp = T::DELTA.to_num::<f64>();
+        bits.step_by(stride)
+            .filter_map(|raw| T::Bits::try_from(raw).ok())
+            .map(|raw| {
+                let x = T::from_bits(raw);
+                let got: f64 = ln(x).unwrap().to_num();
+                (got - x.to_num::<f64>().ln()).abs() / ulp
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn ln_narrow_types_are_correctly_rounded() {
+        let million = I24F8::from_num(1_000_000);
+        let got: f64 = ln(million).unwrap().to_num();
+        assert!(
+            (got - 1e6_f64.ln()).abs() <= 0.5 / 256.0,
+            "ln({million}) = {got}"
+        );
+
+        // Large I24F8 inputs, and every positive I8F8 input.
+        let wide = ln_worst_ulps::<I24F8>(1 << 16..i32::MAX, 4099);
+        assert!(wide <= 0.5 + 1e-9, "I24F8 worst {wide} ULPs");
+        let every = ln_worst_ulps::<I8F8>(1..0x8000, 1);
+        assert!(every <= 0.5 + 1e-9, "I8F8 worst {every} ULPs");
+    }
+
+    #[test]
+    fn ln_narrow_saturates_outside_the_type() {
+        // ln(2^-13) ≈ -9.01 is below I3F13::MIN.
+        assert_eq!(ln(I3F13::DELTA).unwrap(), I3F13::MIN);
+        let near_max: f64 = ln(I3F13::MAX).unwrap().to_num();
+        assert!((near_max - I3F13::MAX.to_num::<f64>().ln()).abs() <= 0.5 / 8192.0);
+    }
 }
@@ -3,8 +3,14 @@
 #[cfg(test)]
 #[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
 mod tests {
-    use fixed::types::{I16F16, I32F32};
-    use fixed_analytics::{acosh, acoth, asinh, atanh, cosh, coth, sinh, sinh_cosh, tanh};
+    use fixed::traits::Fixed;
+    use fixed::types::extra::{U13, U29};
+    use fixed::types::{I8F8, I8F24, I16F16, I32F32, I64F64};
+    use fixed::{FixedI16, FixedI32};
+    use fixed_analytics::{
+        CordicNumber, acosh, acoth, asinh, atanh, cosh, cosh_max_arg, coth, sinh, sinh_cosh,
+        sinh_cosh_tanh, sinh_max_arg, tanh, try_cosh, try_sinh_cosh,
+    };
 
     const TOLERANCE: f32 = 0.05;
 
@@ -138,6 +144,71 @@ mod tests {
         }
     }
 
+    fn check_cosh_max_arg<T: Fixed + CordicNumber>() {
+        let limit = cosh_max_arg::<T>();
+        let above = limit + T::DELTA;
+        assert!(try_cosh(limit).is_ok() && try_cosh(-limit).is_ok());
+        assert!(try_cosh(above).is_err() && try_cosh(-above).is_err());
+        assert_eq!(cosh(above), T::MAX);
+        assert_eq!((sinh(above), sinh(-above)), (T::MAX, T::MIN));
+
+        // Just below saturation, and close to acosh(MAX).
+        assert!(cosh(limit) < T::MAX);
+        let max: f64 = T::MAX.to_num();
+        let at_limit: f64 = cosh(limit).to_num();
+        assert!(at_limit > 0.95 * max, "{at_limit}");
+        let expected = max.acosh();
+        assert!((limit.to_num::<f64>() - expected).abs() < 1e-3 * expected);
+        assert_eq!(sinh_max_arg::<T>(), limit);
+    }
+
+    #[test]
+    fn cosh_max_arg_is_the_saturation_threshold() {
+        check_cosh_max_arg::<I8F24>();
+        check_cosh_max_arg::<I16F16>();
+        check_cosh_max_arg::<I32F32>();
+        check_cosh_max_arg::<I64F64>();
+    }
+
+    /// Worst atanh error in ULPs over `[lo, hi]`, against f64 at the rounded input.
+    fn atanh_worst_ulps<T: Fixed + CordicNumber>(lo: f64, hi: f64) -> f64 {
+        let ulp: f64 = T::DELTA.to_num();
+        (0..=2000)
+            .map(|i| {
+                let x = <T as Fixed>::from_num(lo + (hi - lo) * f64::from(i) / 2000.0);
+                let expected = x.to_num::<f64>().atanh();
+                (atanh(x).unwrap().to_num::<f64>() - expected).abs() / ulp
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn atanh_reduction_threshold_per_type() {
+        // I1F31 angle tables cost I32F32 a few more ULPs than these bounds.
+        if cfg!(feature = "small-tables") {
+            return;
+        }
+        // Types with ≥16 fractional bits reduce above 0.625 rather than 0.75;
+        // direct vectoring reached 15 (I16F16) and 24 (I32F32) ULPs here.
+        let i8 = atanh_worst_ulps::<I8F8>(0.625, 0.75);
+        let i16 = atanh_worst_ulps::<I16F16>(0.625, 0.75);
+        let i32 = atanh_worst_ulps::<I32F32>(0.625, 0.75);
+        assert!(i8 <= 3.0, "I8F8 worst {i8} ULP");
+        assert!(i16 <= 10.0, "I16F16 worst {i16} ULP");
+        assert!(i32 <= 19.0, "I32F32 worst {i32} ULP");
+
+        // 0.8 is reduced for every type; guard its error as a regression check.
+        let at_08 = [
+            atanh_worst_ulps::<I8F8>(0.8, 0.8),
+            atanh_worst_ulps::<I16F16>(0.8, 0.8),
+            atanh_worst_ulps::<I32F32>(0.8, 0.8),
+        ];
+        assert!(
+            at_08[0] <= 2.0 && at_08[1] <= 3.0 && at_08[2] <= 8.0,
+            "atanh(0.8) errors {at_08:?} ULP"
+        );
+    }
+
     #[test]
     fn atanh_near_boundary() {
         // atanh approaches infinity as |x| approaches 1
@@ -172,6 +243,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn asinh_large_arguments() {
+        for &t in &[2.0_f64, 50.0, 1000.0, 32767.0] {
+            for x in [t, -t] {
+                let result = asinh(I16F16::from_num(x)).to_num::<f64>();
+                assert!((result - x.asinh()).abs() < 1e-3, "asinh({x}) = {result}");
+            }
+        }
+        let wide = asinh(I32F32::from_num(50)).to_num::<f64>();
+        assert!((wide - 50.0_f64.asinh()).abs() < 2e-8, "asinh(50) = {wide}");
+        let at_min = asinh(I16F16::MIN).to_num::<f64>();
+        assert!((at_min - I16F16::MIN.to_num::<f64>().asinh()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn acosh_accurate_to_one_hundred() {
+        // Squaring x in atanh(sqrt(x² - 1) / x) drifted by over 0.1 near x = 100.
+        for i in 0..=990 {
+            let t = 1.0 + f64::from(i) / 10.0;
+            let x16 = I16F16::from_num(t);
+            let err16 = (acosh(x16).unwrap().to_num::<f64>() - x16.to_num::<f64>().acosh()).abs();
+            assert!(err16 < 1e-3, "I16F16 acosh({t}): error {err16}");
+
+            let x32 = I32F32::from_num(t);
+            let err32 = (acosh(x32).unwrap().to_num::<f64>() - x32.to_num::<f64>().acosh()).abs();
+            assert!(err32 < 2e-8, "I32F32 acosh({t}): error {err32}");
+        }
+    }
+
+    #[test]
+    fn acosh_large_arguments_do_not_overflow() {
+        // x² overflows I16F16 beyond ~181.
+        for &t in &[181.0_f64, 1000.0, 32767.0] {
+            let result = acosh(I16F16::from_num(t)).unwrap().to_num::<f64>();
+            assert!((result - t.acosh()).abs() < 1e-3, "acosh({t}) = {result}");
+        }
+        let at_max = acosh(I16F16::MAX).unwrap().to_num::<f64>();
+        assert!((at_max - I16F16::MAX.to_num::<f64>().acosh()).abs() < 1e-3);
+    }
+
     #[test]
     fn tanh_large_values() {
         // tanh should approach ±1 for large values
@@ -190,6 +301,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tanh_strictly_inside_unit_interval() {
+        for &x in &[10.0_f32, 20.0, 1000.0] {
+            let pos = tanh(I16F16::from_num(x));
+            let neg = tanh(I16F16::from_num(-x));
+            assert_eq!(pos, I16F16::ONE - I16F16::DELTA, "tanh({x})");
+            assert_eq!(neg, -(I16F16::ONE - I16F16::DELTA), "tanh(-{x})");
+        }
+        assert_eq!(tanh(I16F16::MAX), I16F16::ONE - I16F16::DELTA);
+        assert_eq!(tanh(I16F16::MIN), -(I16F16::ONE - I16F16::DELTA));
+
+        let wide = tanh(I32F32::from_num(10));
+        assert!(wide < I32F32::ONE);
+        assert!((wide.to_num::<f64>() - 10.0_f64.tanh()).abs() < 1e-8);
+    }
+
+    #[test]
+    #[cfg(feature = "tanh-table")]
+    fn tanh_table_matches_cordic_ratio() {
+        // Table interpolation error is below 2.5e-5; allow a few ULP on top.
+        // fast-math's sinh and cosh put the reference ratio itself off by up
+        // to ~2.2e-4.
+        let tolerance = if cfg!(feature = "fast-math") {
+            3e-4
+        } else {
+            2.6e-5
+        };
+        for i in -4000..=4000 {
+            let x = I32F32::from_num(i) / 1000;
+            let (sh, ch) = sinh_cosh(x);
+            let err = (tanh(x) - sh / ch).abs().to_num::<f64>();
+            assert!(err < tolerance, "tanh({x}): table differs by {err}");
+
+            let x16 = I16F16::from_num(i) / 1000;
+            let err16 = (tanh(x16).to_num::<f64>() - x16.to_num::<f64>().tanh()).abs();
+            // Plus up to ~2 ULP of truncation in the table conversion.
+            assert!(err16 < 6e-5, "I16F16 tanh({x16}): error {err16}");
+        }
+        // Outside [-4, 4] the computed path takes over.
+        assert_eq!(tanh(I16F16::from_num(10)), I16F16::ONE - I16F16::DELTA);
+    }
+
+    #[test]
+    fn tanh_is_monotonic_across_branches() {
+        // Covers the switch to the exp form at |x| = 1 and the clamp near ±1.
+        // fast-math sinh/cosh carry ~3e-4 relative error below |x| = 1.
+        let tolerance = if cfg!(feature = "fast-math") {
+            6e-4
+        } else if cfg!(feature = "tanh-table") {
+            3e-5
+        } else {
+            1e-8
+        };
+        let mut prev = tanh(I32F32::from_num(-12));
+        for i in -1199..=1200 {
+            let x = I32F32::from_num(i) / 100;
+            let y = tanh(x);
+            assert!(y >= prev, "tanh({x}) = {y} < {prev}");
+            let error = (y.to_num::<f64>() - x.to_num::<f64>().tanh()).abs();
+            assert!(error < tolerance, "tanh({x}) error {error}");
+            prev = y;
+        }
+    }
+
     #[test]
     fn sinh_cosh_large_values() {
         // Test argument reduction for large values
@@ -229,6 +404,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn coth_laurent_series_on_narrow_types() {
+        // Neither type can hold 45, the Laurent series' x³ divisor.
+        let narrow = FixedI16::<U13>::from_num(0.3);
+        for x in [narrow, -narrow] {
+            let xf: f64 = x.to_num();
+            let got: f64 = coth(x).unwrap().to_num();
+            let expected = 1.0 / xf.tanh();
+            assert!(
+                (got - expected).abs() < 1e-3,
+                "coth({xf}) = {got}, expected {expected}"
+            );
+        }
+
+        // I3F29 takes the series only below 2^-5, where 1/x does not fit.
+        let x = FixedI32::<U29>::from_num(0.01);
+        assert_eq!(coth(x).unwrap(), FixedI32::<U29>::MAX);
+    }
+
+    #[test]
+    fn coth_near_pole_uses_laurent_series() {
+        // Near zero, cosh/sinh amplifies the ULP error of sinh(x) ≈ x by 1/x²;
+        // the Laurent series keeps coth within a couple of ULPs.
+        let x = I32F32::from_num(0.01);
+        let xf: f64 = x.to_num();
+        let expected = 1.0 / xf.tanh();
+        let ulp = I32F32::DELTA.to_num::<f64>();
+
+        let series: f64 = coth(x).unwrap().to_num();
+        let (s, c) = sinh_cosh(x);
+        let ratio: f64 = CordicNumber::div(c, s).to_num();
+
+        let series_err = (series - expected).abs() / ulp;
+        let ratio_err = (ratio - expected).abs() / ulp;
+        assert!(series_err <= 2.0, "coth(0.01) off by {series_err} ULP");
+        assert!(
+            series_err < ratio_err,
+            "series ({series_err} ULP) should beat cosh/sinh ({ratio_err} ULP)"
+        );
+
+        let negated: f64 = coth(-x).unwrap().to_num();
+        assert!((negated + series).abs() <= ulp, "coth should be odd");
+    }
+
+    #[test]
+    fn coth_continuous_at_series_threshold() {
+        // I16F16 switches to cosh/sinh at |x| = 2^-2.
+        let edge = I16F16::from_num(0.25);
+        for x in [edge - I16F16::DELTA, edge, edge + I16F16::DELTA] {
+            let xf: f64 = x.to_num();
+            let got: f64 = coth(x).unwrap().to_num();
+            let expected = 1.0 / xf.tanh();
+            assert!(
+                (got - expected).abs() < 1e-4,
+                "coth({xf}) = {got}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn coth_saturates_for_tiny_inputs() {
+        assert_eq!(coth(I16F16::DELTA).unwrap(), I16F16::MAX);
+        assert_eq!(coth(-I16F16::DELTA).unwrap(), I16F16::MIN);
+    }
+
     #[test]
     fn sinh_cosh_small_values_high_precision() {
         // Test Taylor series approximation for high-precision types (≥24 frac bits)
@@ -317,6 +557,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sinh_cosh_tanh_consistent() {
+        for i in -10..=10 {
+            let x = I16F16::from_num(i) * I16F16::from_num(0.3);
+            let (sh, ch, th) = sinh_cosh_tanh(x);
+            assert_eq!((sh, ch), sinh_cosh(x));
+            if x.abs() < I16F16::ONE && !cfg!(feature = "tanh-table") {
+                assert_eq!(th, sh / ch);
+            } else {
+                assert_eq!(th, tanh(x));
+            }
+
+            let diff: f32 = (ch * ch - sh * sh).to_num();
+            assert!(
+                (diff - 1.0).abs() < 0.1,
+                "cosh²({}) - sinh²({}) = {diff}, expected ~1.0",
+                x.to_num::<f32>(),
+                x.to_num::<f32>()
+            );
+        }
+    }
+
+    #[test]
+    fn sinh_cosh_tanh_matches_tanh() {
+        for i in -10..=10 {
+            let x = I32F32::from_num(i) * I32F32::from_num(0.25);
+            let (_, _, th) = sinh_cosh_tanh(x);
+            assert_eq!(th, tanh(x));
+        }
+    }
+
+    #[test]
+    fn try_sinh_cosh_reports_overflow() {
+        for &x in &[20.0_f32, -20.0, 11.5, -11.5, 1000.0] {
+            let err = try_sinh_cosh(I16F16::from_num(x)).unwrap_err();
+            assert_eq!(err, fixed_analytics::Error::overflow("sinh_cosh"));
+        }
+        assert!(try_sinh_cosh(I32F32::from_num(23)).is_err());
+    }
+
+    #[test]
+    fn try_sinh_cosh_ok_matches_saturating() {
+        for i in -20..=20 {
+            let x = I16F16::from_num(i) * I16F16::from_num(0.5);
+            assert_eq!(try_sinh_cosh(x).unwrap(), sinh_cosh(x));
+        }
+        let x = I32F32::from_num(20);
+        assert_eq!(try_sinh_cosh(x).unwrap(), sinh_cosh(x));
+    }
+
+    #[test]
+    fn sinh_cosh_i16f16_overflow_edge() {
+        // cosh(x) reaches I16F16::MAX at x = ln(2^16) ≈ 11.0904.
+        let edge = 16.0 * core::f64::consts::LN_2;
+        // The limit is rounded to I16F16, so allow two ULPs either side.
+        let margin = 2.0 * I16F16::DELTA.to_num::<f64>();
+        let center = I16F16::from_num(edge).to_bits();
+        for bits in center - 64..=center + 64 {
+            for x in [I16F16::from_bits(bits), I16F16::from_bits(-bits)] {
+                let x_f64: f64 = x.to_num();
+                let (sh, ch) = sinh_cosh(x);
+                if x_f64.abs() < edge - margin {
+                    assert_eq!(try_sinh_cosh(x).unwrap(), (sh, ch));
+                    let (sh_f64, ch_f64): (f64, f64) = (sh.to_num(), ch.to_num());
+                    let rel = (ch_f64 - x_f64.cosh()).abs() / x_f64.cosh();
+                    assert!(rel < 2e-3, "cosh({x_f64}) = {ch_f64}, rel error {rel}");
+                    assert_eq!(
+                        sh.is_negative(),
+                        x.is_negative(),
+                        "sinh({x_f64}) = {sh_f64}"
+                    );
+                } else if x_f64.abs() > edge + margin {
+                    assert!(try_sinh_cosh(x).is_err(), "x = {x_f64} should overflow");
+                    let sh_sat = if x.is_negative() {
+                        I16F16::MIN
+                    } else {
+                        I16F16::MAX
+                    };
+                    assert_eq!((sh, ch), (sh_sat, I16F16::MAX), "x = {x_f64}");
+                }
+            }
+        }
+    }
+
     mod saturation {
         use super::*;
         use fixed::types::I32F32;
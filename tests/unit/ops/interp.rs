@@ -0,0 +1,100 @@
+//! Tests for interpolation helpers
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::{ema_step, lerp, smoothstep};
+
+    #[test]
+    fn lerp_midpoint_and_endpoints() {
+        let (a, b) = (I16F16::ZERO, I16F16::from_num(10));
+        assert_eq!(lerp(a, b, I16F16::from_num(0.5)), I16F16::from_num(5));
+        assert_eq!(lerp(a, b, I16F16::ZERO), a);
+        assert_eq!(lerp(a, b, I16F16::ONE), b);
+
+        let (start, end) = (I32F32::from_num(-3.25), I32F32::from_num(7.5));
+        assert_eq!(lerp(start, end, I32F32::ZERO), start);
+        assert_eq!(lerp(start, end, I32F32::ONE), end);
+        assert_eq!(
+            lerp(start, end, I32F32::from_num(2)),
+            I32F32::from_num(18.25)
+        );
+    }
+
+    #[test]
+    fn lerp_saturates_instead_of_wrapping() {
+        let far = I16F16::from_num(5000);
+        let ten = I16F16::from_num(10);
+        assert_eq!(lerp(I16F16::ZERO, ten, far), I16F16::MAX);
+        assert_eq!(lerp(I16F16::ZERO, -ten, far), I16F16::MIN);
+        assert_eq!(lerp(I16F16::ZERO, ten, -far), I16F16::MIN);
+        // A saturated difference clamps the slope rather than wrapping.
+        assert!(lerp(I16F16::MIN, I16F16::MAX, I16F16::ONE) < I16F16::ZERO);
+    }
+
+    #[test]
+    fn smoothstep_endpoints_and_clamping() {
+        let (e0, e1) = (I16F16::from_num(2), I16F16::from_num(6));
+        assert_eq!(smoothstep(e0, e1, e0), I16F16::ZERO);
+        assert_eq!(smoothstep(e0, e1, e1), I16F16::ONE);
+        assert_eq!(
+            smoothstep(e0, e1, I16F16::from_num(4)),
+            I16F16::from_num(0.5)
+        );
+        assert_eq!(smoothstep(e0, e1, I16F16::MIN), I16F16::ZERO);
+        assert_eq!(smoothstep(e0, e1, I16F16::MAX), I16F16::ONE);
+
+        // Reversed edges fall; equal edges are a hard step.
+        assert_eq!(smoothstep(e1, e0, e0), I16F16::ONE);
+        assert_eq!(smoothstep(e1, e0, e1), I16F16::ZERO);
+        assert_eq!(smoothstep(e0, e0, e0 - I16F16::DELTA), I16F16::ZERO);
+        assert_eq!(smoothstep(e0, e0, e0), I16F16::ONE);
+    }
+
+    #[test]
+    fn lerp_and_smoothstep_are_monotone() {
+        let (a, b) = (I16F16::from_num(-1.5), I16F16::from_num(3));
+        let (e0, e1) = (I16F16::from_num(-1), I16F16::ONE);
+        let mut previous = (lerp(a, b, I16F16::ZERO), I16F16::ZERO);
+        // Every representable step across [-1.25, 1.25].
+        for bits in -(5 << 14)..=(5 << 14) {
+            let x = I16F16::from_bits(bits);
+            let current = (lerp(a, b, x), smoothstep(e0, e1, x));
+            if bits > -(5 << 14) {
+                assert!(current.0 >= previous.0, "lerp at {x}");
+                assert!(current.1 >= previous.1, "smoothstep at {x}");
+            }
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn ema_step_alpha_endpoints() {
+        let (prev, sample) = (I16F16::from_num(-2.75), I16F16::from_num(40.5));
+        assert_eq!(ema_step(prev, sample, I16F16::ZERO), prev);
+        assert_eq!(ema_step(prev, sample, I16F16::ONE), sample);
+        assert_eq!(
+            ema_step(prev, sample, I16F16::from_num(0.5)),
+            I16F16::from_num(18.875)
+        );
+        // A saturated difference is clamped like lerp.
+        assert!(ema_step(I16F16::MIN, I16F16::MAX, I16F16::from_num(0.5)) < I16F16::ZERO);
+    }
+
+    #[test]
+    fn ema_step_converges_to_constant_input() {
+        let alpha = I32F32::from_num(0.05);
+        for (start, target) in [(0.0, 1.0), (3.0, 1.0), (-7.25, -0.001), (100.0, -100.0)] {
+            let target = I32F32::from_num(target);
+            let mut ema = I32F32::from_num(start);
+            let mut distance = (ema - target).abs();
+            for _ in 0..2000 {
+                ema = ema_step(ema, target, alpha);
+                let next = (ema - target).abs();
+                assert!(next <= distance, "moved away from {target} at {ema}");
+                distance = next;
+            }
+            assert_eq!(ema, target, "from {start}");
+        }
+    }
+}
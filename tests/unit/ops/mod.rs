@@ -1,6 +1,11 @@
 //! Tests for mathematical operations
 
 mod algebraic;
+mod array;
+mod checked;
 mod circular;
+mod complex;
 mod exponential;
 mod hyperbolic;
+mod interp;
+mod special;
@@ -0,0 +1,138 @@
+//! Tests for special functions (gamma, lgamma, erf, erfc)
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "test code uses unwrap for conciseness")]
+mod tests {
+    use fixed::types::{I3F13, I4F4, I8F8, I16F16, I32F32};
+    use fixed_analytics::{erf, erfc, gamma, lgamma};
+
+    #[test]
+    fn gamma_of_integers_is_factorial() {
+        let cases = [(1, 1.0), (2, 1.0), (3, 2.0), (5, 24.0), (8, 5040.0)];
+        for (n, expected) in cases {
+            let result: f64 = gamma(I32F32::from_num(n)).unwrap().to_num();
+            assert!(
+                (result - expected).abs() / expected < 1e-7,
+                "gamma({n}) = {result}, expected {expected}"
+            );
+        }
+        let result: f32 = gamma(I16F16::from_num(5)).unwrap().to_num();
+        assert!((result - 24.0).abs() < 0.01, "gamma(5) = {result}");
+    }
+
+    #[test]
+    fn lgamma_zeros_at_one_and_two() {
+        for x in [1, 2] {
+            let wide: f64 = lgamma(I32F32::from_num(x)).unwrap().to_num();
+            assert!(wide.abs() < 1e-7, "I32F32 lgamma({x}) = {wide}");
+            let narrow: f32 = lgamma(I16F16::from_num(x)).unwrap().to_num();
+            assert!(narrow.abs() < 1e-3, "I16F16 lgamma({x}) = {narrow}");
+        }
+    }
+
+    #[test]
+    fn lgamma_reference_values() {
+        // Values from mpmath.loggamma
+        let cases = [
+            (0.001, 6.907_178_885_4),
+            (0.1, 2.252_712_651_7),
+            (0.5, 0.572_364_942_9),
+            (1.5, -0.120_782_237_6),
+            (10.0, 12.801_827_480_1),
+            (100.0, 359.134_205_369_6),
+        ];
+        for (x, expected) in cases {
+            let result: f64 = lgamma(I32F32::from_num(x)).unwrap().to_num();
+            assert!(
+                (result - expected).abs() < 1e-6,
+                "lgamma({x}) = {result}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn lgamma_on_narrow_types() {
+        // 360 and 1260 overflow both types, so the coefficients must not be
+        // built by dividing by them.
+        let ln_24 = 24f64.ln();
+        let i8f8: f64 = lgamma(I8F8::from_num(5)).unwrap().to_num();
+        assert!((i8f8 - ln_24).abs() < 0.02, "I8F8 lgamma(5) = {i8f8}");
+        let i4f4: f64 = lgamma(I4F4::from_num(5)).unwrap().to_num();
+        assert!((i4f4 - ln_24).abs() < 0.1, "I4F4 lgamma(5) = {i4f4}");
+        // I3F13 cannot hold the shift threshold and only has to saturate.
+        assert!(lgamma(I3F13::from_num(1.5)).is_ok());
+    }
+
+    #[test]
+    fn gamma_half_is_sqrt_pi() {
+        let result: f64 = gamma(I32F32::from_num(0.5)).unwrap().to_num();
+        assert!((result - core::f64::consts::PI.sqrt()).abs() < 1e-7);
+    }
+
+    #[test]
+    fn gamma_saturates_on_overflow() {
+        assert_eq!(gamma(I16F16::from_num(10)).unwrap(), I16F16::MAX);
+    }
+
+    #[test]
+    fn non_positive_is_domain_error() {
+        for x in [0.0, -0.5, -3.0] {
+            assert!(lgamma(I16F16::from_num(x)).is_err());
+            assert!(gamma(I16F16::from_num(x)).is_err());
+        }
+    }
+
+    #[test]
+    fn erf_special_values() {
+        assert_eq!(erf(I16F16::ZERO), I16F16::ZERO);
+        assert_eq!(erfc(I16F16::ZERO), I16F16::ONE);
+        assert_eq!(erf(I16F16::from_num(10)), I16F16::ONE);
+        assert_eq!(erf(I16F16::from_num(-10)), -I16F16::ONE);
+        assert_eq!(erf(I16F16::MAX), I16F16::ONE);
+        assert_eq!(erfc(I16F16::MIN), I16F16::from_num(2));
+    }
+
+    #[test]
+    fn erf_reference_values() {
+        // Values from mpmath.erf
+        let cases = [
+            (0.1, 0.112_462_916_0),
+            (0.5, 0.520_499_877_8),
+            (1.0, 0.842_700_792_9),
+            (2.0, 0.995_322_265_0),
+            (3.0, 0.999_977_909_5),
+        ];
+        for (x, expected) in cases {
+            let result: f64 = erf(I32F32::from_num(x)).to_num();
+            assert!(
+                (result - expected).abs() < 2e-7,
+                "erf({x}) = {result}, expected {expected}"
+            );
+            let negated: f64 = erf(I32F32::from_num(-x)).to_num();
+            assert!((negated + result).abs() < 1e-9, "erf is not odd at {x}");
+        }
+    }
+
+    #[test]
+    fn erfc_is_one_minus_erf() {
+        for i in -40..=40 {
+            let x = I32F32::from_num(i) / 10;
+            let sum = erf(x) + erfc(x);
+            assert!(
+                (sum - I32F32::ONE).abs() < I32F32::from_num(1e-9),
+                "erf + erfc = {sum} at {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn erf_is_monotonic() {
+        let mut prev = erf(I16F16::from_num(-5));
+        for i in -499..=500 {
+            let x = I16F16::from_num(i) / 100;
+            let next = erf(x);
+            assert!(next >= prev, "erf decreases at {x}");
+            prev = next;
+        }
+    }
+}
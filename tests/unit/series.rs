@@ -0,0 +1,88 @@
+//! Tests for the small-argument Taylor series
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I4F4, I8F8, I32F32};
+    use fixed_analytics::series::{
+        atanh_series, cos_series, cosh_series, exp_series, sin_series, sinc_series, sinh_series,
+    };
+
+    /// Inputs spanning `[-bound, bound]`.
+    fn inputs(bound: f64) -> impl Iterator<Item = I32F32> {
+        (-64..=64).map(move |i| I32F32::from_num(bound * f64::from(i) / 64.0))
+    }
+
+    /// Largest error of `series` against `reference`, in I32F32 ULPs.
+    fn max_ulp(bound: f64, series: fn(I32F32) -> I32F32, reference: fn(f64) -> f64) -> f64 {
+        inputs(bound)
+            .map(|x| {
+                let err = series(x).to_num::<f64>() - reference(x.to_num());
+                (err * 2f64.powi(32)).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn sin_and_sinc_series_match_f64() {
+        let err = max_ulp(0.5, |x| sin_series(x, 7), f64::sin);
+        assert!(err <= 4.0, "sin off by {err} ULP");
+        let err_sinc = max_ulp(
+            0.125,
+            |x| sinc_series(x, 4),
+            |x| if x == 0.0 { 1.0 } else { x.sin() / x },
+        );
+        assert!(err_sinc <= 2.0, "sinc off by {err_sinc} ULP");
+    }
+
+    #[test]
+    fn cos_series_matches_f64() {
+        let err = max_ulp(0.5, |x| cos_series(x, 7), f64::cos);
+        assert!(err <= 4.0, "cos off by {err} ULP");
+    }
+
+    #[test]
+    fn sinh_and_cosh_series_match_f64() {
+        let err = max_ulp(1.0, |x| sinh_series(x, 7), f64::sinh);
+        assert!(err <= 4.0, "sinh off by {err} ULP");
+        let err_cosh = max_ulp(1.0, |x| cosh_series(x, 8), f64::cosh);
+        assert!(err_cosh <= 4.0, "cosh off by {err_cosh} ULP");
+    }
+
+    #[test]
+    fn exp_series_matches_f64() {
+        let err = max_ulp(0.25, |x| exp_series(x, 10), f64::exp);
+        assert!(err <= 4.0, "exp off by {err} ULP");
+    }
+
+    #[test]
+    fn atanh_series_matches_f64() {
+        let err = max_ulp(0.125, |x| atanh_series(x, 8), f64::atanh);
+        assert!(err <= 4.0, "atanh off by {err} ULP");
+    }
+
+    #[test]
+    fn fewer_terms_are_less_accurate() {
+        let short = max_ulp(0.5, |x| sinh_series(x, 2), f64::sinh);
+        let long = max_ulp(0.5, |x| sinh_series(x, 6), f64::sinh);
+        assert!(short > 1000.0 * long, "{short} vs {long}");
+        assert_eq!(exp_series(I32F32::from_num(0.3), 1), I32F32::ONE);
+        assert_eq!(sin_series(I32F32::ZERO, 5), I32F32::ZERO);
+    }
+
+    #[test]
+    fn narrow_types_stop_before_unrepresentable_divisors() {
+        // 156 and 182 overflow I8F8, 8 overflows I4F4.
+        let x = I8F8::from_num(0.5);
+        assert_eq!(sin_series(x, 7), sin_series(x, 6));
+        assert_eq!(cosh_series(x, 8), cosh_series(x, 7));
+        let small = I4F4::from_num(0.5);
+        assert_eq!(exp_series(small, 20), exp_series(small, 8));
+        let sin: f64 = sin_series(x, 7).to_num();
+        assert!((sin - 0.5f64.sin()).abs() < 2.0 / 256.0, "sin = {sin}");
+
+        // Term counts that would overflow a u32 divisor.
+        let y = I32F32::from_num(0.5);
+        assert_eq!(sin_series(y, u32::MAX), sin_series(y, 12));
+        assert_eq!(atanh_series(x, u32::MAX), atanh_series(x, 63));
+    }
+}
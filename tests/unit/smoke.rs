@@ -139,7 +139,7 @@ mod multi_type {
         let result = exp(ln(x).unwrap());
         let result_f64: f64 = result.to_num();
         assert!(
-            (result_f64 - 2.0).abs() < 0.3,
+            (result_f64 - 2.0).abs() < 1e-7,
             "exp(ln(2)) = {result_f64} (I32F32), expected ~2.0"
         );
     }
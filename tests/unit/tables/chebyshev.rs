@@ -6,7 +6,9 @@
     reason = "test code uses f64 casts for verification"
 )]
 mod tests {
-    use fixed_analytics::tables::chebyshev::{COS_Q_HI, COS_Q_LO, SIN_P_HI, SIN_P_LO};
+    use fixed_analytics::tables::chebyshev::{
+        COS_Q_FAST, COS_Q_HI, COS_Q_LO, SIN_P_FAST, SIN_P_HI, SIN_P_LO,
+    };
 
     const SCALE: f64 = (1_u64 << 63) as f64;
 
@@ -22,8 +24,13 @@ mod tests {
     fn sin_constant_term_is_neg_one_sixth() {
         // (sin(x)-x)/x³ → -1/6 at x=0
         let expected = -1.0 / 6.0;
+        let fast = i1f63_to_f64(*SIN_P_FAST.last().unwrap_or(&0));
         let lo = i1f63_to_f64(*SIN_P_LO.last().unwrap_or(&0));
         let hi = i1f63_to_f64(*SIN_P_HI.last().unwrap_or(&0));
+        assert!(
+            (fast - expected).abs() < 1e-4,
+            "SIN_P_FAST constant = {fast}, expected {expected}"
+        );
         assert!(
             (lo - expected).abs() < 1e-6,
             "SIN_P_LO constant = {lo}, expected {expected}"
@@ -38,8 +45,13 @@ mod tests {
     fn cos_constant_term_is_neg_one_half() {
         // (cos(x)-1)/x² → -1/2 at x=0
         let expected = -0.5;
+        let fast = i1f63_to_f64(*COS_Q_FAST.last().unwrap_or(&0));
         let lo = i1f63_to_f64(*COS_Q_LO.last().unwrap_or(&0));
         let hi = i1f63_to_f64(*COS_Q_HI.last().unwrap_or(&0));
+        assert!(
+            (fast - expected).abs() < 1e-3,
+            "COS_Q_FAST constant = {fast}, expected {expected}"
+        );
         assert!(
             (lo - expected).abs() < 1e-6,
             "COS_Q_LO constant = {lo}, expected {expected}"
@@ -53,8 +65,10 @@ mod tests {
     #[test]
     fn all_coefficients_magnitude_below_one() {
         for (name, table) in [
+            ("SIN_P_FAST", SIN_P_FAST.as_slice()),
             ("SIN_P_LO", SIN_P_LO.as_slice()),
             ("SIN_P_HI", SIN_P_HI.as_slice()),
+            ("COS_Q_FAST", COS_Q_FAST.as_slice()),
             ("COS_Q_LO", COS_Q_LO.as_slice()),
             ("COS_Q_HI", COS_Q_HI.as_slice()),
         ] {
@@ -67,8 +81,10 @@ mod tests {
 
     #[test]
     fn expected_array_lengths() {
+        assert_eq!(SIN_P_FAST.len(), 2);
         assert_eq!(SIN_P_LO.len(), 4);
         assert_eq!(SIN_P_HI.len(), 7);
+        assert_eq!(COS_Q_FAST.len(), 2);
         assert_eq!(COS_Q_LO.len(), 4);
         assert_eq!(COS_Q_HI.len(), 7);
     }
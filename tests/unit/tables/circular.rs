@@ -7,7 +7,7 @@
     reason = "test code uses direct indexing and f64 casts"
 )]
 mod tests {
-    use fixed_analytics::tables::circular::ATAN_TABLE;
+    use fixed_analytics::tables::circular::{ATAN_TABLE, CIRCULAR_GAIN_INV, atan_pow2};
 
     #[test]
     fn atan_table_has_64_entries() {
@@ -66,4 +66,68 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn atan_table_matches_generator() {
+        for (i, &entry) in (0_u32..).zip(ATAN_TABLE.iter()) {
+            assert_eq!(entry, atan_pow2(i), "ATAN_TABLE[{i}]");
+        }
+    }
+
+    #[test]
+    fn atan_generator_matches_f64() {
+        let scale = (1_u64 << 63) as f64;
+        for i in 0..64 {
+            let generated = atan_pow2(i) as f64 / scale;
+            let expected = (-f64::from(i)).exp2().atan();
+            assert!(
+                (generated - expected).abs() <= expected.mul_add(f64::EPSILON, 1.0 / scale),
+                "atan_pow2({i}) = {generated}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn atan_table_halves_each_step() {
+        // atan(2^-i) / atan(2^-(i+1)) = 2·(1 - 4^-i/3 + ...) / (1 - 4^-(i+1)/3 + ...),
+        // just under 2 and closing in at 4^-i. The last entries are a few
+        // units of 2^-63, too coarse to resolve the ratio.
+        for (i, pair) in (1..60).zip(ATAN_TABLE.windows(2).skip(1)) {
+            let ratio = pair[0] as f64 / pair[1] as f64;
+            let bound = 4_f64.powi(1 - i);
+            assert!(
+                ratio <= 2.0 && 2.0 - ratio <= bound,
+                "ATAN_TABLE[{i}] / ATAN_TABLE[{}] = {ratio}",
+                i + 1
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "small-tables")]
+    fn small_atan_table_keeps_i16f16_accuracy() {
+        use fixed::types::I16F16;
+        use fixed_analytics::{atan, sin};
+
+        let sin_tolerance = if cfg!(feature = "fast-math") {
+            1e-3
+        } else {
+            1e-4
+        };
+        for i in -400..=400 {
+            let x = I16F16::from_num(i) / 100;
+            let xf: f64 = x.to_num();
+            let sin_err = (sin(x).to_num::<f64>() - xf.sin()).abs();
+            let atan_err = (atan(x).to_num::<f64>() - xf.atan()).abs();
+            assert!(sin_err < sin_tolerance, "sin({xf}) off by {sin_err}");
+            assert!(atan_err < 2e-4, "atan({xf}) off by {atan_err}");
+        }
+    }
+
+    #[test]
+    fn circular_gain_inv_matches_product() {
+        let gain: f64 = (0..32).map(|i| (1.0 + 4f64.powi(-i)).sqrt()).product();
+        let stored = CIRCULAR_GAIN_INV as f64 / 2f64.powi(63);
+        assert!((stored - gain.recip()).abs() < 1e-15, "1/K = {stored}");
+    }
 }
@@ -7,7 +7,7 @@
     reason = "test code uses direct indexing and f64 casts"
 )]
 mod tests {
-    use fixed_analytics::tables::hyperbolic::{ATANH_HALF, ATANH_TABLE, needs_repeat};
+    use fixed_analytics::tables::hyperbolic::{ATANH_HALF, ATANH_TABLE, atanh_pow2, needs_repeat};
 
     /// Repeat indices for hyperbolic CORDIC convergence (used only in tests).
     const REPEAT_INDICES: [u32; 5] = [4, 13, 40, 121, 364];
@@ -88,4 +88,39 @@ mod tests {
             "atanh(0.25) = {atanh_quarter}, expected {expected_quarter}"
         );
     }
+
+    #[test]
+    fn atanh_table_matches_generator() {
+        for (i, &entry) in (1_u32..).zip(ATANH_TABLE.iter()) {
+            assert_eq!(entry, atanh_pow2(i), "atanh(2^-{i})");
+        }
+    }
+
+    #[test]
+    fn atanh_generator_matches_f64() {
+        let scale = (1_u64 << 63) as f64;
+        for i in 1..64 {
+            let generated = atanh_pow2(i) as f64 / scale;
+            let expected = (-f64::from(i)).exp2().atanh();
+            assert!(
+                (generated - expected).abs() <= expected.mul_add(f64::EPSILON, 1.0 / scale),
+                "atanh_pow2({i}) = {generated}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn atanh_table_halves_each_step() {
+        // atanh(2^-i) / atanh(2^-(i+1)) is just over 2, closing in at 4^-i.
+        // The last entries are too coarse to resolve the ratio.
+        for (j, pair) in (0..60).zip(ATANH_TABLE.windows(2)) {
+            let ratio = pair[0] as f64 / pair[1] as f64;
+            let bound = 4_f64.powi(-j);
+            assert!(
+                ratio >= 2.0 && ratio - 2.0 <= bound,
+                "ATANH_TABLE[{j}] / ATANH_TABLE[{}] = {ratio}",
+                j + 1
+            );
+        }
+    }
 }
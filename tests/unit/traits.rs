@@ -98,6 +98,131 @@ mod tests {
         assert_eq!(pos.div(zero), I16F16::MAX);
     }
 
+    #[test]
+    fn saturating_div_by_zero_does_not_panic() {
+        let one = I16F16::ONE;
+        let zero = I16F16::ZERO;
+        assert_eq!(CordicNumber::saturating_div(one, zero), I16F16::MAX);
+        assert_eq!(CordicNumber::saturating_div(-one, zero), I16F16::MIN);
+        assert_eq!(
+            CordicNumber::saturating_div(I32F32::ONE, I32F32::ZERO),
+            I32F32::MAX
+        );
+        assert_eq!(
+            CordicNumber::saturating_div(I16F16::MAX, I16F16::from_num(0.5)),
+            I16F16::MAX
+        );
+        assert_eq!(
+            CordicNumber::saturating_div(I16F16::from_num(3), I16F16::from_num(2)),
+            I16F16::from_num(1.5)
+        );
+    }
+
+    #[test]
+    fn wrapping_ops_wrap_around() {
+        assert_eq!(
+            CordicNumber::wrapping_add(I16F16::MAX, I16F16::DELTA),
+            I16F16::MIN
+        );
+        assert_eq!(
+            CordicNumber::wrapping_sub(I16F16::MIN, I16F16::DELTA),
+            I16F16::MAX
+        );
+        assert_eq!(
+            CordicNumber::wrapping_add(I16F16::ONE, I16F16::ONE),
+            I16F16::from_num(2)
+        );
+    }
+
+    #[test]
+    fn epsilon_is_smallest_positive_value() {
+        assert_eq!(I16F16::epsilon(), I16F16::from_num(1.0 / 65536.0));
+        assert_eq!(I16F16::epsilon().to_bits(), 1);
+        assert_eq!(I32F32::epsilon().to_bits(), 1);
+        assert_eq!(I4F60::epsilon().to_bits(), 1);
+        assert_eq!(I16F16::epsilon() >> 1, I16F16::ZERO);
+    }
+
+    #[test]
+    fn mul_add_rounds_once_toward_negative_infinity() {
+        let half = I16F16::from_num(0.5);
+        // δ·½ + δ is exact only if the product is not rounded first.
+        assert_eq!(
+            CordicNumber::mul_add(I16F16::DELTA, half, I16F16::DELTA),
+            I16F16::DELTA
+        );
+        assert_eq!(
+            CordicNumber::mul_add(-I16F16::DELTA, half, I16F16::ZERO),
+            -I16F16::DELTA
+        );
+        assert_eq!(
+            CordicNumber::mul_add(I16F16::MAX, I16F16::from_num(2), I16F16::ONE),
+            I16F16::MAX
+        );
+    }
+
+    #[test]
+    fn checked_mul_reports_overflow() {
+        let thousand = I16F16::from_num(1000);
+        assert_eq!(CordicNumber::checked_mul(thousand, thousand), None);
+        assert_eq!(
+            CordicNumber::checked_mul(thousand, I16F16::from_num(30)),
+            Some(I16F16::from_num(30_000))
+        );
+    }
+
+    #[test]
+    fn rem_euclid_is_non_negative() {
+        let two_pi = I16F16::PI + I16F16::PI;
+        let wrapped = CordicNumber::rem_euclid(I16F16::from_num(-1), two_pi);
+        assert!(wrapped >= I16F16::ZERO && wrapped < two_pi);
+        assert_eq!(wrapped, two_pi - I16F16::ONE);
+
+        let seven_half = I16F16::from_num(7.5);
+        let two = I16F16::from_num(2);
+        assert_eq!(
+            CordicNumber::rem_euclid(seven_half, two),
+            I16F16::from_num(1.5)
+        );
+        assert_eq!(
+            CordicNumber::rem_euclid(-seven_half, two),
+            I16F16::from_num(0.5)
+        );
+        assert_eq!(
+            CordicNumber::rem_euclid(-seven_half, -two),
+            I16F16::from_num(0.5)
+        );
+        assert_eq!(
+            CordicNumber::rem_euclid(seven_half, I16F16::ZERO),
+            I16F16::ZERO
+        );
+        assert_eq!(
+            CordicNumber::rem_euclid(I16F16::MIN, -I16F16::DELTA),
+            I16F16::ZERO
+        );
+    }
+
+    #[test]
+    fn signum_and_copysign() {
+        let three = I16F16::from_num(3);
+        assert_eq!(CordicNumber::signum(-three), -I16F16::ONE);
+        assert_eq!(CordicNumber::signum(three), I16F16::ONE);
+        assert_eq!(CordicNumber::signum(I16F16::ZERO), I16F16::ZERO);
+        assert_eq!(CordicNumber::signum(I16F16::DELTA), I16F16::ONE);
+
+        assert_eq!(CordicNumber::copysign(three, -I16F16::ONE), -three);
+        assert_eq!(CordicNumber::copysign(-three, I16F16::ONE), three);
+        assert_eq!(CordicNumber::copysign(-three, I16F16::ZERO), three);
+        assert_eq!(
+            CordicNumber::copysign(I16F16::MIN, I16F16::ONE),
+            I16F16::MAX
+        );
+        assert_eq!(
+            CordicNumber::copysign(I16F16::MIN, -I16F16::ONE),
+            -I16F16::MAX
+        );
+    }
+
     #[test]
     fn frac_pi_4_values() {
         // Test the frac_pi_4() default implementation
@@ -120,4 +245,62 @@ mod tests {
             "I64F64::frac_pi_4() = {pi_4_64}, expected ~0.7854"
         );
     }
+
+    #[test]
+    fn pi_hi_lo_residual_matches_f64() {
+        // f64 resolves π - PI to well under a thousandth of an ULP here.
+        let (hi, lo) = I16F16::pi_hi_lo();
+        assert_eq!(hi, I16F16::PI);
+        let expected = (core::f64::consts::PI - hi.to_num::<f64>()) * 65_536.0;
+        assert!(
+            (lo.to_num::<f64>() - expected).abs() < 1e-3,
+            "I16F16 lo = {lo}"
+        );
+
+        let (hi_32, lo_32) = I32F32::pi_hi_lo();
+        let expected_32 = (core::f64::consts::PI - hi_32.to_num::<f64>()) * 4_294_967_296.0;
+        assert!(
+            (lo_32.to_num::<f64>() - expected_32).abs() < 1e-3,
+            "I32F32 lo = {lo_32}"
+        );
+    }
+
+    #[test]
+    fn pi_hi_lo_residual_is_below_one_ulp() {
+        assert!(I8F8::pi_hi_lo().1.abs() < I8F8::ONE);
+        assert!(I4F12::pi_hi_lo().1.abs() < I4F12::ONE);
+        assert!(I4F60::pi_hi_lo().1.abs() < I4F60::ONE);
+        assert!(I64F64::pi_hi_lo().1.abs() < I64F64::ONE);
+    }
+
+    #[test]
+    fn try_from_f64_rejects_unrepresentable_values() {
+        use fixed_analytics::try_from_f64;
+
+        assert_eq!(try_from_f64::<I16F16>(1e9), None);
+        assert_eq!(try_from_f64::<I16F16>(-1e9), None);
+        assert_eq!(try_from_f64::<I16F16>(f64::NAN), None);
+        assert_eq!(try_from_f64::<I16F16>(f64::INFINITY), None);
+        assert_eq!(try_from_f64::<I16F16>(f64::NEG_INFINITY), None);
+        // 32768 is one past I16F16::MAX after rounding; -32768 is MIN.
+        assert_eq!(try_from_f64::<I16F16>(32768.0), None);
+        assert_eq!(try_from_f64::<I16F16>(-32768.0), Some(I16F16::MIN));
+        // I32F32::MAX is not an f64, and its nearest f64 is 2^31.
+        assert_eq!(try_from_f64::<I32F32>(I32F32::MAX.to_num()), None);
+    }
+
+    #[test]
+    fn try_from_f64_rounds_in_range_values() {
+        use fixed_analytics::try_from_f64;
+
+        assert_eq!(try_from_f64::<I16F16>(2.5), Some(I16F16::from_num(2.5)));
+        assert_eq!(try_from_f64::<I16F16>(-0.0), Some(I16F16::ZERO));
+        assert_eq!(
+            // Three quarters of a step above one.
+            try_from_f64::<I16F16>(1.0 + 3.0 / 262_144.0),
+            Some(I16F16::ONE + I16F16::DELTA)
+        );
+        assert_eq!(try_from_f64::<I64F64>(1e12), Some(I64F64::from_num(1e12)));
+        assert_eq!(I8F8::checked_from_f64(127.99), Some(I8F8::from_num(127.99)));
+    }
 }
@@ -0,0 +1,44 @@
+//! Tests for the num-traits interop trait
+
+#[cfg(all(test, feature = "num-traits"))]
+mod tests {
+    use fixed::types::{I16F16, I32F32};
+    use fixed_analytics::Transcendental;
+
+    fn generic_sin<T: Transcendental>(x: T) -> T {
+        x.sin()
+    }
+
+    #[test]
+    fn generic_sin_on_i32f32() {
+        // The fast sin/cos polynomials are only accurate to ~1e-4.
+        let tolerance = if cfg!(feature = "fast-math") {
+            1e-4
+        } else {
+            1e-6
+        };
+        let result: f64 = generic_sin(I32F32::from_num(0.5)).to_num();
+        assert!((result - 0.5f64.sin()).abs() < tolerance);
+    }
+
+    #[test]
+    fn methods_match_free_functions() {
+        let x = I32F32::from_num(2.0);
+        assert_eq!(x.cos(), fixed_analytics::cos(x));
+        assert_eq!(x.exp(), fixed_analytics::exp(x));
+        assert_eq!(
+            Transcendental::sqrt(x),
+            fixed_analytics::sqrt(x).unwrap_or_default()
+        );
+        assert_eq!(x.ln(), fixed_analytics::ln(x).unwrap_or_default());
+        assert_eq!(x.powi(3), I32F32::from_num(8));
+    }
+
+    #[test]
+    fn domain_violations_saturate() {
+        let negative = I16F16::from_num(-1);
+        assert_eq!(Transcendental::sqrt(negative), I16F16::ZERO);
+        assert_eq!(negative.ln(), I16F16::MIN);
+        assert_eq!(I16F16::ZERO.ln(), I16F16::MIN);
+    }
+}
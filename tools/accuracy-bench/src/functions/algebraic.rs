@@ -1,5 +1,5 @@
 use crate::{Domain, TestedFunction, reference};
-use fixed::types::{I16F16, I32F32};
+use fixed::types::{I8F8, I16F16, I32F32, I64F64};
 use rug::Float;
 
 pub fn register() -> Vec<Box<dyn TestedFunction>> {
@@ -23,4 +23,14 @@ impl TestedFunction for Sqrt {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::sqrt(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::sqrt(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::sqrt(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::sqrt(x))
+    }
 }
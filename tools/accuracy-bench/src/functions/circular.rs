@@ -1,6 +1,7 @@
 use crate::{Domain, TestedFunction, reference};
-use fixed::types::{I16F16, I32F32};
+use fixed::types::{I8F8, I16F16, I32F32, I64F64};
 use rug::Float;
+use std::f64::consts::{FRAC_PI_8, PI};
 
 pub fn register() -> Vec<Box<dyn TestedFunction>> {
     vec![
@@ -21,6 +22,9 @@ impl TestedFunction for Sin {
     fn domain(&self) -> Domain {
         Domain::Full
     }
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![(-PI, PI), (10.0, 100.0)]
+    }
     fn reference(&self, x: &Float) -> Float {
         reference::circular::sin(x)
     }
@@ -30,6 +34,16 @@ impl TestedFunction for Sin {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::sin(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::sin(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::sin(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::sin(x))
+    }
 }
 
 struct Cos;
@@ -40,6 +54,9 @@ impl TestedFunction for Cos {
     fn domain(&self) -> Domain {
         Domain::Full
     }
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![(-PI, PI), (10.0, 100.0)]
+    }
     fn reference(&self, x: &Float) -> Float {
         reference::circular::cos(x)
     }
@@ -49,6 +66,16 @@ impl TestedFunction for Cos {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::cos(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::cos(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::cos(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::cos(x))
+    }
 }
 
 struct Tan;
@@ -59,6 +86,10 @@ impl TestedFunction for Tan {
     fn domain(&self) -> Domain {
         Domain::Open(-1.5, 1.5)
     }
+    /// `tan` switches to a CORDIC rotation within π/8 of π/4.
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![(-1.5, 1.5), (FRAC_PI_8, 3.0 * FRAC_PI_8)]
+    }
     fn reference(&self, x: &Float) -> Float {
         reference::circular::tan(x)
     }
@@ -68,6 +99,16 @@ impl TestedFunction for Tan {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::tan(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::tan(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::tan(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::tan(x))
+    }
 }
 
 struct Asin;
@@ -76,7 +117,10 @@ impl TestedFunction for Asin {
         "asin"
     }
     fn domain(&self) -> Domain {
-        Domain::Closed(-0.99, 0.99)
+        Domain::Closed(-0.999, 0.999)
+    }
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![(-0.999, 0.999), (0.99, 0.999)]
     }
     fn reference(&self, x: &Float) -> Float {
         reference::circular::asin(x)
@@ -87,6 +131,16 @@ impl TestedFunction for Asin {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::asin(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::asin(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::asin(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::asin(x))
+    }
 }
 
 struct Acos;
@@ -95,7 +149,10 @@ impl TestedFunction for Acos {
         "acos"
     }
     fn domain(&self) -> Domain {
-        Domain::Closed(-0.99, 0.99)
+        Domain::Closed(-0.999, 0.999)
+    }
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![(-0.999, 0.999), (0.99, 0.999)]
     }
     fn reference(&self, x: &Float) -> Float {
         reference::circular::acos(x)
@@ -106,6 +163,16 @@ impl TestedFunction for Acos {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::acos(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::acos(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::acos(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::acos(x))
+    }
 }
 
 struct Atan;
@@ -125,4 +192,82 @@ impl TestedFunction for Atan {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::atan(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::atan(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::atan(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::atan(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        MIN_REFERENCE_PRECISION_F64, MIN_REFERENCE_PRECISION_I64F64, reference_precision_warning,
+        sampling::SampleStrategy, test_function, test_function_with_precision,
+    };
+
+    #[test]
+    fn test_sin_large_angles_keep_small_angle_accuracy() {
+        // The two-word π reduction keeps large angles within a small factor
+        // of the error on [-π, π].
+        let result = test_function(&Sin, &SampleStrategy::quick());
+        let [small, large] = result.subdomains.as_slice() else {
+            panic!("expected two subdomains for sin");
+        };
+        assert!(small.i16f16.count > 0 && large.i16f16.count > 0);
+        assert!(large.i16f16.abs_mean < 2.0 * small.i16f16.abs_mean);
+        assert!(large.i32f32.abs_mean < 2.0 * small.i32f32.abs_mean);
+    }
+
+    #[test]
+    fn test_sin_i64f64_error_resolves_below_f64() {
+        // I64F64 sin has a mean relative error near 2^-63 on [-π, π]. Rounding
+        // both values to f64 would report ~2^-54 instead.
+        let result = test_function(&Sin, &SampleStrategy::new(200, 0, 0, 0));
+        let [small, _] = result.subdomains.as_slice() else {
+            panic!("expected two subdomains for sin");
+        };
+        assert!(small.i64f64.count > 0);
+        assert!(
+            small.i64f64.rel_mean < 2f64.powi(-58),
+            "I64F64 rel_mean {:e}",
+            small.i64f64.rel_mean
+        );
+    }
+
+    #[test]
+    fn test_lower_reference_precision_keeps_narrow_results() {
+        let strategy = SampleStrategy::new(300, 0, 0, 0);
+        let low = test_function_with_precision(&Sin, &strategy, MIN_REFERENCE_PRECISION_F64);
+        let high = test_function_with_precision(&Sin, &strategy, 4096);
+        // Below I64F64 the measurement is unchanged at the lower precision.
+        assert!((low.i32f32.rel_mean - high.i32f32.rel_mean).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_low_reference_precision_warns() {
+        assert!(reference_precision_warning(32).is_some_and(|w| w.contains("every type")));
+        assert!(
+            reference_precision_warning(MIN_REFERENCE_PRECISION_F64)
+                .is_some_and(|w| w.contains("I64F64"))
+        );
+        assert_eq!(
+            reference_precision_warning(MIN_REFERENCE_PRECISION_I64F64),
+            None
+        );
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_libm_comparison_runs_for_sin() {
+        let result = test_function(&Sin, &SampleStrategy::new(100, 0, 0, 0));
+        assert_eq!(result.libm_f32.count, result.samples_tested);
+        assert!(result.libm_f32.rel_mean < 1e-3);
+    }
 }
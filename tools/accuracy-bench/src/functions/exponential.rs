@@ -1,5 +1,5 @@
 use crate::{Domain, TestedFunction, reference};
-use fixed::types::{I16F16, I32F32};
+use fixed::types::{I8F8, I16F16, I32F32, I64F64};
 use rug::Float;
 
 pub fn register() -> Vec<Box<dyn TestedFunction>> {
@@ -29,6 +29,16 @@ impl TestedFunction for Exp {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::exp(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::exp(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::exp(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::exp(x))
+    }
 }
 
 struct Ln;
@@ -48,6 +58,16 @@ impl TestedFunction for Ln {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::ln(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::ln(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::ln(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::ln(x))
+    }
 }
 
 struct Log2;
@@ -67,6 +87,16 @@ impl TestedFunction for Log2 {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::log2(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::log2(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::log2(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::log2(x))
+    }
 }
 
 struct Log10;
@@ -86,6 +116,16 @@ impl TestedFunction for Log10 {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::log10(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::log10(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::log10(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::log10(x))
+    }
 }
 
 struct Pow2;
@@ -105,4 +145,52 @@ impl TestedFunction for Pow2 {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::pow2(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::pow2(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::pow2(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::pow2(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sampling::SampleStrategy, test_function};
+
+    /// [`Exp`] over a domain that runs past I16F16's overflow at x ≈ 10.4.
+    struct WideExp;
+    impl TestedFunction for WideExp {
+        fn name(&self) -> &'static str {
+            "exp"
+        }
+        fn domain(&self) -> Domain {
+            Domain::Closed(-10.0, 12.0)
+        }
+        fn reference(&self, x: &Float) -> Float {
+            Exp.reference(x)
+        }
+        fn compute_i16f16(&self, x: I16F16) -> I16F16 {
+            Exp.compute_i16f16(x)
+        }
+        fn compute_i32f32(&self, x: I32F32) -> I32F32 {
+            Exp.compute_i32f32(x)
+        }
+    }
+
+    #[test]
+    fn test_exp_past_overflow_reports_saturation() {
+        let strategy = SampleStrategy::new(221, 0, 0, 0);
+        let wide = test_function(&WideExp, &strategy).saturation_rate;
+        // Grid step 0.1: the 17 points from 10.4 to 12 saturate.
+        assert!(wide.i16f16 > 0.05, "I16F16 saturation rate {}", wide.i16f16);
+        assert_eq!(wide.i32f32, 0.0);
+
+        let default = test_function(&Exp, &strategy).saturation_rate;
+        assert_eq!(default.i16f16, 0.0);
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{Domain, TestedFunction, reference};
-use fixed::types::{I16F16, I32F32};
+use fixed::types::{I8F8, I16F16, I32F32, I64F64};
 use rug::Float;
 
 pub fn register() -> Vec<Box<dyn TestedFunction>> {
@@ -32,6 +32,16 @@ impl TestedFunction for Sinh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::sinh(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::sinh(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::sinh(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::sinh(x))
+    }
 }
 
 struct Cosh;
@@ -51,6 +61,16 @@ impl TestedFunction for Cosh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::cosh(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::cosh(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::cosh(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::cosh(x))
+    }
 }
 
 struct Tanh;
@@ -70,6 +90,16 @@ impl TestedFunction for Tanh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::tanh(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::tanh(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::tanh(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::tanh(x))
+    }
 }
 
 struct Coth;
@@ -89,6 +119,16 @@ impl TestedFunction for Coth {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::coth(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::coth(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::coth(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::coth(x))
+    }
 }
 
 struct Asinh;
@@ -108,6 +148,16 @@ impl TestedFunction for Asinh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::asinh(x)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::asinh(x))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::asinh(x))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::asinh(x))
+    }
 }
 
 struct Acosh;
@@ -127,6 +177,16 @@ impl TestedFunction for Acosh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::acosh(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::acosh(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::acosh(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::acosh(x))
+    }
 }
 
 struct Atanh;
@@ -146,6 +206,16 @@ impl TestedFunction for Atanh {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::atanh(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::atanh(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::atanh(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::atanh(x))
+    }
 }
 
 struct Acoth;
@@ -165,4 +235,14 @@ impl TestedFunction for Acoth {
     fn compute_i32f32(&self, x: I32F32) -> I32F32 {
         fixed_analytics::acoth(x).unwrap_or(I32F32::ZERO)
     }
+    fn compute_i8f8(&self, x: I8F8) -> Option<I8F8> {
+        Some(fixed_analytics::acoth(x).unwrap_or(I8F8::ZERO))
+    }
+    fn compute_i64f64(&self, x: I64F64) -> Option<I64F64> {
+        Some(fixed_analytics::acoth(x).unwrap_or(I64F64::ZERO))
+    }
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, x: f32) -> Option<f32> {
+        Some(reference::libm_f32::acoth(x))
+    }
 }
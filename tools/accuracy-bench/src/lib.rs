@@ -6,13 +6,43 @@ pub mod readme;
 pub mod reference;
 pub mod report;
 pub mod sampling;
+pub mod tolerance;
 
 use fixed::traits::Fixed;
-use metrics::ErrorStats;
+use fixed_analytics::try_from_f64;
+use metrics::{ErrorMeasurement, ErrorStats};
 use rug::Float;
 use sampling::SampleStrategy;
 
+/// Default MPFR precision of the reference values. I64F64 carries 128
+/// significant bits, so twice that leaves ample room to judge its sub-ULP
+/// errors. Override with `--ref-bits`.
 pub const REFERENCE_PRECISION: u32 = 256;
+const _: () = assert!(REFERENCE_PRECISION >= MIN_REFERENCE_PRECISION_I64F64);
+
+/// Smallest safe reference precision for I8F8, I16F16 and I32F32, whose
+/// errors are measured in f64: its 53 bits plus guard bits.
+pub const MIN_REFERENCE_PRECISION_F64: u32 = 64;
+
+/// Smallest safe reference precision for I64F64, whose errors are measured in
+/// MPFR against its 128 significant bits.
+pub const MIN_REFERENCE_PRECISION_I64F64: u32 = 2 * i128::BITS;
+
+/// A warning if `bits` is too low for the reference error to stay well below
+/// the errors being measured.
+pub fn reference_precision_warning(bits: u32) -> Option<String> {
+    if bits < MIN_REFERENCE_PRECISION_F64 {
+        Some(format!(
+            "--ref-bits {bits} is below {MIN_REFERENCE_PRECISION_F64}; reference error may swamp the measurement for every type"
+        ))
+    } else if bits < MIN_REFERENCE_PRECISION_I64F64 {
+        Some(format!(
+            "--ref-bits {bits} is below {MIN_REFERENCE_PRECISION_I64F64}; reference error may swamp the I64F64 measurement"
+        ))
+    } else {
+        None
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum Domain {
@@ -47,26 +77,174 @@ impl Domain {
 pub trait TestedFunction: Send + Sync {
     fn name(&self) -> &'static str;
     fn domain(&self) -> Domain;
+    /// The exact value at `x`, evaluated at the precision of `x`.
     fn reference(&self, x: &Float) -> Float;
     fn compute_i16f16(&self, x: fixed::types::I16F16) -> fixed::types::I16F16;
     fn compute_i32f32(&self, x: fixed::types::I32F32) -> fixed::types::I32F32;
+    /// Input ranges reported separately; defaults to the whole sampled domain.
+    fn subdomains(&self) -> Vec<(f64, f64)> {
+        vec![self.domain().sampling_bounds()]
+    }
+    /// Returns `None` to skip types where the function is not meaningful.
+    fn compute_i8f8(&self, _x: fixed::types::I8F8) -> Option<fixed::types::I8F8> {
+        None
+    }
+    /// Returns `None` to skip types where the function is not meaningful.
+    fn compute_i64f64(&self, _x: fixed::types::I64F64) -> Option<fixed::types::I64F64> {
+        None
+    }
+    /// The same function evaluated by `libm` in single precision, for comparison.
+    #[cfg(feature = "libm")]
+    fn compute_libm_f32(&self, _x: f32) -> Option<f32> {
+        None
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FunctionResult {
     pub name: String,
+    #[serde(default = "ErrorStats::empty")]
+    pub i8f8: ErrorStats,
     pub i16f16: ErrorStats,
     pub i32f32: ErrorStats,
+    #[serde(default = "ErrorStats::empty")]
+    pub i64f64: ErrorStats,
+    /// Error of `libm` in f32; empty unless built with the `libm` feature.
+    #[serde(default = "ErrorStats::empty")]
+    pub libm_f32: ErrorStats,
+    /// Error broken down by [`TestedFunction::subdomains`].
+    #[serde(default)]
+    pub subdomains: Vec<SubdomainResult>,
+    /// Fraction of each type's samples that saturated.
+    #[serde(default)]
+    pub saturation_rate: SaturationRate,
     pub samples_tested: usize,
 }
 
+/// One-line summary of the I16F16 and I32F32 relative errors, for logging
+/// progress during long runs.
+impl std::fmt::Display for FunctionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: I16F16 rel_mean={:.2e} max={:.2e}, I32F32 rel_mean={:.2e} max={:.2e} ({} samples)",
+            self.name,
+            self.i16f16.rel_mean,
+            self.i16f16.rel_max,
+            self.i32f32.rel_mean,
+            self.i32f32.rel_max,
+            self.samples_tested
+        )
+    }
+}
+
+/// Fraction of samples, per type, whose result was `T::MAX` or `T::MIN`.
+///
+/// Saturated results otherwise show up only as large errors, or not at all
+/// when the domain is narrowed to avoid them.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SaturationRate {
+    pub i8f8: f64,
+    pub i16f16: f64,
+    pub i32f32: f64,
+    pub i64f64: f64,
+}
+
+impl SaturationRate {
+    pub fn any(&self) -> bool {
+        [self.i8f8, self.i16f16, self.i32f32, self.i64f64]
+            .iter()
+            .any(|&rate| rate > 0.0)
+    }
+}
+
+/// Per-type counts of computed results and of those that saturated.
+#[derive(Default)]
+struct SaturationCounts {
+    computed: [usize; 4],
+    saturated: [usize; 4],
+}
+
+impl SaturationCounts {
+    /// Records `result` for the type at `index` and passes it through.
+    fn record<T: Fixed>(&mut self, index: usize, result: T) -> T {
+        self.computed[index] += 1;
+        if result == T::MAX || result == T::MIN {
+            self.saturated[index] += 1;
+        }
+        result
+    }
+
+    fn rates(&self) -> SaturationRate {
+        let rate = |i: usize| {
+            if self.computed[i] == 0 {
+                0.0
+            } else {
+                self.saturated[i] as f64 / self.computed[i] as f64
+            }
+        };
+        SaturationRate {
+            i8f8: rate(0),
+            i16f16: rate(1),
+            i32f32: rate(2),
+            i64f64: rate(3),
+        }
+    }
+}
+
+/// Error statistics restricted to inputs in `[lo, hi]`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubdomainResult {
+    pub lo: f64,
+    pub hi: f64,
+    pub i8f8: ErrorStats,
+    pub i16f16: ErrorStats,
+    pub i32f32: ErrorStats,
+    pub i64f64: ErrorStats,
+}
+
+/// Per-type error measurements accumulated over a set of inputs.
+#[derive(Default)]
+struct TypeErrors {
+    i8f8: Vec<ErrorMeasurement>,
+    i16f16: Vec<ErrorMeasurement>,
+    i32f32: Vec<ErrorMeasurement>,
+    i64f64: Vec<ErrorMeasurement>,
+}
+
+impl TypeErrors {
+    fn push(&mut self, point: [Option<ErrorMeasurement>; 4]) {
+        let [i8f8, i16f16, i32f32, i64f64] = point;
+        self.i8f8.extend(i8f8);
+        self.i16f16.extend(i16f16);
+        self.i32f32.extend(i32f32);
+        self.i64f64.extend(i64f64);
+    }
+}
+
 pub fn test_function(func: &dyn TestedFunction, strategy: &SampleStrategy) -> FunctionResult {
+    test_function_with_precision(func, strategy, REFERENCE_PRECISION)
+}
+
+/// [`test_function`] with the MPFR reference evaluated at `reference_bits`.
+/// Lower values run faster; see [`reference_precision_warning`] for the
+/// safe minimum.
+pub fn test_function_with_precision(
+    func: &dyn TestedFunction,
+    strategy: &SampleStrategy,
+    reference_bits: u32,
+) -> FunctionResult {
     let domain = func.domain();
     let (lo, hi) = domain.sampling_bounds();
     let points = strategy.generate(lo, hi);
+    let subdomains = func.subdomains();
 
-    let mut i16f16_errors = Vec::new();
-    let mut i32f32_errors = Vec::new();
+    let mut errors = TypeErrors::default();
+    let mut subdomain_errors: Vec<TypeErrors> =
+        subdomains.iter().map(|_| TypeErrors::default()).collect();
+    #[cfg_attr(not(feature = "libm"), expect(unused_mut))]
+    let mut libm_f32_errors = Vec::new();
+    let mut saturation = SaturationCounts::default();
     let mut tested = 0;
 
     for &x_f64 in &points {
@@ -74,43 +252,103 @@ pub fn test_function(func: &dyn TestedFunction, strategy: &SampleStrategy) -> Fu
             continue;
         }
 
-        let x_mpfr = Float::with_val(REFERENCE_PRECISION, x_f64);
-        let ref_f64 = func.reference(&x_mpfr).to_f64();
+        let x_mpfr = Float::with_val(reference_bits, x_f64);
+        let ref_mpfr = func.reference(&x_mpfr);
+        let ref_f64 = ref_mpfr.to_f64();
 
-        if let Some(x) = try_from_f64::<fixed::types::I16F16>(x_f64) {
-            let result: f64 = func.compute_i16f16(x).to_num();
-            if let Some(err) = metrics::compute_error(result, ref_f64) {
-                i16f16_errors.push(err);
+        let i8f8 = try_from_f64::<fixed::types::I8F8>(x_f64)
+            .and_then(|x| func.compute_i8f8(x))
+            .map(|result| saturation.record(0, result))
+            .and_then(|result| {
+                metrics::compute_error(
+                    x_f64,
+                    result.to_num(),
+                    ref_f64,
+                    fixed::types::I8F8::FRAC_NBITS,
+                )
+            });
+
+        let i16f16 = try_from_f64::<fixed::types::I16F16>(x_f64).and_then(|x| {
+            let result: f64 = saturation.record(1, func.compute_i16f16(x)).to_num();
+            metrics::compute_error(x_f64, result, ref_f64, fixed::types::I16F16::FRAC_NBITS)
+        });
+
+        let i32f32 = try_from_f64::<fixed::types::I32F32>(x_f64).and_then(|x| {
+            let result: f64 = saturation.record(2, func.compute_i32f32(x)).to_num();
+            metrics::compute_error(x_f64, result, ref_f64, fixed::types::I32F32::FRAC_NBITS)
+        });
+
+        // I64F64 exceeds f64 precision, so its error is measured in MPFR.
+        let i64f64 = try_from_f64::<fixed::types::I64F64>(x_f64)
+            .and_then(|x| func.compute_i64f64(x))
+            .map(|result| saturation.record(3, result))
+            .and_then(|result| {
+                let frac_bits = fixed::types::I64F64::FRAC_NBITS;
+                let computed = Float::with_val(reference_bits, result.to_bits()) >> frac_bits;
+                metrics::compute_error_exact(x_f64, &computed, &ref_mpfr, frac_bits)
+            });
+
+        let point = [i8f8, i16f16, i32f32, i64f64];
+        errors.push(point);
+        for (&(sub_lo, sub_hi), sub_errors) in subdomains.iter().zip(&mut subdomain_errors) {
+            if x_f64 >= sub_lo && x_f64 <= sub_hi {
+                sub_errors.push(point);
             }
         }
 
-        if let Some(x) = try_from_f64::<fixed::types::I32F32>(x_f64) {
-            let result: f64 = func.compute_i32f32(x).to_num();
-            if let Some(err) = metrics::compute_error(result, ref_f64) {
-                i32f32_errors.push(err);
+        // f32 has 23 fraction bits, so the ULP figure is only exact in [1, 2).
+        // libm is scored against the reference at its rounded f32 input, so
+        // input quantization is not counted as function error.
+        #[cfg(feature = "libm")]
+        {
+            let x_f32 = x_f64 as f32;
+            let x_input = f64::from(x_f32);
+            if let Some(result) = func.compute_libm_f32(x_f32)
+                && domain.contains(x_input)
+            {
+                let ref_input = func
+                    .reference(&Float::with_val(reference_bits, x_input))
+                    .to_f64();
+                if let Some(err) = metrics::compute_error(
+                    x_input,
+                    f64::from(result),
+                    ref_input,
+                    f32::MANTISSA_DIGITS - 1,
+                ) {
+                    libm_f32_errors.push(err);
+                }
             }
         }
 
         tested += 1;
     }
 
+    let subdomains = subdomains
+        .iter()
+        .zip(&subdomain_errors)
+        .map(|(&(lo, hi), e)| SubdomainResult {
+            lo,
+            hi,
+            i8f8: ErrorStats::from_errors(&e.i8f8),
+            i16f16: ErrorStats::from_errors(&e.i16f16),
+            i32f32: ErrorStats::from_errors(&e.i32f32),
+            i64f64: ErrorStats::from_errors(&e.i64f64),
+        })
+        .collect();
+
     FunctionResult {
         name: func.name().to_string(),
-        i16f16: ErrorStats::from_errors(&i16f16_errors),
-        i32f32: ErrorStats::from_errors(&i32f32_errors),
+        i8f8: ErrorStats::from_errors(&errors.i8f8),
+        i16f16: ErrorStats::from_errors(&errors.i16f16),
+        i32f32: ErrorStats::from_errors(&errors.i32f32),
+        i64f64: ErrorStats::from_errors(&errors.i64f64),
+        libm_f32: ErrorStats::from_errors(&libm_f32_errors),
+        subdomains,
+        saturation_rate: saturation.rates(),
         samples_tested: tested,
     }
 }
 
-fn try_from_f64<T: Fixed>(x: f64) -> Option<T> {
-    let max: f64 = T::MAX.to_num();
-    let min: f64 = T::MIN.to_num();
-    if x > max || x < min || !x.is_finite() {
-        return None;
-    }
-    Some(T::from_num(x))
-}
-
 pub type FunctionRegistry = Vec<Box<dyn TestedFunction>>;
 
 pub fn build_registry() -> FunctionRegistry {
@@ -121,3 +359,35 @@ pub fn build_registry() -> FunctionRegistry {
     reg.extend(functions::algebraic::register());
     reg
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_summarizes_both_types() {
+        let mut i16f16 = ErrorStats::empty();
+        i16f16.rel_mean = 1.5e-5;
+        i16f16.rel_max = 3.0e-4;
+        let mut i32f32 = ErrorStats::empty();
+        i32f32.rel_mean = 2.25e-10;
+        i32f32.rel_max = 4.0e-9;
+        let result = FunctionResult {
+            name: "sin".to_string(),
+            i8f8: ErrorStats::empty(),
+            i16f16,
+            i32f32,
+            i64f64: ErrorStats::empty(),
+            libm_f32: ErrorStats::empty(),
+            subdomains: Vec::new(),
+            saturation_rate: SaturationRate::default(),
+            samples_tested: 1000,
+        };
+        let line = result.to_string();
+
+        assert!(line.starts_with("sin: "), "{line}");
+        assert!(line.contains("I16F16 rel_mean=1.50e-5"), "{line}");
+        assert!(line.contains("I32F32 rel_mean=2.25e-10"), "{line}");
+        assert!(line.ends_with("(1000 samples)"), "{line}");
+    }
+}
@@ -2,12 +2,21 @@
 //!
 //! Run with: cargo run --release
 //! Compare: cargo run --release -- --baseline path/to/baseline.json
+//! CSV export: cargo run --release -- --csv path/to/report.csv
+//! libm comparison: cargo run --release --features libm
+//! Histograms: cargo run --release -- --histogram
+//! Saturation rates: cargo run --release -- --saturation
+//! Per-function summaries as they finish: cargo run --release -- --verbose
+//! Sampling: cargo run --release -- [--quick] [--grid N] [--random N] [--boundary N] [--seed N]
+//! Per-function tolerances: cargo run --release -- --baseline path --tolerances path/to/tolerances.json
+//! Reference precision: cargo run --release -- --ref-bits N (default 256; 64 is enough below I64F64)
 
 use accuracy_bench::{
-    build_registry, readme, report::Report, sampling::SampleStrategy, test_function,
+    REFERENCE_PRECISION, build_registry, readme, reference_precision_warning, report::Report,
+    sampling::SampleStrategy, test_function_with_precision, tolerance::Tolerances,
 };
 use rayon::prelude::*;
-use std::{env, fs, path::Path, process};
+use std::{env, fs, path::Path, process, str::FromStr};
 
 const README_PATH: &str = "../../README.md";
 
@@ -18,10 +27,36 @@ fn main() {
         .position(|a| a == "--baseline")
         .and_then(|i| args.get(i + 1))
         .map(String::as_str);
+    let csv_path = args
+        .iter()
+        .position(|a| a == "--csv")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str);
+    let tolerances_path = args
+        .iter()
+        .position(|a| a == "--tolerances")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str);
+
+    let base = if args.iter().any(|a| a == "--quick") {
+        SampleStrategy::quick()
+    } else {
+        SampleStrategy::thorough()
+    };
+    let strategy = SampleStrategy::new(
+        parse_flag(&args, "--grid").unwrap_or(base.grid_points),
+        parse_flag(&args, "--random").unwrap_or(base.random_points),
+        parse_flag(&args, "--boundary").unwrap_or(base.boundary_points),
+        parse_flag(&args, "--seed").unwrap_or(base.seed()),
+    );
 
-    let strategy = SampleStrategy::thorough();
+    let reference_bits = parse_flag(&args, "--ref-bits").unwrap_or(REFERENCE_PRECISION);
+    let verbose = args.iter().any(|a| a == "--verbose");
 
     eprintln!("--- fixed_analytics accuracy benchmark ---");
+    if let Some(warning) = reference_precision_warning(reference_bits) {
+        eprintln!("Warning: {warning}");
+    }
     eprintln!(
         "Points per function: ~{}",
         strategy.grid_points + strategy.random_points + strategy.boundary_points * 2
@@ -34,7 +69,11 @@ fn main() {
         .par_iter()
         .map(|f| {
             eprintln!("  {}", f.name());
-            test_function(f.as_ref(), &strategy)
+            let result = test_function_with_precision(f.as_ref(), &strategy, reference_bits);
+            if verbose {
+                eprintln!("  {result}");
+            }
+            result
         })
         .collect();
 
@@ -46,6 +85,11 @@ fn main() {
     fs::write(&json_path, report.to_json()).expect("Failed to write report");
     eprintln!("Report saved: {json_path}");
 
+    if let Some(csv_path) = csv_path {
+        fs::write(csv_path, report.to_csv()).expect("Failed to write CSV report");
+        eprintln!("CSV saved: {csv_path}");
+    }
+
     // Determine README path (handle running from different directories)
     let readme_path = find_readme_path();
 
@@ -68,7 +112,15 @@ fn main() {
         }
 
         // Compare to baseline
-        let baseline_passed = compare_and_report(&report, baseline_path);
+        let tolerances = match tolerances_path.map(load_tolerances) {
+            Some(Ok(t)) => t,
+            Some(Err(e)) => {
+                eprintln!("Failed to load tolerances: {e}");
+                process::exit(2);
+            }
+            None => Tolerances::default(),
+        };
+        let baseline_passed = compare_and_report(&report, baseline_path, &tolerances);
         if !baseline_passed {
             all_passed = false;
         }
@@ -85,10 +137,31 @@ fn main() {
         }
 
         report.print_table();
+        if args.iter().any(|a| a == "--histogram") {
+            report.print_histogram();
+        }
+        if args.iter().any(|a| a == "--saturation") {
+            report.print_saturation();
+        }
     }
 }
 
 /// Find the README.md file, checking multiple possible locations.
+/// Parse the value following `flag`, exiting with a message if it is malformed.
+fn parse_flag<T: FromStr>(args: &[String], flag: &str) -> Option<T> {
+    let value = args
+        .iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))?;
+    match value.parse() {
+        Ok(v) => Some(v),
+        Err(_) => {
+            eprintln!("Invalid value for {flag}: {value}");
+            process::exit(2);
+        }
+    }
+}
+
 fn find_readme_path() -> Option<String> {
     let candidates = [
         README_PATH,
@@ -110,7 +183,12 @@ fn find_readme_path() -> Option<String> {
     None
 }
 
-fn compare_and_report(current: &Report, baseline_path: &str) -> bool {
+fn load_tolerances(path: &str) -> Result<Tolerances, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    Tolerances::from_json(&json).map_err(|e| format!("{path}: {e}"))
+}
+
+fn compare_and_report(current: &Report, baseline_path: &str, tolerances: &Tolerances) -> bool {
     let baseline_json = match fs::read_to_string(baseline_path) {
         Ok(s) => s,
         Err(e) => {
@@ -127,6 +205,11 @@ fn compare_and_report(current: &Report, baseline_path: &str) -> bool {
         }
     };
 
+    if let Err(e) = current.check_comparable(&baseline) {
+        eprintln!("Cannot compare against baseline: {e}");
+        return false;
+    }
+
     println!("\n================================================================================");
     println!("  ACCURACY COMPARISON");
     println!("================================================================================\n");
@@ -155,8 +238,11 @@ fn compare_and_report(current: &Report, baseline_path: &str) -> bool {
         };
 
         // Check I16F16
-        let (passed_16, status_16) =
-            check_regression(baseline_fn.i16f16.rel_mean, current_fn.i16f16.rel_mean);
+        let (passed_16, status_16) = tolerances.check(
+            &current_fn.name,
+            baseline_fn.i16f16.rel_mean,
+            current_fn.i16f16.rel_mean,
+        );
         if !passed_16 {
             all_passed = false;
         }
@@ -172,8 +258,11 @@ fn compare_and_report(current: &Report, baseline_path: &str) -> bool {
         );
 
         // Check I32F32
-        let (passed_32, status_32) =
-            check_regression(baseline_fn.i32f32.rel_mean, current_fn.i32f32.rel_mean);
+        let (passed_32, status_32) = tolerances.check(
+            &current_fn.name,
+            baseline_fn.i32f32.rel_mean,
+            current_fn.i32f32.rel_mean,
+        );
         if !passed_32 {
             all_passed = false;
         }
@@ -199,15 +288,3 @@ fn compare_and_report(current: &Report, baseline_path: &str) -> bool {
 
     all_passed
 }
-
-fn check_regression(baseline: f64, current: f64) -> (bool, &'static str) {
-    // Allow 0.1% tolerance to avoid floating-point noise triggering false regressions
-    let tolerance = baseline * 0.001;
-    if current > baseline + tolerance {
-        (false, "REGRESS")
-    } else if current < baseline - tolerance {
-        (true, "IMPROVE")
-    } else {
-        (true, "SAME")
-    }
-}
@@ -1,14 +1,27 @@
 //! Error metrics and statistical analysis.
 
+use rug::Float;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy)]
 pub struct ErrorMeasurement {
+    /// The input that produced this error.
+    pub input: f64,
     pub absolute: f64,
     pub relative: Option<f64>,
+    /// Distance in units of the last place between the computed value and
+    /// the representable value nearest the reference.
+    pub ulp: f64,
 }
 
-pub fn compute_error(computed: f64, reference: f64) -> Option<ErrorMeasurement> {
+/// Measure the error of `computed` against `reference` at `input` for a type
+/// with `frac_bits` fractional bits.
+pub fn compute_error(
+    input: f64,
+    computed: f64,
+    reference: f64,
+    frac_bits: u32,
+) -> Option<ErrorMeasurement> {
     if !computed.is_finite() || !reference.is_finite() {
         return None;
     }
@@ -18,7 +31,49 @@ pub fn compute_error(computed: f64, reference: f64) -> Option<ErrorMeasurement>
     } else {
         None
     };
-    Some(ErrorMeasurement { absolute, relative })
+    // Raw bits of the computed value, and of the reference rounded to the
+    // nearest representable value.
+    let scale = 2f64.powi(frac_bits as i32);
+    let computed_bits = computed * scale;
+    let reference_bits = (reference * scale).round();
+    let ulp = (computed_bits - reference_bits).abs();
+    Some(ErrorMeasurement {
+        input,
+        absolute,
+        relative,
+        ulp,
+    })
+}
+
+/// [`compute_error`] with the computed value and reference kept in MPFR.
+///
+/// Needed for types with more significant bits than f64's 53: rounding both
+/// values to f64 first would floor the measured relative error at ~2^-53.
+pub fn compute_error_exact(
+    input: f64,
+    computed: &Float,
+    reference: &Float,
+    frac_bits: u32,
+) -> Option<ErrorMeasurement> {
+    if !computed.is_finite() || !reference.is_finite() {
+        return None;
+    }
+    let prec = computed.prec().max(reference.prec());
+    let diff = Float::with_val(prec, computed - reference).abs();
+    let relative = if reference.clone().abs() > 1e-15 {
+        Some(Float::with_val(prec, &diff / reference).abs().to_f64())
+    } else {
+        None
+    };
+    let computed_bits = Float::with_val(prec, computed) << frac_bits;
+    let reference_bits = (Float::with_val(prec, reference) << frac_bits).round();
+    let ulp = (computed_bits - reference_bits).abs().to_f64();
+    Some(ErrorMeasurement {
+        input,
+        absolute: diff.to_f64(),
+        relative,
+        ulp,
+    })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +89,21 @@ pub struct ErrorStats {
     pub rel_p50: f64,
     pub rel_p95: f64,
     pub rel_p99: f64,
+    #[serde(default)]
+    pub ulp_max: f64,
+    #[serde(default)]
+    pub ulp_mean: f64,
+    #[serde(default)]
+    pub ulp_p95: f64,
+    /// Relative errors in decade buckets, as (bucket upper bound, count).
+    /// Input at which the absolute error peaks.
+    #[serde(default)]
+    pub worst_abs_input: f64,
+    /// Input at which the relative error peaks.
+    #[serde(default)]
+    pub worst_rel_input: f64,
+    #[serde(default)]
+    pub rel_histogram: Vec<(f64, usize)>,
 }
 
 impl ErrorStats {
@@ -54,6 +124,25 @@ impl ErrorStats {
         let abs_p95 = percentile(&abs_vals, 0.95);
         let abs_p99 = percentile(&abs_vals, 0.99);
 
+        let mut ulp_vals: Vec<f64> = errors.iter().map(|e| e.ulp).collect();
+        ulp_vals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let ulp_max = *ulp_vals.last().unwrap_or(&0.0);
+        let ulp_mean = mean(&ulp_vals);
+        let ulp_p95 = percentile(&ulp_vals, 0.95);
+
+        let rel_histogram = log_histogram(&rel_vals);
+
+        let worst_abs_input = errors
+            .iter()
+            .max_by(|a, b| a.absolute.total_cmp(&b.absolute))
+            .map_or(0.0, |e| e.input);
+        let worst_rel_input = errors
+            .iter()
+            .filter_map(|e| e.relative.map(|r| (r, e.input)))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or(0.0, |(_, input)| input);
+
         let (rel_max, rel_mean, rel_p50, rel_p95, rel_p99) = if rel_vals.is_empty() {
             (0.0, 0.0, 0.0, 0.0, 0.0)
         } else {
@@ -78,6 +167,12 @@ impl ErrorStats {
             rel_p50,
             rel_p95,
             rel_p99,
+            ulp_max,
+            ulp_mean,
+            ulp_p95,
+            worst_abs_input,
+            worst_rel_input,
+            rel_histogram,
         }
     }
 
@@ -94,22 +189,533 @@ impl ErrorStats {
             rel_p50: 0.0,
             rel_p95: 0.0,
             rel_p99: 0.0,
+            ulp_max: 0.0,
+            ulp_mean: 0.0,
+            ulp_p95: 0.0,
+            worst_abs_input: 0.0,
+            worst_rel_input: 0.0,
+            rel_histogram: Vec::new(),
+        }
+    }
+}
+
+/// Streaming counterpart of [`ErrorStats::from_errors`] that keeps O(1) state
+/// instead of every measurement.
+///
+/// Maxima, worst inputs and the histogram are exact. Means and variances use
+/// Welford's update; percentiles are P² estimates, exact for up to five
+/// measurements and close to the sorted result for large smooth samples.
+/// Prefer [`ErrorStats::from_errors`] when the errors fit in memory.
+#[derive(Debug, Clone)]
+pub struct ErrorAccumulator {
+    abs: RunningMoments,
+    rel: RunningMoments,
+    ulp: RunningMoments,
+    abs_p50: P2Quantile,
+    abs_p95: P2Quantile,
+    abs_p99: P2Quantile,
+    rel_p50: P2Quantile,
+    rel_p95: P2Quantile,
+    rel_p99: P2Quantile,
+    ulp_p95: P2Quantile,
+    worst_abs_input: f64,
+    worst_rel_input: f64,
+    rel_decades: DecadeCounts,
+}
+
+impl Default for ErrorAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorAccumulator {
+    pub fn new() -> Self {
+        Self {
+            abs: RunningMoments::default(),
+            rel: RunningMoments::default(),
+            ulp: RunningMoments::default(),
+            abs_p50: P2Quantile::new(0.50),
+            abs_p95: P2Quantile::new(0.95),
+            abs_p99: P2Quantile::new(0.99),
+            rel_p50: P2Quantile::new(0.50),
+            rel_p95: P2Quantile::new(0.95),
+            rel_p99: P2Quantile::new(0.99),
+            ulp_p95: P2Quantile::new(0.95),
+            worst_abs_input: 0.0,
+            worst_rel_input: 0.0,
+            rel_decades: DecadeCounts::default(),
+        }
+    }
+
+    pub fn push(&mut self, error: &ErrorMeasurement) {
+        if self.abs.count == 0 || error.absolute >= self.abs.max {
+            self.worst_abs_input = error.input;
+        }
+        self.abs.push(error.absolute);
+        self.abs_p50.push(error.absolute);
+        self.abs_p95.push(error.absolute);
+        self.abs_p99.push(error.absolute);
+
+        self.ulp.push(error.ulp);
+        self.ulp_p95.push(error.ulp);
+
+        if let Some(relative) = error.relative {
+            if self.rel.count == 0 || relative >= self.rel.max {
+                self.worst_rel_input = error.input;
+            }
+            self.rel.push(relative);
+            self.rel_p50.push(relative);
+            self.rel_p95.push(relative);
+            self.rel_p99.push(relative);
+            self.rel_decades.push(relative);
+        }
+    }
+
+    /// Sample variance of the absolute errors.
+    pub fn abs_variance(&self) -> f64 {
+        self.abs.variance()
+    }
+
+    /// Sample variance of the relative errors.
+    pub fn rel_variance(&self) -> f64 {
+        self.rel.variance()
+    }
+
+    /// Sample variance of the ULP errors.
+    pub fn ulp_variance(&self) -> f64 {
+        self.ulp.variance()
+    }
+
+    pub fn finish(&self) -> ErrorStats {
+        if self.abs.count == 0 {
+            return ErrorStats::empty();
+        }
+        ErrorStats {
+            count: self.abs.count,
+            abs_max: self.abs.max,
+            abs_mean: self.abs.mean,
+            abs_p50: self.abs_p50.estimate(),
+            abs_p95: self.abs_p95.estimate(),
+            abs_p99: self.abs_p99.estimate(),
+            rel_max: self.rel.max,
+            rel_mean: self.rel.mean,
+            rel_p50: self.rel_p50.estimate(),
+            rel_p95: self.rel_p95.estimate(),
+            rel_p99: self.rel_p99.estimate(),
+            ulp_max: self.ulp.max,
+            ulp_mean: self.ulp.mean,
+            ulp_p95: self.ulp_p95.estimate(),
+            worst_abs_input: self.worst_abs_input,
+            worst_rel_input: self.worst_rel_input,
+            rel_histogram: self.rel_decades.histogram(self.rel.max),
+        }
+    }
+}
+
+/// Count, maximum, mean and sum of squared deviations by Welford's update.
+#[derive(Debug, Clone, Copy, Default)]
+struct RunningMoments {
+    count: usize,
+    max: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningMoments {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.max = if self.count == 1 {
+            value
+        } else {
+            self.max.max(value)
+        };
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+}
+
+/// The P² quantile estimator of Jain and Chlamtac: five markers whose heights
+/// track the minimum, `p/2`, `p`, `(1+p)/2` quantiles and maximum, adjusted
+/// by piecewise-parabolic interpolation as values arrive.
+#[derive(Debug, Clone)]
+struct P2Quantile {
+    p: f64,
+    count: usize,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+}
+
+impl P2Quantile {
+    fn new(p: f64) -> Self {
+        Self {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+        }
+    }
+
+    fn push(&mut self, value: f64) {
+        if self.count < 5 {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        let q = &mut self.heights;
+        let cell = if value < q[0] {
+            q[0] = value;
+            0
+        } else if value >= q[4] {
+            q[4] = value;
+            3
+        } else {
+            (0..4).rfind(|&i| q[i] <= value).unwrap_or(0)
+        };
+        for n in &mut self.positions[cell + 1..] {
+            *n += 1.0;
+        }
+        let p = self.p;
+        let increments = [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0];
+        for (d, inc) in self.desired.iter_mut().zip(increments) {
+            *d += inc;
         }
+
+        let n = &mut self.positions;
+        for i in 1..4 {
+            let offset = self.desired[i] - n[i];
+            if (offset >= 1.0 && n[i + 1] - n[i] > 1.0)
+                || (offset <= -1.0 && n[i - 1] - n[i] < -1.0)
+            {
+                let d = offset.signum();
+                let parabolic = q[i]
+                    + d / (n[i + 1] - n[i - 1])
+                        * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                            + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+                q[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                    parabolic
+                } else {
+                    let j = if d > 0.0 { i + 1 } else { i - 1 };
+                    q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+                };
+                n[i] += d;
+            }
+        }
+    }
+
+    fn estimate(&self) -> f64 {
+        if self.count >= 5 {
+            return self.heights[2];
+        }
+        let mut seen = self.heights;
+        let seen = &mut seen[..self.count];
+        seen.sort_by(f64::total_cmp);
+        percentile(seen, self.p)
     }
 }
 
+/// Relative errors counted by decade for [`log_histogram`]'s buckets.
+#[derive(Debug, Clone, Default)]
+struct DecadeCounts {
+    zeros: usize,
+    min_positive: Option<f64>,
+    /// Count per `k` for values in `(10^(k-1), 10^k]`.
+    by_decade: std::collections::BTreeMap<i32, usize>,
+}
+
+impl DecadeCounts {
+    fn push(&mut self, value: f64) {
+        if value <= 0.0 {
+            self.zeros += 1;
+            return;
+        }
+        self.min_positive = Some(self.min_positive.map_or(value, |m| m.min(value)));
+        let mut k = value.log10().ceil() as i32;
+        // log10 can land a hair off an exact power of ten.
+        if 10f64.powi(k - 1) >= value {
+            k -= 1;
+        } else if 10f64.powi(k) < value {
+            k += 1;
+        }
+        *self.by_decade.entry(k).or_default() += 1;
+    }
+
+    /// The buckets [`log_histogram`] gives for the same values.
+    fn histogram(&self, max: f64) -> Vec<(f64, usize)> {
+        let Some(min_positive) = self.min_positive else {
+            return if self.zeros == 0 {
+                Vec::new()
+            } else {
+                vec![(0.0, self.zeros)]
+            };
+        };
+        let hi = max.log10().ceil() as i32;
+        let lo = (min_positive.log10().floor() as i32).min(hi - 1);
+        (lo + 1..=hi)
+            .map(|k| {
+                let in_decade = if k == lo + 1 {
+                    self.zeros + self.by_decade.range(..=k).map(|(_, n)| n).sum::<usize>()
+                } else {
+                    self.by_decade.get(&k).copied().unwrap_or(0)
+                };
+                (10f64.powi(k), in_decade)
+            })
+            .collect()
+    }
+}
+
+/// Mean by Neumaier compensated summation, so the result does not depend on
+/// how rounding errors accumulate over tens of thousands of terms.
 fn mean(vals: &[f64]) -> f64 {
     if vals.is_empty() {
-        0.0
-    } else {
-        vals.iter().sum::<f64>() / vals.len() as f64
+        return 0.0;
     }
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for &v in vals {
+        let t = sum + v;
+        compensation += if sum.abs() >= v.abs() {
+            (sum - t) + v
+        } else {
+            (v - t) + sum
+        };
+        sum = t;
+    }
+    (sum + compensation) / vals.len() as f64
 }
 
+/// Percentile `p` in `[0, 1]`, interpolating linearly between neighbouring
+/// ranks.
 fn percentile(sorted: &[f64], p: f64) -> f64 {
-    if sorted.is_empty() {
+    let Some(&last) = sorted.last() else {
         return 0.0;
+    };
+    let rank = (sorted.len() - 1) as f64 * p.clamp(0.0, 1.0);
+    let lo = rank.floor() as usize;
+    match sorted.get(lo + 1) {
+        Some(&next) => sorted[lo] + (rank - lo as f64) * (next - sorted[lo]),
+        None => last,
+    }
+}
+
+/// Bucket sorted values by decade. The first bucket also holds zeros.
+fn log_histogram(sorted: &[f64]) -> Vec<(f64, usize)> {
+    let Some(&max) = sorted.last() else {
+        return Vec::new();
+    };
+    if max <= 0.0 {
+        return vec![(0.0, sorted.len())];
+    }
+    let min_positive = sorted.iter().copied().find(|&v| v > 0.0).unwrap_or(max);
+
+    let hi = max.log10().ceil() as i32;
+    let lo = (min_positive.log10().floor() as i32).min(hi - 1);
+
+    let mut buckets = Vec::with_capacity((hi - lo) as usize);
+    let mut counted = 0;
+    for k in lo + 1..=hi {
+        let upper = 10f64.powi(k);
+        let below = sorted.partition_point(|&v| v <= upper);
+        buckets.push((upper, below - counted));
+        counted = below;
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ulp_one_bit_difference() {
+        let lsb = 2f64.powi(-16);
+        let err = compute_error(0.0, 1.0 + lsb, 1.0, 16).unwrap();
+        assert_eq!(err.ulp, 1.0);
+
+        let err = compute_error(0.0, 1.0, 1.0, 16).unwrap();
+        assert_eq!(err.ulp, 0.0);
+    }
+
+    #[test]
+    fn test_ulp_rounds_reference_to_nearest() {
+        // Reference sits 0.4 LSB above the computed value: nearest is exact.
+        let lsb = 2f64.powi(-8);
+        let err = compute_error(0.0, 0.5, 0.5 + 0.4 * lsb, 8).unwrap();
+        assert_eq!(err.ulp, 0.0);
+        // Three bits away in the other direction.
+        let err = compute_error(0.0, 0.5 - 3.0 * lsb, 0.5, 8).unwrap();
+        assert_eq!(err.ulp, 3.0);
+    }
+
+    #[test]
+    fn test_exact_error_resolves_below_f64() {
+        // 1 + 2^-60 is 1.0 in f64, so the f64 path sees no error at all.
+        let reference = Float::with_val(256, 1);
+        let computed = Float::with_val(256, 1) + (Float::with_val(256, 1) >> 60u32);
+        let err = compute_error_exact(0.0, &computed, &reference, 64).unwrap();
+        assert_eq!(err.ulp, 16.0);
+        assert_eq!(err.relative, Some(2f64.powi(-60)));
+
+        let f64_err = compute_error(0.0, computed.to_f64(), 1.0, 64).unwrap();
+        assert_eq!(f64_err.ulp, 0.0);
+    }
+
+    #[test]
+    fn test_worst_inputs_match_largest_errors() {
+        let errors = [
+            // Largest absolute error, but a large reference keeps it relatively small.
+            compute_error(99.7, 50.01, 50.0, 16).unwrap(),
+            // Largest relative error.
+            compute_error(3.1, 0.002, 0.001, 16).unwrap(),
+            compute_error(0.5, 0.5, 0.5, 16).unwrap(),
+        ];
+        let stats = ErrorStats::from_errors(&errors);
+        assert_eq!(stats.worst_abs_input, 99.7);
+        assert_eq!(stats.worst_rel_input, 3.1);
+    }
+
+    #[test]
+    fn test_mean_is_compensated() {
+        assert_eq!(mean(&[1.0, 2.0, 3.0, 4.0]), 2.5);
+        // Naive summation loses the 1.0 entirely.
+        assert_eq!(mean(&[1e16, 1.0, -1e16]), 1.0 / 3.0);
+        let tenths = vec![0.1; 57_000];
+        assert_eq!(mean(&tenths), 0.1);
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        let vals: Vec<f64> = (1..=10).map(f64::from).collect();
+        // Rank 9 × 0.95 = 8.55 lies between 9 and 10.
+        assert!((percentile(&vals, 0.95) - 9.55).abs() < 1e-12);
+        assert_eq!(percentile(&vals, 0.5), 5.5);
+        assert_eq!(percentile(&vals, 0.0), 1.0);
+        assert_eq!(percentile(&vals, 1.0), 10.0);
+        assert_eq!(percentile(&[4.0], 0.95), 4.0);
+        assert_eq!(percentile(&[], 0.95), 0.0);
+    }
+
+    #[test]
+    fn test_histogram_counts_sum_to_total() {
+        let errors: Vec<ErrorMeasurement> = [1e-9, 3e-9, 2e-6, 5e-6, 7e-6, 0.5, 0.0]
+            .iter()
+            .map(|&rel| compute_error(0.0, 1.0 + rel, 1.0, 16).unwrap())
+            .collect();
+        let stats = ErrorStats::from_errors(&errors);
+
+        let total: usize = stats.rel_histogram.iter().map(|&(_, n)| n).sum();
+        assert_eq!(total, errors.len());
+        // Decades from 1e-8 up to 1e0.
+        assert_eq!(stats.rel_histogram.len(), 9);
+        assert_eq!(stats.rel_histogram.first(), Some(&(1e-8, 3)));
+        assert_eq!(stats.rel_histogram.last(), Some(&(1.0, 1)));
+    }
+
+    /// Deterministic spread of measurements over several decades.
+    fn sample_errors(count: u32) -> Vec<ErrorMeasurement> {
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        (0..count)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let unit = (state >> 11) as f64 / (1u64 << 53) as f64;
+                let reference = 0.5 + unit;
+                let error = 1e-6 * unit * unit * unit;
+                compute_error(f64::from(i), reference + error, reference, 32).unwrap()
+            })
+            .collect()
+    }
+
+    fn accumulate(errors: &[ErrorMeasurement]) -> ErrorAccumulator {
+        let mut acc = ErrorAccumulator::new();
+        for error in errors {
+            acc.push(error);
+        }
+        acc
+    }
+
+    #[test]
+    fn test_streaming_mean_matches_exact() {
+        let errors = sample_errors(50_000);
+        let exact = ErrorStats::from_errors(&errors);
+        let acc = accumulate(&errors);
+        let streamed = acc.finish();
+
+        assert_eq!(streamed.count, exact.count);
+        assert!((streamed.abs_mean - exact.abs_mean).abs() <= 1e-12 * exact.abs_mean);
+        assert!((streamed.rel_mean - exact.rel_mean).abs() <= 1e-12 * exact.rel_mean);
+        assert!((streamed.ulp_mean - exact.ulp_mean).abs() <= 1e-12 * exact.ulp_mean);
+
+        let abs_vals: Vec<f64> = errors.iter().map(|e| e.absolute).collect();
+        let mean = mean(&abs_vals);
+        let two_pass =
+            abs_vals.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (abs_vals.len() - 1) as f64;
+        assert!((acc.abs_variance() - two_pass).abs() <= 1e-9 * two_pass);
+    }
+
+    #[test]
+    fn test_streaming_exact_fields_match() {
+        let errors = sample_errors(20_000);
+        let exact = ErrorStats::from_errors(&errors);
+        let streamed = accumulate(&errors).finish();
+
+        assert_eq!(streamed.abs_max, exact.abs_max);
+        assert_eq!(streamed.rel_max, exact.rel_max);
+        assert_eq!(streamed.ulp_max, exact.ulp_max);
+        assert_eq!(streamed.worst_abs_input, exact.worst_abs_input);
+        assert_eq!(streamed.worst_rel_input, exact.worst_rel_input);
+        assert_eq!(streamed.rel_histogram, exact.rel_histogram);
+    }
+
+    #[test]
+    fn test_streaming_percentiles_close_to_sorted() {
+        let errors = sample_errors(20_000);
+        let exact = ErrorStats::from_errors(&errors);
+        let streamed = accumulate(&errors).finish();
+
+        for (estimate, sorted) in [
+            (streamed.abs_p50, exact.abs_p50),
+            (streamed.abs_p95, exact.abs_p95),
+            (streamed.abs_p99, exact.abs_p99),
+            (streamed.rel_p95, exact.rel_p95),
+            (streamed.ulp_p95, exact.ulp_p95),
+        ] {
+            assert!(
+                (estimate - sorted).abs() <= 0.02 * sorted,
+                "P² gave {estimate}, sorted {sorted}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_small_sets_are_exact() {
+        let errors: Vec<ErrorMeasurement> = [1e-9, 3e-9, 2e-6, 0.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &rel)| compute_error(i as f64, 1.0 + rel, 1.0, 16).unwrap())
+            .collect();
+        let exact = ErrorStats::from_errors(&errors);
+        let streamed = accumulate(&errors).finish();
+        assert_eq!(streamed.rel_p50, exact.rel_p50);
+        assert_eq!(streamed.rel_p95, exact.rel_p95);
+        assert_eq!(streamed.rel_histogram, exact.rel_histogram);
+        assert_eq!(ErrorAccumulator::new().finish().count, 0);
     }
-    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
-    sorted[idx.min(sorted.len() - 1)]
 }
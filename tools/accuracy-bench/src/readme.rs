@@ -17,28 +17,30 @@ pub fn generate_accuracy_section(results: &[FunctionResult]) -> String {
     )
     .unwrap();
 
-    // Combined table with both I16F16 and I32F32
+    // Combined table: full statistics for I16F16 and I32F32, means for I8F8 and I64F64
     writeln!(
         out,
-        "| Function | I16F16 Mean | I16F16 Median | I16F16 P95 | I32F32 Mean | I32F32 Median | I32F32 P95 |"
+        "| Function | I16F16 Mean | I16F16 Median | I16F16 P95 | I32F32 Mean | I32F32 Median | I32F32 P95 | I8F8 Mean | I64F64 Mean |"
     )
     .unwrap();
     writeln!(
         out,
-        "|----------|-------------|---------------|------------|-------------|---------------|------------|"
+        "|----------|-------------|---------------|------------|-------------|---------------|------------|-----------|-------------|"
     )
     .unwrap();
     for r in results {
         writeln!(
             out,
-            "| {} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} |",
+            "| {} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} | {:.2e} |",
             r.name,
             r.i16f16.rel_mean,
             r.i16f16.rel_p50,
             r.i16f16.rel_p95,
             r.i32f32.rel_mean,
             r.i32f32.rel_p50,
-            r.i32f32.rel_p95
+            r.i32f32.rel_p95,
+            r.i8f8.rel_mean,
+            r.i64f64.rel_mean
         )
         .unwrap();
     }
@@ -164,7 +166,7 @@ fn parse_table_values(section: &str) -> Result<std::collections::HashMap<String,
             continue;
         }
 
-        // Parse table row: | func | i16f16_mean | i16f16_median | i16f16_p95 | i32f32_mean | i32f32_median | i32f32_p95 |
+        // Parse table row: | func | i16f16_mean | i16f16_median | i16f16_p95 | i32f32_mean | i32f32_median | i32f32_p95 | i8f8_mean | i64f64_mean |
         let parts: Vec<&str> = line
             .split('|')
             .map(|s| s.trim())
@@ -192,6 +194,16 @@ fn parse_table_values(section: &str) -> Result<std::collections::HashMap<String,
                 values.insert(format!("{}/I32F32/p95", func), v);
             }
         }
+
+        if parts.len() >= 9 {
+            let func = parts[0];
+            if let Ok(v) = parts[7].parse::<f64>() {
+                values.insert(format!("{}/I8F8/mean", func), v);
+            }
+            if let Ok(v) = parts[8].parse::<f64>() {
+                values.insert(format!("{}/I64F64/mean", func), v);
+            }
+        }
     }
 
     Ok(values)
@@ -218,5 +230,15 @@ Relative error statistics measured against MPFR reference implementations. Accur
         assert!((values["sin/I16F16/mean"] - 7.30e-5).abs() < 1e-10);
         assert!((values["sin/I32F32/mean"] - 1.41e-9).abs() < 1e-14);
         assert!((values["cos/I16F16/p95"] - 2.03e-4).abs() < 1e-10);
+        assert!(!values.contains_key("sin/I8F8/mean"));
+    }
+
+    #[test]
+    fn test_parse_extended_precision_columns() {
+        let section = "| sin | 7.30e-5 | 6.05e-5 | 1.80e-4 | 1.41e-9 | 1.16e-9 | 3.49e-9 | 2.10e-2 | 3.30e-18 |";
+        let values = parse_table_values(section).unwrap();
+
+        assert!((values["sin/I8F8/mean"] - 2.10e-2).abs() < 1e-7);
+        assert!((values["sin/I64F64/mean"] - 3.30e-18).abs() < 1e-23);
     }
 }
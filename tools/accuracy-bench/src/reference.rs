@@ -1,6 +1,7 @@
-//! MPFR reference implementations.
+//! MPFR reference implementations, plus optional `libm` (f32) comparisons.
+//!
+//! Each result carries the precision of its input.
 
-use crate::REFERENCE_PRECISION;
 use rug::Float;
 
 pub mod circular {
@@ -46,10 +47,10 @@ pub mod hyperbolic {
         x.clone().atanh()
     }
     pub fn coth(x: &Float) -> Float {
-        Float::with_val(REFERENCE_PRECISION, 1.0) / x.clone().tanh()
+        Float::with_val(x.prec(), 1.0) / x.clone().tanh()
     }
     pub fn acoth(x: &Float) -> Float {
-        (Float::with_val(REFERENCE_PRECISION, 1.0) / x).atanh()
+        (Float::with_val(x.prec(), 1.0) / x).atanh()
     }
 }
 
@@ -78,3 +79,69 @@ pub mod algebraic {
         x.clone().sqrt()
     }
 }
+
+/// Single-precision `libm` equivalents, for comparing fixed-point error
+/// against what an f32 implementation would achieve on the same inputs.
+#[cfg(feature = "libm")]
+pub mod libm_f32 {
+    pub fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+    pub fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+    pub fn tan(x: f32) -> f32 {
+        libm::tanf(x)
+    }
+    pub fn asin(x: f32) -> f32 {
+        libm::asinf(x)
+    }
+    pub fn acos(x: f32) -> f32 {
+        libm::acosf(x)
+    }
+    pub fn atan(x: f32) -> f32 {
+        libm::atanf(x)
+    }
+    pub fn sinh(x: f32) -> f32 {
+        libm::sinhf(x)
+    }
+    pub fn cosh(x: f32) -> f32 {
+        libm::coshf(x)
+    }
+    pub fn tanh(x: f32) -> f32 {
+        libm::tanhf(x)
+    }
+    pub fn asinh(x: f32) -> f32 {
+        libm::asinhf(x)
+    }
+    pub fn acosh(x: f32) -> f32 {
+        libm::acoshf(x)
+    }
+    pub fn atanh(x: f32) -> f32 {
+        libm::atanhf(x)
+    }
+    pub fn coth(x: f32) -> f32 {
+        1.0 / libm::tanhf(x)
+    }
+    pub fn acoth(x: f32) -> f32 {
+        libm::atanhf(1.0 / x)
+    }
+    pub fn exp(x: f32) -> f32 {
+        libm::expf(x)
+    }
+    pub fn ln(x: f32) -> f32 {
+        libm::logf(x)
+    }
+    pub fn log2(x: f32) -> f32 {
+        libm::log2f(x)
+    }
+    pub fn log10(x: f32) -> f32 {
+        libm::log10f(x)
+    }
+    pub fn pow2(x: f32) -> f32 {
+        libm::exp2f(x)
+    }
+    pub fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+}
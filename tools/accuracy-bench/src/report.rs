@@ -5,8 +5,32 @@ use comfy_table::{ContentArrangement, Table};
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Version of the report JSON layout. Bump it whenever a change would make
+/// an older report mis-parse or compare meaninglessly.
+///
+/// 1: reports without a version field. 2: adds `schema_version` and
+/// `crate_version`.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Version of `fixed_analytics` being measured, read from its manifest.
+fn crate_version() -> String {
+    include_str!("../../../Cargo.toml")
+        .lines()
+        .find_map(|line| line.strip_prefix("version = "))
+        .map(|v| v.trim_matches('"').to_string())
+        .unwrap_or_default()
+}
+
+fn unversioned_schema() -> u32 {
+    1
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Report {
+    #[serde(default = "unversioned_schema")]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub crate_version: String,
     pub timestamp: u64,
     pub results: Vec<FunctionResult>,
 }
@@ -17,7 +41,24 @@ impl Report {
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_secs())
             .unwrap_or(0);
-        Self { timestamp, results }
+        Self {
+            schema_version: SCHEMA_VERSION,
+            crate_version: crate_version(),
+            timestamp,
+            results,
+        }
+    }
+
+    /// Errors if `baseline` was written with a different report schema.
+    pub fn check_comparable(&self, baseline: &Report) -> Result<(), String> {
+        if baseline.schema_version == self.schema_version {
+            Ok(())
+        } else {
+            Err(format!(
+                "baseline uses report schema v{} but this report is v{}; regenerate the baseline",
+                baseline.schema_version, self.schema_version
+            ))
+        }
     }
 
     pub fn print_table(&self) {
@@ -33,27 +74,236 @@ impl Report {
         table.set_content_arrangement(ContentArrangement::Dynamic);
         table.set_header(vec![
             "Function",
+            "I8F8 rel_mean",
             "I16F16 rel_mean",
             "I16F16 rel_max",
             "I32F32 rel_mean",
             "I32F32 rel_max",
+            "I64F64 rel_mean",
+            "libm f32 rel_mean",
             "Samples",
         ]);
 
         for r in &self.results {
             table.add_row(vec![
                 r.name.clone(),
+                format!("{:.6e}", r.i8f8.rel_mean),
                 format!("{:.6e}", r.i16f16.rel_mean),
                 format!("{:.6e}", r.i16f16.rel_max),
                 format!("{:.6e}", r.i32f32.rel_mean),
                 format!("{:.6e}", r.i32f32.rel_max),
+                format!("{:.6e}", r.i64f64.rel_mean),
+                format!("{:.6e}", r.libm_f32.rel_mean),
                 r.samples_tested.to_string(),
             ]);
         }
         println!("{table}\n");
+
+        let mut breakdown = Table::new();
+        breakdown.set_content_arrangement(ContentArrangement::Dynamic);
+        breakdown.set_header(vec![
+            "Function",
+            "Subdomain",
+            "I8F8 rel_mean",
+            "I16F16 rel_mean",
+            "I32F32 rel_mean",
+            "I64F64 rel_mean",
+            "Samples",
+        ]);
+
+        for r in self.results.iter().filter(|r| r.subdomains.len() > 1) {
+            for sub in &r.subdomains {
+                breakdown.add_row(vec![
+                    r.name.clone(),
+                    format!("[{:.4}, {:.4}]", sub.lo, sub.hi),
+                    format!("{:.6e}", sub.i8f8.rel_mean),
+                    format!("{:.6e}", sub.i16f16.rel_mean),
+                    format!("{:.6e}", sub.i32f32.rel_mean),
+                    format!("{:.6e}", sub.i64f64.rel_mean),
+                    sub.i16f16.count.to_string(),
+                ]);
+            }
+        }
+        if !breakdown.is_empty() {
+            println!("  SUBDOMAIN BREAKDOWN\n");
+            println!("{breakdown}\n");
+        }
+    }
+
+    /// Print the fraction of samples that saturated, for functions where any
+    /// type did.
+    pub fn print_saturation(&self) {
+        let mut table = Table::new();
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+        table.set_header(vec!["Function", "I8F8", "I16F16", "I32F32", "I64F64"]);
+
+        for r in self.results.iter().filter(|r| r.saturation_rate.any()) {
+            let rate = &r.saturation_rate;
+            table.add_row(vec![
+                r.name.clone(),
+                format!("{:.2}%", rate.i8f8 * 100.0),
+                format!("{:.2}%", rate.i16f16 * 100.0),
+                format!("{:.2}%", rate.i32f32 * 100.0),
+                format!("{:.2}%", rate.i64f64 * 100.0),
+            ]);
+        }
+
+        println!("  SATURATION RATE\n");
+        if table.is_empty() {
+            println!("No samples saturated.\n");
+        } else {
+            println!("{table}\n");
+        }
+    }
+
+    /// Print each function's relative-error histogram as ASCII bars.
+    pub fn print_histogram(&self) {
+        const BAR_WIDTH: usize = 40;
+
+        println!("  RELATIVE ERROR HISTOGRAMS\n");
+        for r in &self.results {
+            for (ty, stats) in [("I16F16", &r.i16f16), ("I32F32", &r.i32f32)] {
+                if stats.rel_histogram.is_empty() {
+                    continue;
+                }
+                println!("{} ({ty})", r.name);
+                let peak = stats
+                    .rel_histogram
+                    .iter()
+                    .map(|&(_, n)| n)
+                    .max()
+                    .unwrap_or(1)
+                    .max(1);
+                for &(upper, count) in &stats.rel_histogram {
+                    let bar = "#".repeat(count * BAR_WIDTH / peak);
+                    println!("  <= {upper:8.0e} | {bar:<BAR_WIDTH$} {count}");
+                }
+                println!();
+            }
+        }
     }
 
     pub fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
     }
+
+    /// One row per function; per type: rel_mean, rel_max, abs_max, samples.
+    pub fn to_csv(&self) -> String {
+        const TYPES: [&str; 4] = ["i8f8", "i16f16", "i32f32", "i64f64"];
+
+        let mut header = vec!["function".to_string()];
+        for ty in TYPES {
+            for col in ["rel_mean", "rel_max", "abs_max", "samples"] {
+                header.push(format!("{ty}_{col}"));
+            }
+        }
+
+        let mut out = header.join(",");
+        out.push('\n');
+
+        for r in &self.results {
+            let mut row = vec![r.name.clone()];
+            for stats in [&r.i8f8, &r.i16f16, &r.i32f32, &r.i64f64] {
+                row.push(format!("{:e}", stats.rel_mean));
+                row.push(format!("{:e}", stats.rel_max));
+                row.push(format!("{:e}", stats.abs_max));
+                row.push(stats.count.to_string());
+            }
+            out.push_str(&row.join(","));
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SaturationRate, metrics::ErrorStats};
+
+    #[test]
+    fn test_json_contains_all_types() {
+        let report = Report::new(vec![FunctionResult {
+            name: "sin".to_string(),
+            i8f8: ErrorStats::empty(),
+            i16f16: ErrorStats::empty(),
+            i32f32: ErrorStats::empty(),
+            libm_f32: ErrorStats::empty(),
+            i64f64: ErrorStats::empty(),
+            subdomains: Vec::new(),
+            saturation_rate: SaturationRate::default(),
+            samples_tested: 0,
+        }]);
+        let json = report.to_json();
+
+        for field in ["\"i8f8\"", "\"i16f16\"", "\"i32f32\"", "\"i64f64\""] {
+            assert!(json.contains(field), "missing {field} in report JSON");
+        }
+    }
+
+    #[test]
+    fn test_csv_rows_match_header() {
+        let mut stats = ErrorStats::empty();
+        stats.count = 42;
+        stats.rel_mean = 1.5e-5;
+        let report = Report::new(vec![
+            FunctionResult {
+                name: "sin".to_string(),
+                i8f8: ErrorStats::empty(),
+                i16f16: stats.clone(),
+                i32f32: stats.clone(),
+                i64f64: ErrorStats::empty(),
+                libm_f32: ErrorStats::empty(),
+                subdomains: Vec::new(),
+                saturation_rate: SaturationRate::default(),
+                samples_tested: 42,
+            },
+            FunctionResult {
+                name: "cos".to_string(),
+                i8f8: ErrorStats::empty(),
+                i16f16: stats.clone(),
+                i32f32: stats,
+                i64f64: ErrorStats::empty(),
+                libm_f32: ErrorStats::empty(),
+                subdomains: Vec::new(),
+                saturation_rate: SaturationRate::default(),
+                samples_tested: 42,
+            },
+        ]);
+        let csv = report.to_csv();
+        let mut lines = csv.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(header.len(), 17);
+        assert_eq!(header[5], "i16f16_rel_mean");
+
+        let rows: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+        assert_eq!(rows.len(), 2);
+        for row in &rows {
+            assert_eq!(row.len(), header.len());
+        }
+        assert_eq!(rows[0][0], "sin");
+        assert_eq!(rows[0][5].parse::<f64>().unwrap(), 1.5e-5);
+        assert_eq!(rows[0][8], "42");
+    }
+
+    #[test]
+    fn test_new_report_records_versions() {
+        let report = Report::new(Vec::new());
+        assert_eq!(report.schema_version, SCHEMA_VERSION);
+        assert!(!report.crate_version.is_empty());
+        let json = report.to_json();
+        assert!(json.contains("\"schema_version\""));
+        assert!(json.contains("\"crate_version\""));
+    }
+
+    #[test]
+    fn test_unversioned_baseline_is_refused() {
+        let v1: Report = serde_json::from_str(r#"{"timestamp": 0, "results": []}"#).unwrap();
+        assert_eq!(v1.schema_version, 1);
+        let current = Report::new(Vec::new());
+        let err = current.check_comparable(&v1).unwrap_err();
+        assert!(err.contains("v1") && err.contains("v2"), "{err}");
+        assert!(current.check_comparable(&Report::new(Vec::new())).is_ok());
+    }
 }
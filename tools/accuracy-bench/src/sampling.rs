@@ -1,13 +1,56 @@
 //! Test point generation.
+//!
+//! [`SampleStrategy`] is independent of the rest of the bench, so it can be
+//! used to sample inputs for other fixed-point functions with the same
+//! distribution as the accuracy report. The same strategy and seed always
+//! produce the same points.
+//!
+//! ```
+//! use accuracy_bench::sampling::SampleStrategy;
+//!
+//! let points = SampleStrategy::quick().generate(-1.0, 1.0);
+//! assert!(points.iter().all(|&x| (-1.0..=1.0).contains(&x)));
+//! assert!(points.is_sorted());
+//! ```
 
+/// How many points of each kind [`SampleStrategy::generate`] produces.
 pub struct SampleStrategy {
+    /// Evenly spaced points across the range, endpoints included.
     pub grid_points: usize,
+    /// Uniform pseudo-random points drawn from the seed.
     pub random_points: usize,
+    /// Points clustered towards each end of the range, counted per end.
     pub boundary_points: usize,
     seed: u64,
 }
 
 impl SampleStrategy {
+    /// A strategy with explicit point counts and random seed.
+    pub fn new(
+        grid_points: usize,
+        random_points: usize,
+        boundary_points: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            grid_points,
+            random_points,
+            boundary_points,
+            seed,
+        }
+    }
+
+    /// A small preset for fast local runs.
+    pub fn quick() -> Self {
+        Self {
+            grid_points: 500,
+            random_points: 2000,
+            boundary_points: 200,
+            seed: 0xDEAD_BEEF_CAFE_BABE,
+        }
+    }
+
+    /// The preset used for the published accuracy tables.
     pub fn thorough() -> Self {
         Self {
             grid_points: 5000,
@@ -17,6 +60,14 @@ impl SampleStrategy {
         }
     }
 
+    /// Seed for the random points.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Sorted, deduplicated points in `[lo, hi]`.
+    ///
+    /// Also includes whichever of 0, ±0.5, ±1 and ±2 fall in the range.
     pub fn generate(&self, lo: f64, hi: f64) -> Vec<f64> {
         let mut points = Vec::with_capacity(
             self.grid_points + self.random_points + self.boundary_points * 2 + 10,
@@ -54,4 +105,79 @@ impl SampleStrategy {
         points.dedup_by(|a, b| (*a - *b).abs() < 1e-15);
         points
     }
+
+    /// `n` logarithmically spaced points from `lo` to `hi`, endpoints included.
+    ///
+    /// Consecutive points have a constant ratio, so each decade gets the same
+    /// number of samples. Use this for functions such as `ln` and `exp`,
+    /// where linear spacing leaves small inputs under-sampled.
+    /// Requires `0 < lo <= hi`; returns no points otherwise.
+    pub fn log_spaced(lo: f64, hi: f64, n: usize) -> Vec<f64> {
+        if !(lo > 0.0 && lo <= hi) || n == 0 {
+            return Vec::new();
+        }
+        if n == 1 {
+            return vec![lo];
+        }
+        let (ln_lo, ln_hi) = (lo.ln(), hi.ln());
+        let mut points: Vec<f64> = (0..n)
+            .map(|i| {
+                let t = i as f64 / (n - 1) as f64;
+                (ln_lo + t * (ln_hi - ln_lo)).exp()
+            })
+            .collect();
+        // Pin the endpoints exactly despite exp/ln rounding.
+        points[0] = lo;
+        points[n - 1] = hi;
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_honors_point_counts() {
+        // No special values fall in [3, 4], so every point comes from the strategy.
+        let points = SampleStrategy::new(10, 20, 5, 1).generate(3.0, 4.0);
+        assert_eq!(points.len(), 10 + 20 + 5 * 2);
+
+        let points = SampleStrategy::new(100, 0, 0, 1).generate(3.0, 4.0);
+        assert_eq!(points.len(), 100);
+        assert_eq!(points.first(), Some(&3.0));
+        assert_eq!(points.last(), Some(&4.0));
+    }
+
+    #[test]
+    fn test_log_spaced_denser_near_lo() {
+        let points = SampleStrategy::log_spaced(1e-3, 1e3, 61);
+        assert_eq!(points.len(), 61);
+        assert_eq!(points.first(), Some(&1e-3));
+        assert_eq!(points.last(), Some(&1e3));
+        assert!(points.windows(2).all(|w| w[0] < w[1]));
+
+        // Gaps grow with x, and each decade holds the same number of points.
+        let first_gap = points[1] - points[0];
+        let last_gap = points[60] - points[59];
+        assert!(first_gap * 1e5 < last_gap);
+        let below_one = points.iter().filter(|&&x| x < 1.0).count();
+        assert_eq!(below_one, 30);
+    }
+
+    #[test]
+    fn test_log_spaced_rejects_non_positive_range() {
+        assert!(SampleStrategy::log_spaced(0.0, 1.0, 10).is_empty());
+        assert!(SampleStrategy::log_spaced(-1.0, 1.0, 10).is_empty());
+        assert!(SampleStrategy::log_spaced(2.0, 1.0, 10).is_empty());
+        assert_eq!(SampleStrategy::log_spaced(2.0, 8.0, 1), vec![2.0]);
+    }
+
+    #[test]
+    fn test_seed_changes_random_points() {
+        let a = SampleStrategy::new(0, 50, 0, 1).generate(3.0, 4.0);
+        let b = SampleStrategy::new(0, 50, 0, 2).generate(3.0, 4.0);
+        assert_eq!(a.len(), b.len());
+        assert_ne!(a, b);
+    }
 }
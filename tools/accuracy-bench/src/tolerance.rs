@@ -0,0 +1,97 @@
+//! Per-function regression tolerances for baseline comparison.
+//!
+//! Loaded from an optional JSON file:
+//!
+//! ```json
+//! { "default": 0.001, "functions": { "sinh": 0.05, "sqrt": 0.0001 } }
+//! ```
+//!
+//! Tolerances are relative to the baseline `rel_mean`. Functions without an
+//! entry use `default`, which is itself optional.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Relative tolerance used when neither the function nor the file sets one.
+pub const DEFAULT_TOLERANCE: f64 = 0.001;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tolerances {
+    #[serde(default = "default_tolerance")]
+    pub default: f64,
+    #[serde(default)]
+    pub functions: HashMap<String, f64>,
+}
+
+fn default_tolerance() -> f64 {
+    DEFAULT_TOLERANCE
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_TOLERANCE,
+            functions: HashMap::new(),
+        }
+    }
+}
+
+impl Tolerances {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Relative tolerance for `function`.
+    pub fn for_function(&self, function: &str) -> f64 {
+        self.functions
+            .get(function)
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    /// Compare `current` against `baseline` for `function`, returning whether
+    /// it passed and a status label.
+    pub fn check(&self, function: &str, baseline: f64, current: f64) -> (bool, &'static str) {
+        let tolerance = baseline * self.for_function(function);
+        if current > baseline + tolerance {
+            (false, "REGRESS")
+        } else if current < baseline - tolerance {
+            (true, "IMPROVE")
+        } else {
+            (true, "SAME")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_override_suppresses_regression() {
+        let baseline = 1.0e-4;
+        let current = baseline * 1.02;
+
+        let strict = Tolerances::default();
+        assert_eq!(strict.check("sinh", baseline, current), (false, "REGRESS"));
+
+        let loose = Tolerances::from_json(r#"{ "functions": { "sinh": 0.05 } }"#).unwrap();
+        assert_eq!(loose.check("sinh", baseline, current), (true, "SAME"));
+        // Other functions keep the default.
+        assert_eq!(loose.check("cosh", baseline, current), (false, "REGRESS"));
+    }
+
+    #[test]
+    fn test_default_can_be_overridden() {
+        let tolerances = Tolerances::from_json(r#"{ "default": 0.1 }"#).unwrap();
+        assert_eq!(tolerances.for_function("sin"), 0.1);
+        assert_eq!(Tolerances::default().for_function("sin"), DEFAULT_TOLERANCE);
+    }
+
+    #[test]
+    fn test_improvement_outside_band() {
+        let tolerances = Tolerances::default();
+        assert_eq!(tolerances.check("sqrt", 1.0, 0.9), (true, "IMPROVE"));
+        assert_eq!(tolerances.check("sqrt", 1.0, 1.0005), (true, "SAME"));
+    }
+}